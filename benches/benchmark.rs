@@ -1,7 +1,10 @@
 // Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
 
 use crc_fast::checksum;
+use crc_fast::checksum_multi;
+use crc_fast::nvme::{generate, Format, ProtectionType, Tags};
 use crc_fast::CrcAlgorithm;
+use crc_fast::{copy_and_checksum, copy_and_checksum_non_temporal};
 use criterion::*;
 use rand::{rng, RngCore};
 use std::hint::black_box;
@@ -174,6 +177,220 @@ fn bench_crc64(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench_crc32, bench_crc64);
+// small record sizes, where per-update dispatch overhead dominates
+pub const SMALL_UPDATE_SIZES: &[usize; 5] = &[16, 32, 64, 128, 256];
+
+// total amount of data processed per iteration in the small update benchmarks
+const SMALL_UPDATE_TOTAL: usize = 64 * 1024;
+
+pub const SMALL_UPDATE_ALGORITHMS: &[CrcAlgorithm; 3] = &[
+    CrcAlgorithm::Crc32Iscsi,   // per-record CRC32C
+    CrcAlgorithm::Crc32IsoHdlc, // reflected, fusion
+    CrcAlgorithm::Crc64Nvme,    // reflected
+];
+
+#[inline(always)]
+fn bench_small_updates(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Small updates");
+
+    let buf = create_aligned_data(&random_data(SMALL_UPDATE_TOTAL as i32));
+
+    for size in SMALL_UPDATE_SIZES {
+        for algorithm in SMALL_UPDATE_ALGORITHMS {
+            group.throughput(Throughput::Bytes(SMALL_UPDATE_TOTAL as u64));
+            group.sample_size(100);
+            group.measurement_time(Duration::from_secs(10));
+
+            let size_name = format!("{size} bytes");
+
+            let bench_name = [algorithm.to_string(), "(digest updates)".to_string()].join(" ");
+
+            group.bench_function(BenchmarkId::new(bench_name, &size_name), |b| {
+                let mut digest = crc_fast::Digest::new(*algorithm);
+
+                b.iter(|| {
+                    for record in buf.chunks(*size) {
+                        digest.update(black_box(record));
+                    }
+                    black_box(digest.finalize())
+                })
+            });
+
+            let bench_name = [algorithm.to_string(), "(checksums)".to_string()].join(" ");
+
+            group.bench_function(BenchmarkId::new(bench_name, &size_name), |b| {
+                b.iter(|| {
+                    for record in buf.chunks(*size) {
+                        black_box(checksum(*algorithm, black_box(record)));
+                    }
+                })
+            });
+        }
+    }
+}
+
+// large buffers, which fold with the widest accumulator sets (512-byte and wider distances)
+pub const LARGE_SIZES: &[(&str, usize); 4] = &[
+    ("16 KiB", 16 * 1024),
+    ("1 MiB", 1024 * 1024),
+    ("4 MiB", 4 * 1024 * 1024),
+    ("16 MiB", 16 * 1024 * 1024),
+];
+
+// fusion algorithms are excluded since they don't use the wide folding paths on x86
+pub const LARGE_ALGORITHMS: &[CrcAlgorithm; 4] = &[
+    CrcAlgorithm::Crc32Autosar, // reflected
+    CrcAlgorithm::Crc32Bzip2,   // forward
+    CrcAlgorithm::Crc64Ecma182, // forward
+    CrcAlgorithm::Crc64Nvme,    // reflected
+];
+
+#[inline(always)]
+fn bench_large_buffers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Large buffers");
+
+    for (size_name, size) in LARGE_SIZES {
+        let buf = create_aligned_data(&random_data(*size as i32));
+
+        for algorithm in LARGE_ALGORITHMS {
+            group.throughput(Throughput::Bytes(*size as u64));
+            group.sample_size(100);
+            group.measurement_time(Duration::from_secs(10));
+
+            let bench_name = [algorithm.to_string(), "(checksum)".to_string()].join(" ");
+
+            group.bench_function(BenchmarkId::new(bench_name, size_name), |b| {
+                b.iter(|| black_box(checksum(*algorithm, &buf)))
+            });
+        }
+    }
+}
+
+// the CRCs S3 clients may request, computed together vs one after another
+pub const MULTI_ALGORITHMS: &[CrcAlgorithm; 3] = &[
+    CrcAlgorithm::Crc32IsoHdlc, // reflected, fusion
+    CrcAlgorithm::Crc32Iscsi,   // reflected, fusion
+    CrcAlgorithm::Crc64Nvme,    // reflected
+];
+
+#[inline(always)]
+fn bench_multi(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Multiple CRCs");
+
+    for (size_name, size) in LARGE_SIZES {
+        let buf = create_aligned_data(&random_data(*size as i32));
+
+        group.throughput(Throughput::Bytes(*size as u64));
+        group.sample_size(100);
+        group.measurement_time(Duration::from_secs(10));
+
+        let pair = [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme];
+
+        group.bench_function(
+            BenchmarkId::new("CRC-32/ISCSI + CRC-64/NVME (checksum_multi)", size_name),
+            |b| b.iter(|| black_box(checksum_multi(&pair, &buf))),
+        );
+
+        group.bench_function(
+            BenchmarkId::new("CRC-32/ISCSI + CRC-64/NVME (sequential)", size_name),
+            |b| b.iter(|| black_box(pair.map(|algorithm| checksum(algorithm, &buf)))),
+        );
+
+        group.bench_function(
+            BenchmarkId::new("S3 CRCs (checksum_multi)", size_name),
+            |b| b.iter(|| black_box(checksum_multi(MULTI_ALGORITHMS, &buf))),
+        );
+
+        group.bench_function(BenchmarkId::new("S3 CRCs (sequential)", size_name), |b| {
+            b.iter(|| black_box(MULTI_ALGORITHMS.map(|algorithm| checksum(algorithm, &buf))))
+        });
+    }
+}
+
+#[inline(always)]
+fn bench_copy(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Copy and checksum");
+
+    for (size_name, size) in LARGE_SIZES {
+        let buf = create_aligned_data(&random_data(*size as i32));
+        let mut dst = create_aligned_data(&buf);
+
+        group.throughput(Throughput::Bytes(*size as u64));
+        group.sample_size(100);
+        group.measurement_time(Duration::from_secs(10));
+
+        for algorithm in [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme] {
+            group.bench_function(
+                BenchmarkId::new(format!("{algorithm} (copy_and_checksum)"), size_name),
+                |b| b.iter(|| black_box(copy_and_checksum(algorithm, &buf, &mut dst))),
+            );
+
+            group.bench_function(
+                BenchmarkId::new(
+                    format!("{algorithm} (copy_and_checksum_non_temporal)"),
+                    size_name,
+                ),
+                |b| b.iter(|| black_box(copy_and_checksum_non_temporal(algorithm, &buf, &mut dst))),
+            );
+
+            group.bench_function(
+                BenchmarkId::new(
+                    format!("{algorithm} (copy_from_slice + checksum)"),
+                    size_name,
+                ),
+                |b| {
+                    b.iter(|| {
+                        dst.copy_from_slice(&buf);
+                        black_box(checksum(algorithm, &dst))
+                    })
+                },
+            );
+        }
+    }
+}
+
+fn bench_nvme(c: &mut Criterion) {
+    let mut group = c.benchmark_group("NVMe protection information");
+
+    for sector_size in [512, 4096] {
+        let format = Format::new(sector_size, ProtectionType::Type1);
+        let blocks = 1024 * 1024 / sector_size;
+        let mut buf = random_data((blocks * format.block_size()) as i32);
+
+        group.throughput(Throughput::Bytes((blocks * sector_size) as u64));
+        group.sample_size(100);
+        group.measurement_time(Duration::from_secs(10));
+
+        group.bench_function(
+            BenchmarkId::new("generate", format!("{sector_size}-byte sectors")),
+            |b| b.iter(|| generate(black_box(&mut buf), format, Tags::default())),
+        );
+
+        group.bench_function(
+            BenchmarkId::new("checksum per sector", format!("{sector_size}-byte sectors")),
+            |b| {
+                b.iter(|| {
+                    for block in buf.chunks_exact_mut(format.block_size()) {
+                        let (sector, pi) = block.split_at_mut(sector_size);
+                        let guard = checksum(CrcAlgorithm::Crc64Nvme, sector);
+                        pi[..8].copy_from_slice(&guard.to_be_bytes());
+                    }
+                    black_box(&buf);
+                })
+            },
+        );
+    }
+}
+
+criterion_group!(
+    benches,
+    bench_crc32,
+    bench_crc64,
+    bench_small_updates,
+    bench_large_buffers,
+    bench_multi,
+    bench_copy,
+    bench_nvme
+);
 
 criterion_main!(benches);
@@ -15,16 +15,16 @@
 
 #![cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
 
-use crate::consts::CRC_CHUNK_SIZE;
+use crate::consts::{CRC_CHUNK_SIZE, CRC_WIDE_FOLD_THRESHOLD};
 use crate::enums::{DataChunkProcessor, Reflector};
-use crate::structs::CrcState;
+use crate::structs::{CrcState, MultiLane, Width32, Width64};
 use crate::traits::{ArchOps, EnhancedCrcWidth};
-use crate::{crc32, crc64, CrcParams};
+use crate::{crc32, crc64, CalculatorFn, CrcParams};
 
 /// Extract keys from CrcParams using safe accessor methods
 /// This ensures bounds checking and future compatibility
 #[inline(always)]
-fn extract_keys_array(params: CrcParams) -> [u64; 23] {
+fn extract_keys_array(params: CrcParams) -> [u64; 27] {
     [
         params.get_key(0),
         params.get_key(1),
@@ -49,9 +49,26 @@ fn extract_keys_array(params: CrcParams) -> [u64; 23] {
         params.get_key(20),
         params.get_key(21),
         params.get_key(22),
+        0,
+        0,
+        0,
+        0,
     ]
 }
 
+/// Extract keys like `extract_keys_array()`, adding the wide folding keys at indices 23-26 when
+/// the input is long enough for the AVX-512 VPCLMULQDQ tier to use them
+#[inline(always)]
+fn extract_keys_array_for_len(params: CrcParams, len: usize) -> [u64; 27] {
+    let mut keys = extract_keys_array(params);
+
+    if len >= CRC_WIDE_FOLD_THRESHOLD {
+        keys[23..].copy_from_slice(&params.wide_keys());
+    }
+
+    keys
+}
+
 /// Main entry point that works for both CRC-32 and CRC-64
 #[inline(always)]
 pub unsafe fn update<T: ArchOps, W: EnhancedCrcWidth>(
@@ -101,11 +118,427 @@ where
         bytes,
         &mut crc_state,
         reflector,
-        extract_keys_array(params),
+        extract_keys_array_for_len(params, len),
         ops,
     )
 }
 
+/// Entry point for copying `src` to `dst` while calculating the CRC of `src`, for both CRC-32
+/// and CRC-64
+///
+/// The aligned bulk of the buffer is stored to `dst` from inside the folding loop, so each vector
+/// is only loaded once. Short buffers and the unaligned edges are copied first and then handed to
+/// the CRC's own calculator while they're still in cache.
+///
+/// `dst` must be the same length as `src`.
+#[inline(always)]
+pub unsafe fn update_copy<T: ArchOps>(
+    state: u64,
+    src: &[u8],
+    dst: &mut [u8],
+    params: CrcParams,
+    calculator: CalculatorFn,
+    non_temporal: bool,
+    ops: &T,
+) -> u64
+where
+    T::Vector: Copy,
+{
+    debug_assert_eq!(src.len(), dst.len());
+
+    let (left, middle, right) = src.align_to::<[T::Vector; 8]>();
+
+    if middle.is_empty() {
+        dst.copy_from_slice(src);
+
+        return calculator(state, src, params);
+    }
+
+    let (dst_left, dst_rest) = dst.split_at_mut(left.len());
+    let (dst_middle, dst_right) = dst_rest.split_at_mut(middle.len() * 128);
+
+    dst_left.copy_from_slice(left);
+    let state = calculator(state, left, params);
+
+    let dst = dst_middle.as_mut_ptr();
+    let state = match params.width {
+        64 => copy_aligned::<T, Width64>(state, middle, dst, params, non_temporal, ops),
+        _ => {
+            copy_aligned::<T, Width32>(state as u32, middle, dst, params, non_temporal, ops) as u64
+        }
+    };
+
+    dst_right.copy_from_slice(right);
+    calculator(state, right, params)
+}
+
+/// Copy and fold SIMD-aligned chunks of 128 bytes, returning the updated CRC state
+#[inline(always)]
+unsafe fn copy_aligned<T: ArchOps, W: EnhancedCrcWidth>(
+    state: W::Value,
+    blocks: &[[T::Vector; 8]],
+    dst: *mut u8,
+    params: CrcParams,
+    non_temporal: bool,
+    ops: &T,
+) -> W::Value
+where
+    T::Vector: Copy,
+{
+    // Create the appropriate reflector based on CRC type
+    let reflector = if params.refin {
+        Reflector::NoReflector
+    } else {
+        // Load mask for byte-swapping operations
+        let smask = ops.load_aligned(&W::load_constants(params.refin)[0] as *const [u64; 2]);
+        Reflector::ForwardReflector { smask }
+    };
+
+    let mut crc_state = W::create_state(state, params.refin, ops);
+
+    process_simd_chunks_copy::<T, W>(
+        &mut crc_state,
+        blocks,
+        dst,
+        &reflector,
+        extract_keys_array(params),
+        non_temporal,
+        ops,
+    );
+
+    W::extract_result(crc_state.value, crc_state.reflected, ops)
+}
+
+/// Process SIMD-aligned chunks of 128 bytes, storing each loaded vector to `dst` as it's folded
+///
+/// Non-temporal stores are only used if `dst` is 16-byte aligned, otherwise regular stores are
+/// used.
+#[inline]
+#[cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature(enable = "ssse3,sse4.1,pclmulqdq")
+)]
+#[cfg_attr(target_arch = "aarch64", target_feature(enable = "aes"))]
+unsafe fn process_simd_chunks_copy<T: ArchOps, W: EnhancedCrcWidth>(
+    state: &mut CrcState<T::Vector>,
+    blocks: &[[T::Vector; 8]],
+    dst: *mut u8,
+    reflector: &Reflector<T::Vector>,
+    keys: [u64; 27],
+    non_temporal: bool,
+    ops: &T,
+) where
+    T::Vector: Copy,
+{
+    let non_temporal = non_temporal && (dst as usize) % 16 == 0;
+
+    let (first, rest) = blocks.split_first().unwrap_unchecked();
+
+    store_block(first, dst, non_temporal, ops);
+
+    // Create a copy of the first 128 bytes
+    let mut x = *first;
+
+    // Apply initial reflection if needed
+    for item in &mut x {
+        *item = reflect_bytes(reflector, *item, ops);
+    }
+
+    // XOR initial CRC with the first 16 bytes
+    x[0] = ops.xor_vectors(x[0], state.value);
+
+    // Load the coefficient pair for folding
+    let coeff = W::create_coefficient(keys[4], keys[3], state.reflected, ops);
+
+    // Process remaining 128-byte chunks
+    for (n, chunk) in rest.iter().enumerate() {
+        store_block(chunk, dst.add((n + 1) * 128), non_temporal, ops);
+
+        for (xi, yi) in x.iter_mut().zip(chunk.iter()) {
+            // Reflect the new data if needed, then fold 16 bytes
+            let yi = reflect_bytes(reflector, *yi, ops);
+            *xi = fold_and_xor::<T, W>(*xi, coeff, yi, state.reflected, ops);
+        }
+    }
+
+    if non_temporal {
+        ops.store_fence();
+    }
+
+    // Fold the 8 xmm registers to 1 xmm register and update state
+    fold_8_to_state::<T, W>(state, x, keys, ops);
+}
+
+/// Store a 128-byte block to `dst`
+#[inline(always)]
+unsafe fn store_block<T: ArchOps>(block: &[T::Vector; 8], dst: *mut u8, non_temporal: bool, ops: &T)
+where
+    T::Vector: Copy,
+{
+    for (i, &yi) in block.iter().enumerate() {
+        if non_temporal {
+            ops.store_bytes_non_temporal(dst.add(i * 16), yi);
+        } else {
+            ops.store_bytes(dst.add(i * 16), yi);
+        }
+    }
+}
+
+/// Entry point for updating several CRCs (of either width) over the same buffer in a single pass
+///
+/// The aligned bulk of the buffer is loaded once and folded into every CRC's accumulators, while
+/// short buffers and the unaligned edges use each CRC's own calculator.
+#[inline(always)]
+pub unsafe fn update_multi<T: ArchOps, const N: usize>(
+    states: &mut [u64; N],
+    bytes: &[u8],
+    params: &[CrcParams; N],
+    calculators: &[CalculatorFn; N],
+    ops: &T,
+) where
+    T::Vector: Copy,
+{
+    let (left, middle, right) = bytes.align_to::<[T::Vector; 8]>();
+
+    // Folding together only pays off once there are at least two aligned 128-byte blocks
+    let Some((first, rest)) = middle.split_first().filter(|(_, rest)| !rest.is_empty()) else {
+        for i in 0..N {
+            states[i] = calculators[i](states[i], bytes, params[i]);
+        }
+
+        return;
+    };
+
+    if !left.is_empty() {
+        for i in 0..N {
+            states[i] = calculators[i](states[i], left, params[i]);
+        }
+    }
+
+    let mut lanes: [MultiLane<T::Vector>; N] = core::array::from_fn(|i| {
+        let reflected = params[i].refin;
+        let state = match params[i].width {
+            64 => Width64::create_state(states[i], reflected, ops),
+            _ => Width32::create_state(states[i] as u32, reflected, ops),
+        };
+
+        MultiLane {
+            state,
+            width: params[i].width,
+            keys: extract_keys_array(params[i]),
+        }
+    });
+
+    // try to use the enhanced SIMD implementation first, fall back to non-enhanced if necessary
+    if !ops.process_enhanced_multi_blocks(&mut lanes, first, rest) {
+        process_multi_simd_chunks(&mut lanes, first, rest, ops);
+    }
+
+    for (state, lane) in states.iter_mut().zip(lanes.iter()) {
+        *state = match lane.width {
+            64 => Width64::extract_result(lane.state.value, lane.state.reflected, ops),
+            _ => Width32::extract_result(lane.state.value, lane.state.reflected, ops) as u64,
+        };
+    }
+
+    if !right.is_empty() {
+        for i in 0..N {
+            states[i] = calculators[i](states[i], right, params[i]);
+        }
+    }
+}
+
+/// Entry point for calculating the CRCs of several equal-length sectors at once, for both CRC-32
+/// and CRC-64
+///
+/// Each sector starts from `state`. Every sector must be the same non-zero multiple of 16 bytes
+/// long, as NVMe's 512 and 4096-byte sectors are.
+#[inline(always)]
+pub unsafe fn update_sectors<T: ArchOps, const N: usize>(
+    state: u64,
+    sectors: [&[u8]; N],
+    params: CrcParams,
+    ops: &T,
+) -> [u64; N]
+where
+    T::Vector: Copy,
+{
+    match params.width {
+        64 => process_sectors::<T, Width64, N>(state, sectors, params, ops),
+        _ => process_sectors::<T, Width32, N>(state as u32, sectors, params, ops).map(u64::from),
+    }
+}
+
+/// Fold several sectors in lockstep, 16 bytes at a time, with one accumulator per sector
+///
+/// A single short buffer spends much of its time waiting on its own chain of carry-less
+/// multiplies and final reduction, so interleaving the sectors' independent chains keeps the
+/// multiplier busy instead.
+#[inline]
+#[cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature(enable = "ssse3,sse4.1,pclmulqdq")
+)]
+#[cfg_attr(target_arch = "aarch64", target_feature(enable = "aes"))]
+unsafe fn process_sectors<T: ArchOps, W: EnhancedCrcWidth, const N: usize>(
+    state: W::Value,
+    sectors: [&[u8]; N],
+    params: CrcParams,
+    ops: &T,
+) -> [W::Value; N]
+where
+    T::Vector: Copy,
+{
+    let len = sectors[0].len();
+    assert!(
+        len >= CRC_CHUNK_SIZE
+            && len % CRC_CHUNK_SIZE == 0
+            && sectors.iter().all(|sector| sector.len() == len),
+        "Sectors must be the same non-zero multiple of 16 bytes"
+    );
+
+    let reflector = if params.refin {
+        Reflector::NoReflector
+    } else {
+        let smask = ops.load_aligned(&W::load_constants(params.refin)[0] as *const [u64; 2]);
+        Reflector::ForwardReflector { smask }
+    };
+
+    let keys = extract_keys_array(params);
+    let initial = W::create_state(state, params.refin, ops);
+    let coeff = W::create_coefficient(keys[2], keys[1], params.refin, ops); // 16 bytes
+
+    let mut x: [T::Vector; N] = core::array::from_fn(|l| {
+        process_16_byte_block(sectors[l].as_ptr(), initial.value, &reflector, ops)
+    });
+
+    for offset in (CRC_CHUNK_SIZE..len).step_by(CRC_CHUNK_SIZE) {
+        for (xi, sector) in x.iter_mut().zip(sectors.iter()) {
+            let yi = reflect_bytes(&reflector, ops.load_bytes(sector.as_ptr().add(offset)), ops);
+            *xi = fold_and_xor::<T, W>(*xi, coeff, yi, params.refin, ops);
+        }
+    }
+
+    x.map(|xi| W::perform_final_reduction(xi, params.refin, keys, ops))
+}
+
+/// Process SIMD-aligned chunks of 128 bytes for several CRCs at once
+///
+/// Each 16-byte vector is loaded (and byte-swapped, if any CRC is forward) once, then folded
+/// into each lane's 8 accumulators.
+#[inline]
+#[cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature(enable = "ssse3,sse4.1,pclmulqdq")
+)]
+#[cfg_attr(target_arch = "aarch64", target_feature(enable = "aes"))]
+unsafe fn process_multi_simd_chunks<T: ArchOps, const N: usize>(
+    lanes: &mut [MultiLane<T::Vector>; N],
+    first: &[T::Vector; 8],
+    rest: &[[T::Vector; 8]],
+    ops: &T,
+) where
+    T::Vector: Copy,
+{
+    // The byte-swap mask is the same for both widths
+    let smask = ops.load_aligned(&Width64::load_constants(false)[0] as *const [u64; 2]);
+    let any_forward = lanes.iter().any(|lane| !lane.state.reflected);
+
+    let mut x = [*first; N];
+    let mut coeffs = [first[0]; N];
+
+    for (l, lane) in lanes.iter().enumerate() {
+        for (xi, yi) in x[l].iter_mut().zip(first.iter()) {
+            if !lane.state.reflected {
+                *xi = ops.shuffle_bytes(*yi, smask);
+            }
+        }
+
+        // XOR initial CRC with the first 16 bytes
+        x[l][0] = ops.xor_vectors(x[l][0], lane.state.value);
+
+        coeffs[l] = create_multi_coefficient(lane, lane.keys[4], lane.keys[3], ops);
+    }
+
+    // Process remaining 128-byte chunks
+    for chunk in rest {
+        for (i, &data) in chunk.iter().enumerate() {
+            let swapped = if any_forward {
+                ops.shuffle_bytes(data, smask)
+            } else {
+                data
+            };
+
+            for l in 0..N {
+                let y = if lanes[l].state.reflected {
+                    data
+                } else {
+                    swapped
+                };
+
+                x[l][i] = ops.xor3_vectors(
+                    ops.carryless_mul_00(x[l][i], coeffs[l]),
+                    ops.carryless_mul_11(x[l][i], coeffs[l]),
+                    y,
+                );
+            }
+        }
+    }
+
+    // Fold each lane's 8 xmm registers to 1 xmm register and update its state
+    for (lane, x) in lanes.iter_mut().zip(x) {
+        match lane.width {
+            64 => fold_8_to_state::<T, Width64>(&mut lane.state, x, lane.keys, ops),
+            _ => fold_8_to_state::<T, Width32>(&mut lane.state, x, lane.keys, ops),
+        }
+    }
+}
+
+/// Create a folding coefficient for a lane which folds with the low and high products (00/11)
+/// regardless of width, so lanes of both widths share the same inner loop
+#[inline(always)]
+unsafe fn create_multi_coefficient<T: ArchOps>(
+    lane: &MultiLane<T::Vector>,
+    high: u64,
+    low: u64,
+    ops: &T,
+) -> T::Vector
+where
+    T::Vector: Copy,
+{
+    match (lane.width, lane.state.reflected) {
+        (64, reflected) => Width64::create_coefficient(high, low, reflected, ops),
+        // Reflected CRC-32 folds with the cross products (01/10), so swap the halves
+        (_, true) => Width32::create_coefficient(low, high, true, ops),
+        (_, false) => Width32::create_coefficient(high, low, false, ops),
+    }
+}
+
+/// Perform the width-specific final reduction for a lane and update its state
+#[inline(always)]
+pub(crate) unsafe fn reduce_multi_lane<T: ArchOps>(
+    lane: &mut MultiLane<T::Vector>,
+    folded: T::Vector,
+    ops: &T,
+) where
+    T::Vector: Copy,
+{
+    let reflected = lane.state.reflected;
+
+    lane.state = match lane.width {
+        64 => Width64::create_state(
+            Width64::perform_final_reduction(folded, reflected, lane.keys, ops),
+            reflected,
+            ops,
+        ),
+        _ => Width32::create_state(
+            Width32::perform_final_reduction(folded, reflected, lane.keys, ops),
+            reflected,
+            ops,
+        ),
+    };
+}
+
 /// Process data with the selected strategy
 #[inline]
 #[cfg_attr(
@@ -118,7 +551,7 @@ unsafe fn process_by_strategy<T: ArchOps, W: EnhancedCrcWidth>(
     data: &[u8],
     state: &mut CrcState<T::Vector>,
     reflector: Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 27],
     ops: &T,
 ) -> W::Value
 where
@@ -153,7 +586,7 @@ unsafe fn process_large_aligned<T: ArchOps, W: EnhancedCrcWidth>(
     bytes: &[u8],
     state: &mut CrcState<T::Vector>,
     reflector: Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 27],
     ops: &T,
 ) -> W::Value
 where
@@ -207,7 +640,7 @@ unsafe fn process_simd_chunks<T: ArchOps, W: EnhancedCrcWidth>(
     first: &[T::Vector; 8],
     rest: &[[T::Vector; 8]],
     reflector: &Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 27],
     ops: &T,
 ) where
     T::Vector: Copy,
@@ -246,6 +679,95 @@ unsafe fn process_simd_chunks<T: ArchOps, W: EnhancedCrcWidth>(
         }
     }
 
+    // Fold the 8 xmm registers to 1 xmm register and update state
+    fold_8_to_state::<T, W>(state, x, keys, ops);
+}
+
+/// Process SIMD-aligned chunks using 16 accumulators, folding 256 bytes at a time
+///
+/// Returns false (without touching the state) if the buffer is too short to benefit, or if the
+/// keys don't include 256-byte folding distances, so the caller can fall back to
+/// `process_simd_chunks`.
+#[cfg(any(target_arch = "aarch64", test))]
+#[inline]
+#[cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature(enable = "ssse3,sse4.1,pclmulqdq")
+)]
+#[cfg_attr(target_arch = "aarch64", target_feature(enable = "aes"))]
+pub(crate) unsafe fn process_wide_simd_chunks<T: ArchOps, W: EnhancedCrcWidth>(
+    state: &mut CrcState<T::Vector>,
+    first: &[T::Vector; 8],
+    rest: &[[T::Vector; 8]],
+    reflector: &Reflector<T::Vector>,
+    keys: [u64; 27],
+    ops: &T,
+) -> bool
+where
+    T::Vector: Copy,
+{
+    // At least 512 bytes, so the wide loop runs at least once
+    if rest.len() < 3 || keys[21] == 0 || keys[22] == 0 {
+        return false;
+    }
+
+    // Load and reflect the first 256 bytes into 16 accumulators
+    let mut x = [state.value; 16];
+    for (xi, yi) in x.iter_mut().zip(first.iter().chain(rest[0].iter())) {
+        *xi = reflect_bytes(reflector, *yi, ops);
+    }
+
+    // XOR initial CRC with the first 16 bytes
+    x[0] = ops.xor_vectors(x[0], state.value);
+
+    // Process remaining 256-byte chunks
+    let coeff = W::create_coefficient(keys[22], keys[21], state.reflected, ops);
+
+    let mut pairs = rest[1..].chunks_exact(2);
+
+    for pair in &mut pairs {
+        for (xi, yi) in x.iter_mut().zip(pair[0].iter().chain(pair[1].iter())) {
+            let yi = reflect_bytes(reflector, *yi, ops);
+            *xi = fold_and_xor::<T, W>(*xi, coeff, yi, state.reflected, ops);
+        }
+    }
+
+    // Fold 16 accumulators to 8 (registers 128 bytes apart)
+    let coeff = W::create_coefficient(keys[4], keys[3], state.reflected, ops);
+
+    let mut x8 = [state.value; 8];
+    for i in 0..8 {
+        x8[i] = fold_and_xor::<T, W>(x[i], coeff, x[i + 8], state.reflected, ops);
+    }
+
+    // Fold in a trailing 128-byte chunk, if any
+    if let [chunk] = pairs.remainder() {
+        for (xi, yi) in x8.iter_mut().zip(chunk.iter()) {
+            let yi = reflect_bytes(reflector, *yi, ops);
+            *xi = fold_and_xor::<T, W>(*xi, coeff, yi, state.reflected, ops);
+        }
+    }
+
+    fold_8_to_state::<T, W>(state, x8, keys, ops);
+
+    true
+}
+
+/// Fold 8 x 128-bit registers to 1, perform the final reduction, and update the state
+#[inline]
+#[cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature(enable = "ssse3,sse4.1,pclmulqdq")
+)]
+#[cfg_attr(target_arch = "aarch64", target_feature(enable = "aes"))]
+unsafe fn fold_8_to_state<T: ArchOps, W: EnhancedCrcWidth>(
+    state: &mut CrcState<T::Vector>,
+    x: [T::Vector; 8],
+    keys: [u64; 27],
+    ops: &T,
+) where
+    T::Vector: Copy,
+{
     // Fold the 8 xmm registers to 1 xmm register with different constants
     let mut res = x[7];
 
@@ -286,7 +808,7 @@ unsafe fn process_exactly_16<T: ArchOps, W: EnhancedCrcWidth>(
     data: &[u8],
     state: &mut CrcState<T::Vector>,
     reflector: &Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 27],
     ops: &T,
 ) -> W::Value
 where
@@ -386,7 +908,7 @@ unsafe fn process_17_to_31<T: ArchOps, W: EnhancedCrcWidth>(
     data: &[u8],
     state: &mut CrcState<T::Vector>,
     reflector: &Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 27],
     ops: &T,
 ) -> W::Value
 where
@@ -428,7 +950,7 @@ unsafe fn process_32_to_255<T: ArchOps, W: EnhancedCrcWidth>(
     data: &[u8],
     state: &mut CrcState<T::Vector>,
     reflector: &Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 27],
     ops: &T,
 ) -> W::Value
 where
@@ -512,7 +1034,7 @@ struct DataRegion<'a> {
 unsafe fn get_last_two_xmms<T: ArchOps, W: EnhancedCrcWidth>(
     region: DataRegion,
     current_state: T::Vector,
-    keys: [u64; 23],
+    keys: [u64; 27],
     reflector: &Reflector<T::Vector>,
     reflected: bool,
     ops: &T,
@@ -588,3 +1110,199 @@ where
         temp_state.value
     }
 }
+
+#[cfg(all(test, target_arch = "x86_64"))]
+mod tests {
+    use super::*;
+    use crate::arch::x86::sse::X86SsePclmulqdqOps;
+    use crate::structs::{Width32, Width64};
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use crate::test::enums::AnyCrcTestConfig;
+    use core::arch::x86_64::__m128i;
+    use rand::{rng, Rng};
+
+    /// Skipping for Miri runs due to time constraints, underlying code already covered by other
+    /// tests.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_wide_simd_chunks() {
+        for config in TEST_ALL_CONFIGS {
+            // Odd and even block counts, so the trailing 128-byte chunk is covered
+            for block_count in [4, 5, 6, 7, 8, 17, 8192] {
+                test_wide_blocks(block_count, config);
+            }
+        }
+    }
+
+    /// Skipping for Miri runs due to time constraints, underlying code already covered by other
+    /// tests.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_multi_simd_chunks() {
+        let mut data = vec![0u8; 8192 + 129];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            // mixed widths, with CRC-32/BZIP2 (forward) and CRC-64/NVME (reflected)
+            let configs = [config, &TEST_ALL_CONFIGS[3], &TEST_ALL_CONFIGS[15]];
+            let params = configs.map(|config| *config.get_params());
+            let calculators = params.map(|params| crate::get_calculator(&params));
+
+            for len in [0, 255, 256, 1024, 1100, data.len()] {
+                let mut states = params.map(|params| params.init);
+
+                // SSE has no enhanced multi-block path, so this exercises the generic one
+                unsafe {
+                    update_multi(
+                        &mut states,
+                        &data[..len],
+                        &params,
+                        &calculators,
+                        &X86SsePclmulqdqOps,
+                    );
+                }
+
+                for (i, config) in configs.iter().enumerate() {
+                    assert_eq!(
+                        states[i] ^ params[i].xorout,
+                        config.checksum_with_reference(&data[..len]),
+                        "Mismatch CRC, {}, length {}",
+                        config.get_name(),
+                        len
+                    );
+                }
+            }
+        }
+    }
+
+    /// Skipping for Miri runs due to time constraints, underlying code already covered by other
+    /// tests.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_update_copy() {
+        let mut data = vec![0u8; 8192 + 129 + 32];
+        rng().fill(&mut data[..]);
+        let mut dst = vec![0u8; data.len()];
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+            let calculator = crate::get_calculator(&params);
+
+            for len in [0, 127, 256, 1100, 8192 + 129] {
+                // aligned and misaligned destinations, which disable non-temporal stores
+                for (src_offset, dst_offset) in [(0, 0), (3, 0), (0, 5), (16, 32)] {
+                    for non_temporal in [false, true] {
+                        let src = &data[src_offset..src_offset + len];
+                        let dst = &mut dst[dst_offset..dst_offset + len];
+                        dst.fill(0);
+
+                        let actual = unsafe {
+                            update_copy(
+                                params.init,
+                                src,
+                                dst,
+                                params,
+                                calculator,
+                                non_temporal,
+                                &X86SsePclmulqdqOps,
+                            )
+                        };
+
+                        assert_eq!(
+                            actual ^ params.xorout,
+                            config.checksum_with_reference(src),
+                            "Mismatch CRC, {}, length {}, offsets {} and {}",
+                            config.get_name(),
+                            len,
+                            src_offset,
+                            dst_offset
+                        );
+                        assert_eq!(dst, src, "Copy mismatch, {}", config.get_name());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_wide_simd_chunks_requires_wide_keys() {
+        let params = *TEST_ALL_CONFIGS[0].get_params();
+        let mut keys = extract_keys_array(params);
+        keys[21] = 0;
+
+        let blocks = random_blocks(8);
+        let ops = X86SsePclmulqdqOps;
+
+        unsafe {
+            let mut state = Width32::create_state(0, params.refin, &ops);
+            let (first, rest) = blocks.split_first().unwrap();
+
+            assert!(!process_wide_simd_chunks::<_, Width32>(
+                &mut state,
+                first,
+                rest,
+                &Reflector::NoReflector,
+                keys,
+                &ops,
+            ));
+        }
+    }
+
+    fn test_wide_blocks(block_count: usize, config: &AnyCrcTestConfig) {
+        let blocks = random_blocks(block_count);
+        let bytes =
+            unsafe { core::slice::from_raw_parts(blocks.as_ptr() as *const u8, block_count * 128) };
+
+        let params = *config.get_params();
+        let actual = match params.width {
+            64 => unsafe { wide_update::<Width64>(params.init, &blocks, params) },
+            _ => unsafe { wide_update::<Width32>(params.init as u32, &blocks, params) as u64 },
+        } ^ params.xorout;
+
+        assert_eq!(
+            actual,
+            config.checksum_with_reference(bytes),
+            "Mismatch CRC, {}, length {}",
+            config.get_name(),
+            bytes.len()
+        );
+    }
+
+    fn random_blocks(block_count: usize) -> Vec<[__m128i; 8]> {
+        let mut data = vec![0u8; block_count * 128];
+        rng().fill(&mut data[..]);
+
+        data.chunks_exact(128)
+            .map(|chunk| unsafe { core::ptr::read_unaligned(chunk.as_ptr() as *const _) })
+            .collect()
+    }
+
+    unsafe fn wide_update<W: EnhancedCrcWidth>(
+        state: W::Value,
+        blocks: &[[__m128i; 8]],
+        params: CrcParams,
+    ) -> W::Value {
+        let ops = X86SsePclmulqdqOps;
+
+        let reflector = if params.refin {
+            Reflector::NoReflector
+        } else {
+            let smask = ops.load_aligned(&W::load_constants(params.refin)[0] as *const [u64; 2]);
+            Reflector::ForwardReflector { smask }
+        };
+
+        let mut crc_state = W::create_state(state, params.refin, &ops);
+        let (first, rest) = blocks.split_first().unwrap();
+
+        assert!(process_wide_simd_chunks::<_, W>(
+            &mut crc_state,
+            first,
+            rest,
+            &reflector,
+            extract_keys_array(params),
+            &ops,
+        ));
+
+        W::extract_result(crc_state.value, crc_state.reflected, &ops)
+    }
+}
@@ -0,0 +1,893 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Error-detection analysis for CRC polynomials.
+//!
+//! Computes the Hamming distance (HD) a polynomial guarantees at a given message length, the
+//! number of undetectable error patterns at that weight, and the burst-detection length, in the
+//! style of Philip Koopman's [CRC polynomial zoo](https://users.ece.cmu.edu/~koopman/crc/).
+//!
+//! Only the polynomial (and width) matters here: `init`, `xorout` and reflection don't change
+//! which error patterns go undetected, so every catalogue entry sharing a polynomial shares the
+//! same analysis.
+//!
+//! The search is exact but expensive, so it covers error patterns of up to 6 bits, and each
+//! weight is only searched up to a fixed codeword length (2^22 bits for 2 and 3-bit errors, 2^16
+//! for 4-bit, 2^12 for 5-bit and 2^9 for 6-bit). Where a search was cut short, the result is
+//! reported as [`HammingDistance::AtLeast`] rather than guessed.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::analysis::{hamming_distance, HammingDistance};
+//! use crc_fast::CrcParams;
+//!
+//! let params = CrcParams::new("CRC-32/CUSTOM", 32, 0x04c11db7, 0xffffffff, true, 0xffffffff, 0xcbf43926);
+//!
+//! // CRC-32 detects every 5-bit error in a 32-byte message...
+//! assert_eq!(hamming_distance(params, 32 * 8), HammingDistance::Exact(6));
+//!
+//! // ...but only every 3-bit error once the message is 1 KiB.
+//! assert_eq!(hamming_distance(params, 1024 * 8), HammingDistance::Exact(4));
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use crate::enums::CATALOGUE;
+use crate::{CrcAlgorithm, CrcParams};
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+
+/// The heaviest error pattern the analysis searches for. Anything not found by then is reported
+/// as `AtLeast(MAX_SEARCH_WEIGHT + 1)`.
+const MAX_SEARCH_WEIGHT: u32 = 6;
+
+/// Longest codeword (message + CRC), in bits, the analysis will consider at all.
+const MAX_CODEWORD_BITS: u64 = 1 << 22;
+
+/// Longest codeword searched for each error weight, indexed by weight. The cost of an exhaustive
+/// search grows as `bits^((weight - 1) / 2)` (or worse), so heavier patterns get shorter windows.
+/// The weight-5 limit is also bounded by memory, since its pair table needs ~128 MiB at 4096 bits.
+const WEIGHT_SEARCH_LIMITS: [usize; MAX_SEARCH_WEIGHT as usize + 1] =
+    [0, 0, 1 << 22, 1 << 22, 1 << 16, 1 << 12, 1 << 9];
+
+/// The Hamming distance of a CRC at some message length: the fewest bit flips that can go
+/// undetected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HammingDistance {
+    /// An undetectable error pattern of exactly this weight exists.
+    Exact(u32),
+    /// No undetectable pattern lighter than this was found, but the search stopped here.
+    AtLeast(u32),
+}
+
+impl HammingDistance {
+    /// Returns the guaranteed minimum, which is exact for [`HammingDistance::Exact`].
+    pub fn value(self) -> u32 {
+        match self {
+            HammingDistance::Exact(hd) | HammingDistance::AtLeast(hd) => hd,
+        }
+    }
+}
+
+impl Ord for HammingDistance {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // AtLeast(n) might be better than n, Exact(n) never is
+        let rank = |hd: &HammingDistance| (hd.value(), matches!(hd, HammingDistance::AtLeast(_)));
+
+        rank(self).cmp(&rank(other))
+    }
+}
+
+impl PartialOrd for HammingDistance {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for HammingDistance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HammingDistance::Exact(hd) => write!(f, "{hd}"),
+            HammingDistance::AtLeast(hd) => write!(f, ">={hd}"),
+        }
+    }
+}
+
+/// The longest message for which a Hamming distance holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HdRange {
+    /// The Hamming distance over this range.
+    pub hd: HammingDistance,
+    /// The longest message, in bits and excluding the CRC itself, for which `hd` holds.
+    pub max_data_bits: u64,
+}
+
+/// The number of undetectable error patterns at the minimum weight for a message length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UndetectedErrors {
+    /// The weight (number of flipped bits) of the patterns, which is the Hamming distance.
+    pub weight: u32,
+    /// How many distinct patterns of that weight, across the message and CRC, go undetected.
+    pub count: u64,
+}
+
+/// Error-detection properties of a CRC polynomial up to some message length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolynomialAnalysis {
+    /// CRC width in bits.
+    pub width: u8,
+    /// Polynomial, in normal (non-reflected) notation without the leading term.
+    pub poly: u64,
+    /// Every error burst of at most this many bits is detected.
+    pub burst_detection_bits: u32,
+    /// Hamming distance versus message length, shortest messages (and highest HD) first. The
+    /// first range starts at 1 bit, and each following range starts where the previous one ended.
+    pub hd_profile: Vec<HdRange>,
+}
+
+/// The error-detection properties of a catalogue algorithm at a particular payload size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CatalogueRanking {
+    /// The catalogue algorithm.
+    pub algorithm: CrcAlgorithm,
+    /// The algorithm's catalogue name, such as `CRC-32/ISCSI`.
+    pub name: &'static str,
+    /// CRC width in bits.
+    pub width: u8,
+    /// Hamming distance at the payload size.
+    pub hd: HammingDistance,
+    /// Undetectable patterns at the Hamming distance, if it's exact.
+    pub undetected: Option<UndetectedErrors>,
+}
+
+/// Analyzes the polynomial in `params` for messages up to `max_data_bits` long.
+///
+/// Runtime is roughly quadratic in message length once HD drops to 4, so analyzing
+/// multi-kilobyte messages takes a while, particularly in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::analysis::{analyze, HammingDistance};
+/// use crc_fast::CrcParams;
+///
+/// let params = CrcParams::new("CRC-32/CUSTOM", 32, 0x04c11db7, 0xffffffff, true, 0xffffffff, 0xcbf43926);
+/// let analysis = analyze(params, 4000);
+///
+/// assert_eq!(analysis.burst_detection_bits, 32);
+///
+/// // HD=5 holds up to 2974 bits, then HD=4 takes over
+/// assert_eq!(analysis.hd_profile[2].hd, HammingDistance::Exact(5));
+/// assert_eq!(analysis.hd_profile[2].max_data_bits, 2974);
+/// assert_eq!(analysis.hd_profile[3].hd, HammingDistance::Exact(4));
+/// ```
+pub fn analyze(params: CrcParams, max_data_bits: u64) -> PolynomialAnalysis {
+    let code = Code::from_params(&params);
+    let codeword_bits = code.codeword_bits(max_data_bits);
+
+    // ranges too short to hold any message at all aren't interesting
+    let hd_profile = code
+        .profile(codeword_bits)
+        .into_iter()
+        .filter_map(|(hd, max_codeword_bits)| {
+            code.data_bits(max_codeword_bits)
+                .filter(|bits| *bits > 0)
+                .map(|max_data_bits| HdRange { hd, max_data_bits })
+        })
+        .collect();
+
+    PolynomialAnalysis {
+        width: params.width,
+        poly: params.poly,
+        burst_detection_bits: burst_detection_length(params),
+        hd_profile,
+    }
+}
+
+/// Returns the Hamming distance of the polynomial in `params` for messages of `data_bits` bits.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::analysis::{hamming_distance, HammingDistance};
+/// use crc_fast::CrcParams;
+///
+/// // CRC-32/ISCSI (Castagnoli)
+/// let params = CrcParams::new("CRC-32/CUSTOM", 32, 0x1edc6f41, 0xffffffff, true, 0xffffffff, 0xe3069283);
+///
+/// assert_eq!(hamming_distance(params, 512), HammingDistance::Exact(6));
+/// ```
+pub fn hamming_distance(params: CrcParams, data_bits: u64) -> HammingDistance {
+    let code = Code::from_params(&params);
+
+    code.profile(code.codeword_bits(data_bits))
+        .last()
+        .map(|(hd, _)| *hd)
+        .unwrap_or(HammingDistance::AtLeast(MAX_SEARCH_WEIGHT + 1))
+}
+
+/// Counts the undetectable error patterns at the Hamming distance for messages of `data_bits`
+/// bits.
+///
+/// Returns `None` when the Hamming distance is beyond the search limits, or when counting every
+/// pattern of that weight at this length would be, since the result wouldn't be exact.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::analysis::{undetected_errors, UndetectedErrors};
+/// use crc_fast::CrcParams;
+///
+/// let params = CrcParams::new("CRC-32/CUSTOM", 32, 0x04c11db7, 0xffffffff, true, 0xffffffff, 0xcbf43926);
+/// let errors = undetected_errors(params, 256).unwrap();
+///
+/// assert_eq!(errors.weight, 6);
+/// ```
+pub fn undetected_errors(params: CrcParams, data_bits: u64) -> Option<UndetectedErrors> {
+    let code = Code::from_params(&params);
+    let codeword_bits = code.codeword_bits(data_bits);
+
+    match code.profile(codeword_bits).last() {
+        Some((HammingDistance::Exact(weight), _))
+            if codeword_bits <= WEIGHT_SEARCH_LIMITS[*weight as usize] =>
+        {
+            Some(UndetectedErrors {
+                weight: *weight,
+                count: code.count(*weight, codeword_bits),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the longest error burst the polynomial in `params` is guaranteed to detect.
+///
+/// Any polynomial with a `+1` term detects every burst up to its width, which covers every
+/// polynomial in the catalogue.
+pub fn burst_detection_length(params: CrcParams) -> u32 {
+    params.width as u32 - trailing_zeros(params.poly, params.width)
+}
+
+/// Ranks every catalogue algorithm by how well it protects a payload of `data_bytes` bytes.
+///
+/// Orders by Hamming distance first (highest first), then by the number of undetectable patterns
+/// at that distance (fewest first), then by width (widest first), which is the usual way of
+/// choosing between polynomials.
+///
+/// Algorithms that share a polynomial are only analyzed once, but this can still take several
+/// seconds for multi-kilobyte payloads.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::analysis::rank_catalogue;
+///
+/// let ranking = rank_catalogue(16);
+///
+/// assert_eq!(ranking.len(), 19);
+/// assert!(ranking.windows(2).all(|pair| pair[0].hd >= pair[1].hd));
+/// ```
+pub fn rank_catalogue(data_bytes: usize) -> Vec<CatalogueRanking> {
+    let data_bits = data_bytes as u64 * 8;
+    let mut analyzed: Vec<(u8, u64, HammingDistance, Option<UndetectedErrors>)> = Vec::new();
+    let mut ranking = Vec::with_capacity(CATALOGUE.len());
+
+    for algorithm in CATALOGUE {
+        let params = crate::get_calculator_params(algorithm).1;
+
+        let cached = analyzed
+            .iter()
+            .find(|(width, poly, _, _)| *width == params.width && *poly == params.poly);
+
+        let (hd, undetected) = match cached {
+            Some((_, _, hd, undetected)) => (*hd, *undetected),
+            None => {
+                let hd = hamming_distance(params, data_bits);
+                let undetected = undetected_errors(params, data_bits);
+                analyzed.push((params.width, params.poly, hd, undetected));
+
+                (hd, undetected)
+            }
+        };
+
+        ranking.push(CatalogueRanking {
+            algorithm,
+            name: params.name,
+            width: params.width,
+            hd,
+            undetected,
+        });
+    }
+
+    ranking.sort_by(|a, b| {
+        b.hd.cmp(&a.hd)
+            .then_with(|| {
+                let count = |r: &CatalogueRanking| r.undetected.map_or(0, |u| u.count);
+                count(a).cmp(&count(b))
+            })
+            .then_with(|| b.width.cmp(&a.width))
+    });
+
+    ranking
+}
+
+/// Number of trailing zero bits in a `width`-bit polynomial.
+fn trailing_zeros(poly: u64, width: u8) -> u32 {
+    if poly == 0 {
+        width as u32
+    } else {
+        poly.trailing_zeros().min(width as u32)
+    }
+}
+
+/// A CRC polynomial reduced to the form the search needs.
+///
+/// A polynomial without a `+1` term is `x^k * Q(x)`, and its codewords are exactly `Q`'s codewords
+/// shifted up by `k`, so the search runs on `Q` and adjusts lengths by `k`.
+struct Code {
+    /// The full CRC width, for converting between codeword and data lengths.
+    width: u32,
+    /// Width of the reduced polynomial.
+    degree: u32,
+    /// Reduced polynomial, without its leading term.
+    poly: u64,
+    /// Bits shifted out of the original polynomial.
+    shift: u32,
+    /// Whether the polynomial is divisible by `x + 1`, in which case every odd-weight error is
+    /// detected and odd weights can be skipped.
+    even_parity: bool,
+}
+
+impl Code {
+    fn from_params(params: &CrcParams) -> Self {
+        let width = params.width as u32;
+        let shift = trailing_zeros(params.poly, params.width);
+        let degree = width - shift;
+
+        // x^degree + poly >> shift, with the leading term kept implicit
+        let poly = if degree == 0 {
+            0
+        } else {
+            (params.poly >> shift) & mask(degree)
+        };
+
+        // G(1) is the parity of all terms, including the implicit leading one
+        let even_parity = (poly.count_ones() + 1) % 2 == 0;
+
+        Self {
+            width,
+            degree,
+            poly,
+            shift,
+            even_parity,
+        }
+    }
+
+    /// Codeword length for a message, capped to what the search supports.
+    fn codeword_bits(&self, data_bits: u64) -> usize {
+        (data_bits + self.width as u64).min(MAX_CODEWORD_BITS) as usize
+    }
+
+    /// Message length for a codeword, if there's room for any message at all.
+    fn data_bits(&self, codeword_bits: usize) -> Option<u64> {
+        (codeword_bits as u64).checked_sub(self.width as u64)
+    }
+
+    /// Residues `x^i mod G` for every bit position of the reduced codeword.
+    fn residues(&self, codeword_bits: usize) -> Vec<u64> {
+        let bits = codeword_bits.saturating_sub(self.shift as usize);
+        let mut residues = Vec::with_capacity(bits);
+        let top = 1u64 << (self.degree - 1);
+        let mut residue = 1u64;
+
+        for _ in 0..bits {
+            residues.push(residue);
+            let carry = residue & top != 0;
+            residue = (residue << 1) & mask(self.degree);
+            if carry {
+                residue ^= self.poly;
+            }
+        }
+
+        residues
+    }
+
+    /// Hamming distance versus codeword length, as `(hd, longest codeword)` ranges from the
+    /// shortest codewords up to `codeword_bits`.
+    ///
+    /// Weights are searched in increasing order, each only over codewords shorter than the first
+    /// lighter pattern found. That keeps the search exact (HD is the lightest pattern that fits)
+    /// and guarantees the lookup tables below never see a collision.
+    fn profile(&self, codeword_bits: usize) -> Vec<(HammingDistance, usize)> {
+        let mut ranges = Vec::new();
+
+        if self.degree == 0 {
+            // G(x) = x^k divides every error past the first k bits, so nothing is guaranteed
+            ranges.push((HammingDistance::Exact(1), codeword_bits));
+            return ranges;
+        }
+
+        let residues = self.residues(codeword_bits);
+        let search = Search::new(&residues);
+
+        // the search runs over the reduced polynomial, which is `shift` bits shorter
+        let shift = self.shift as usize;
+        let mut limit = residues.len();
+
+        for weight in 2..=MAX_SEARCH_WEIGHT {
+            if limit == 0 {
+                break;
+            }
+
+            if self.even_parity && weight % 2 == 1 {
+                continue;
+            }
+
+            let window = limit.min(WEIGHT_SEARCH_LIMITS[weight as usize]);
+
+            match search.first(weight, window) {
+                Some(span) => {
+                    // the pattern fits any codeword at least span + 1 bits long
+                    ranges.push((HammingDistance::Exact(weight), limit + shift));
+                    limit = span;
+                }
+                None if window < limit => {
+                    // not found, but longer codewords weren't searched
+                    ranges.push((self.at_least(weight), limit + shift));
+                    limit = window;
+                }
+                None => {}
+            }
+        }
+
+        if limit > 0 {
+            ranges.push((self.at_least(MAX_SEARCH_WEIGHT + 1), limit + shift));
+        }
+
+        ranges.reverse();
+
+        ranges
+    }
+
+    /// A lower bound for HD, rounded up when odd weights are impossible.
+    fn at_least(&self, weight: u32) -> HammingDistance {
+        if self.even_parity && weight % 2 == 1 {
+            HammingDistance::AtLeast(weight + 1)
+        } else {
+            HammingDistance::AtLeast(weight)
+        }
+    }
+
+    /// Counts every undetectable pattern of `weight` bits in a codeword of `codeword_bits`.
+    ///
+    /// Only valid when `weight` is the Hamming distance at this length, since the search tables
+    /// rely on there being no lighter patterns.
+    fn count(&self, weight: u32, codeword_bits: usize) -> u64 {
+        let residues = self.residues(codeword_bits);
+        let search = Search::new(&residues);
+        let bits = residues.len() as u64;
+        let mut count = 0u64;
+
+        // a pattern spanning `span` positions fits in bits - span places
+        search.each(weight, residues.len(), |span| {
+            count += bits - span as u64;
+            true
+        });
+
+        count
+    }
+}
+
+/// All-ones mask for a `width`-bit value.
+fn mask(width: u32) -> u64 {
+    if width >= 64 {
+        u64::MAX
+    } else {
+        (1u64 << width) - 1
+    }
+}
+
+/// Exhaustive search for undetectable error patterns.
+///
+/// An error pattern `E(x)` is undetectable exactly when `G(x)` divides it, i.e. when the residues
+/// `x^i mod G` of its set bits XOR to zero. Because `G` has a `+1` term, `x` is invertible mod `G`,
+/// so every pattern can be shifted down until its lowest bit is `x^0`. The search only looks at
+/// those anchored patterns, identified by their highest bit (the span), and meets in the middle
+/// using tables of single and paired residues.
+struct Search<'a> {
+    residues: &'a [u64],
+}
+
+impl<'a> Search<'a> {
+    fn new(residues: &'a [u64]) -> Self {
+        Self { residues }
+    }
+
+    /// The span of the shortest anchored pattern of `weight` bits within `window` positions.
+    fn first(&self, weight: u32, window: usize) -> Option<usize> {
+        let mut first = None;
+
+        self.each(weight, window, |span| {
+            first = Some(span);
+            false
+        });
+
+        first
+    }
+
+    /// Visits every anchored pattern of `weight` bits within `window` positions, in increasing
+    /// span order, until `visit` returns false.
+    fn each(&self, weight: u32, window: usize, mut visit: impl FnMut(usize) -> bool) {
+        let r = self.residues;
+        let window = window.min(r.len());
+
+        match weight {
+            // 1 + x^d
+            2 => {
+                for d in 1..window {
+                    if r[d] == r[0] && !visit(d) {
+                        return;
+                    }
+                }
+            }
+            // 1 + x^a + x^d
+            3 => {
+                let singles = self.singles(window);
+                for d in 2..window {
+                    if let Some(a) = singles.find_single(r, r[0] ^ r[d]) {
+                        if a >= 1 && a < d && !visit(d) {
+                            return;
+                        }
+                    }
+                }
+            }
+            // 1 + x^a + x^b + x^d
+            4 => {
+                let singles = self.singles(window);
+                for d in 3..window {
+                    for b in 2..d {
+                        if let Some(a) = singles.find_single(r, r[0] ^ r[b] ^ r[d]) {
+                            if a >= 1 && a < b && !visit(d) {
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+            // 1 + x^a + x^b + x^c + x^d
+            5 => {
+                let pairs = self.pairs(window);
+                for d in 4..window {
+                    for c in 3..d {
+                        if let Some((a, b)) = pairs.find_pair(r, r[0] ^ r[c] ^ r[d]) {
+                            if a >= 1 && b < c && !visit(d) {
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+            // 1 + x^a + x^b + x^c + x^d + x^e
+            6 => {
+                let pairs = self.pairs(window);
+                for e in 5..window {
+                    for d in 4..e {
+                        for c in 3..d {
+                            let key = r[0] ^ r[c] ^ r[d] ^ r[e];
+                            if let Some((a, b)) = pairs.find_pair(r, key) {
+                                if a >= 1 && b < c && !visit(e) {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            _ => unreachable!("weights above {MAX_SEARCH_WEIGHT} aren't searched"),
+        }
+    }
+
+    /// Lookup table of single residues within `window`.
+    ///
+    /// Built per search rather than shared, since a table sized for the window keeps probes in
+    /// cache, which matters far more than the cost of building it.
+    fn singles(&self, window: usize) -> Table {
+        let mut table = Table::with_capacity(window);
+        for (i, residue) in self.residues[..window].iter().enumerate() {
+            table.insert(*residue, i as u32);
+        }
+
+        table
+    }
+
+    /// Lookup table of paired residues within `window`.
+    fn pairs(&self, window: usize) -> Table {
+        let r = self.residues;
+        let mut table = Table::with_capacity(window * window.saturating_sub(1) / 2);
+        for b in 1..window {
+            for a in 0..b {
+                table.insert(r[a] ^ r[b], ((a << 16) | b) as u32);
+            }
+        }
+
+        table
+    }
+}
+
+/// Open-addressed hash set of residue positions.
+///
+/// Each slot packs a position (or a pair of positions) with a 32-bit fingerprint of its key, so
+/// the common case of a miss costs a single cache access. Full keys are recomputed from the
+/// residues only when a fingerprint matches.
+struct Table {
+    slots: Vec<u64>,
+    shift: u32,
+}
+
+impl Table {
+    const EMPTY: u64 = u64::MAX;
+
+    fn with_capacity(entries: usize) -> Self {
+        let size = (entries.max(1) * 2).next_power_of_two();
+
+        Self {
+            slots: vec![Self::EMPTY; size],
+            shift: 64 - size.trailing_zeros(),
+        }
+    }
+
+    /// Home slot and fingerprint for a key.
+    #[inline(always)]
+    fn hash(&self, key: u64) -> (usize, u64) {
+        // Fibonacci hashing spreads the (already fairly random) residues across the table
+        let hash = key.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+
+        ((hash >> self.shift) as usize, hash << 32)
+    }
+
+    #[inline(always)]
+    fn next(&self, slot: usize) -> usize {
+        (slot + 1) & (self.slots.len() - 1)
+    }
+
+    fn insert(&mut self, key: u64, value: u32) {
+        let (mut slot, fingerprint) = self.hash(key);
+        while self.slots[slot] != Self::EMPTY {
+            slot = self.next(slot);
+        }
+        self.slots[slot] = fingerprint | value as u64;
+    }
+
+    /// Probes for `key`, calling `matches` with each stored value whose fingerprint agrees.
+    #[inline(always)]
+    fn find(&self, key: u64, matches: impl Fn(u32) -> bool) -> Option<u32> {
+        let (mut slot, fingerprint) = self.hash(key);
+        loop {
+            let entry = self.slots[slot];
+            if entry == Self::EMPTY {
+                return None;
+            }
+            let value = entry as u32;
+            if entry & !0xffff_ffff == fingerprint && matches(value) {
+                return Some(value);
+            }
+            slot = self.next(slot);
+        }
+    }
+
+    #[inline(always)]
+    fn find_single(&self, residues: &[u64], key: u64) -> Option<usize> {
+        self.find(key, |value| residues[value as usize] == key)
+            .map(|value| value as usize)
+    }
+
+    #[inline(always)]
+    fn find_pair(&self, residues: &[u64], key: u64) -> Option<(usize, usize)> {
+        let unpack = |value: u32| ((value >> 16) as usize, (value & 0xffff) as usize);
+
+        self.find(key, |value| {
+            let (a, b) = unpack(value);
+            residues[a] ^ residues[b] == key
+        })
+        .map(unpack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crc32::consts::{CRC32_ISCSI, CRC32_ISO_HDLC, CRC32_XFER};
+    use crate::crc64::consts::CRC64_NVME;
+
+    /// Brute-force minimum weight of any undetectable pattern in a short codeword.
+    fn brute_force_hd(poly: u64, width: u32, codeword_bits: usize) -> u32 {
+        let mut best = u32::MAX;
+
+        for pattern in 1u64..(1 << codeword_bits) {
+            // polynomial remainder of the pattern, high bit first
+            let mut remainder = 0u64;
+            for i in (0..codeword_bits).rev() {
+                let carry = (remainder >> (width - 1)) & 1;
+                remainder = ((remainder << 1) | ((pattern >> i) & 1)) & mask(width);
+                if carry == 1 {
+                    remainder ^= poly;
+                }
+            }
+            // the shift register needs `width` more zero bits to reduce fully
+            for _ in 0..width {
+                let carry = (remainder >> (width - 1)) & 1;
+                remainder = (remainder << 1) & mask(width);
+                if carry == 1 {
+                    remainder ^= poly;
+                }
+            }
+            if remainder == 0 {
+                best = best.min(pattern.count_ones());
+            }
+        }
+
+        best
+    }
+
+    #[test]
+    fn test_crc32_iso_hdlc_matches_koopman() {
+        // Koopman: HD=7 to 171 bits, HD=6 to 268, HD=5 to 2974, HD=4 to 91607
+        let analysis = analyze(CRC32_ISO_HDLC, 4000);
+
+        assert_eq!(
+            analysis.hd_profile,
+            vec![
+                HdRange {
+                    hd: HammingDistance::AtLeast(7),
+                    max_data_bits: 171
+                },
+                HdRange {
+                    hd: HammingDistance::Exact(6),
+                    max_data_bits: 268
+                },
+                HdRange {
+                    hd: HammingDistance::Exact(5),
+                    max_data_bits: 2974
+                },
+                HdRange {
+                    hd: HammingDistance::Exact(4),
+                    max_data_bits: 4000
+                },
+            ]
+        );
+        assert_eq!(analysis.burst_detection_bits, 32);
+    }
+
+    #[test]
+    fn test_crc32_iscsi_matches_koopman() {
+        // Koopman: HD=8 to 177 bits, HD=6 to 5243; odd weights are always detected
+        assert_eq!(
+            hamming_distance(CRC32_ISCSI, 177),
+            HammingDistance::AtLeast(8)
+        );
+        assert_eq!(
+            hamming_distance(CRC32_ISCSI, 178),
+            HammingDistance::Exact(6)
+        );
+        assert_eq!(
+            hamming_distance(CRC32_ISCSI, 992),
+            HammingDistance::Exact(6)
+        );
+    }
+
+    #[test]
+    fn test_search_limits_are_reported() {
+        // weight 6 is only searched up to 512-bit codewords, but CRC-32/ISCSI's first weight-6
+        // pattern is far shorter than that, so HD stays exact past the limit
+        assert_eq!(
+            hamming_distance(CRC32_ISCSI, 2048),
+            HammingDistance::Exact(6)
+        );
+
+        // CRC-64/NVME has nothing as light as the heaviest searched weight in a short message
+        assert_eq!(
+            hamming_distance(CRC64_NVME, 256),
+            HammingDistance::AtLeast(MAX_SEARCH_WEIGHT + 1)
+        );
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        // small polynomials, where every pattern can be enumerated
+        for (poly, width) in [(0x07u64, 8u32), (0x1d, 8), (0x31, 8), (0x9b, 8), (0x2f, 8)] {
+            let params = CrcParams {
+                width: width as u8,
+                poly,
+                ..CRC32_ISO_HDLC
+            };
+
+            for data_bits in [1u64, 4, 8] {
+                let codeword_bits = data_bits as usize + width as usize;
+                let expected = brute_force_hd(poly, width, codeword_bits);
+
+                match hamming_distance(params, data_bits) {
+                    HammingDistance::Exact(hd) => {
+                        assert_eq!(hd, expected, "poly {poly:#x}, {data_bits} data bits")
+                    }
+                    HammingDistance::AtLeast(hd) => {
+                        assert!(expected >= hd, "poly {poly:#x}, {data_bits} data bits")
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_undetected_errors_match_brute_force() {
+        // CRC-8 0x07 has HD=4 at 12 data bits
+        let params = CrcParams {
+            width: 8,
+            poly: 0x07,
+            ..CRC32_ISO_HDLC
+        };
+        let codeword_bits = 20;
+
+        let mut expected = 0u64;
+        for pattern in 1u64..(1 << codeword_bits) {
+            if pattern.count_ones() != 4 {
+                continue;
+            }
+            let mut remainder = 0u64;
+            for i in (0..codeword_bits + 8).rev() {
+                let bit = if i >= 8 { (pattern >> (i - 8)) & 1 } else { 0 };
+                let carry = (remainder >> 7) & 1;
+                remainder = ((remainder << 1) | bit) & 0xff;
+                if carry == 1 {
+                    remainder ^= 0x07;
+                }
+            }
+            if remainder == 0 {
+                expected += 1;
+            }
+        }
+
+        let errors = undetected_errors(params, 12).unwrap();
+        assert_eq!(errors.weight, 4);
+        assert_eq!(errors.count, expected);
+    }
+
+    #[test]
+    fn test_burst_detection_length() {
+        assert_eq!(burst_detection_length(CRC32_ISO_HDLC), 32);
+        assert_eq!(burst_detection_length(CRC64_NVME), 64);
+
+        // x^32 + ... + x^2 only detects bursts up to its odd part
+        let params = CrcParams {
+            poly: 0x04c11db4,
+            ..CRC32_ISO_HDLC
+        };
+        assert_eq!(burst_detection_length(params), 30);
+    }
+
+    #[test]
+    fn test_hamming_distance_ordering() {
+        assert!(HammingDistance::Exact(5) > HammingDistance::Exact(4));
+        assert!(HammingDistance::AtLeast(4) > HammingDistance::Exact(4));
+        assert!(HammingDistance::AtLeast(4) < HammingDistance::Exact(5));
+    }
+
+    #[test]
+    fn test_rank_catalogue() {
+        let ranking = rank_catalogue(8);
+
+        assert_eq!(ranking.len(), CATALOGUE.len());
+        assert!(ranking.windows(2).all(|pair| pair[0].hd >= pair[1].hd));
+
+        // CRC-32/XFER's polynomial is far weaker than the others
+        let xfer = ranking
+            .iter()
+            .position(|r| r.algorithm == CRC32_XFER.algorithm)
+            .unwrap();
+        let iscsi = ranking
+            .iter()
+            .position(|r| r.algorithm == CRC32_ISCSI.algorithm)
+            .unwrap();
+        assert!(iscsi < xfer);
+    }
+}
@@ -90,6 +90,12 @@ impl ArchOps for Aarch64AesOps {
         vreinterpretq_u8_u64(vld1q_u64(ptr as *const u64))
     }
 
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn store_bytes(&self, ptr: *mut u8, vector: Self::Vector) {
+        vst1q_u8(ptr, vector)
+    }
+
     #[inline]
     #[target_feature(enable = "neon")]
     unsafe fn shuffle_bytes(&self, data: Self::Vector, mask: Self::Vector) -> Self::Vector {
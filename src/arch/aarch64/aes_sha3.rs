@@ -2,11 +2,17 @@
 
 //! This module provides AArch64-specific implementations of the ArchOps trait for architectures
 //! with AES+SHA3 support.
+//!
+//! Large buffers fold with 16 accumulators at 256-byte distances. The 512 and 1024-byte wide
+//! keys are only used by the AVX-512 VPCLMULQDQ tier.
 
 #![cfg(target_arch = "aarch64")]
 
+use crate::algorithm;
 use crate::arch::aarch64::aes::Aarch64AesOps;
-use crate::traits::ArchOps;
+use crate::enums::Reflector;
+use crate::structs::CrcState;
+use crate::traits::{ArchOps, EnhancedCrcWidth};
 use core::arch::aarch64::*;
 
 /// AArch64 AES+SHA3 tier - delegates to AES tier and overrides XOR3 operations
@@ -16,7 +22,7 @@ pub struct Aarch64AesSha3Ops(Aarch64AesOps);
 
 impl Aarch64AesSha3Ops {
     #[inline(always)]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self(Aarch64AesOps)
     }
 }
@@ -25,6 +31,23 @@ impl Aarch64AesSha3Ops {
 impl ArchOps for Aarch64AesSha3Ops {
     type Vector = uint8x16_t;
 
+    // Large buffers fold with 16 accumulators (256 bytes at a time), which keeps more PMULL
+    // pipelines busy than the standard 8
+    #[inline(always)]
+    unsafe fn process_enhanced_simd_blocks<W: EnhancedCrcWidth>(
+        &self,
+        state: &mut CrcState<Self::Vector>,
+        first: &[Self::Vector; 8],
+        rest: &[[Self::Vector; 8]],
+        reflector: &Reflector<Self::Vector>,
+        keys: [u64; 27],
+    ) -> bool
+    where
+        Self::Vector: Copy,
+    {
+        algorithm::process_wide_simd_chunks::<Self, W>(state, first, rest, reflector, keys, self)
+    }
+
     // Delegate methods to the base AES implementation
     #[inline(always)]
     unsafe fn create_vector_from_u64_pair(
@@ -75,6 +98,11 @@ impl ArchOps for Aarch64AesSha3Ops {
         self.0.load_aligned(ptr)
     }
 
+    #[inline(always)]
+    unsafe fn store_bytes(&self, ptr: *mut u8, vector: Self::Vector) {
+        self.0.store_bytes(ptr, vector)
+    }
+
     #[inline(always)]
     unsafe fn shuffle_bytes(&self, data: Self::Vector, mask: Self::Vector) -> Self::Vector {
         self.0.shuffle_bytes(data, mask)
@@ -1,375 +1,688 @@
 // Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
 
 //! This module contains a software fallback for unsupported architectures.
+//!
+//! Uses slice-by-8 lookup tables, which process 8 bytes per step with 8 independent table
+//! lookups. Slice-by-16 is slightly faster on some CPUs, but doubles the table size, which
+//! matters most on the small targets that actually end up here.
+//!
+//! Tables for the predefined algorithms are generated at compile time, and shared between
+//! algorithms with the same polynomial and reflection. Tables for custom parameters are generated
+//! on first use and cached by polynomial when `std` or `cache` is enabled, for a bounded number
+//! of polynomials per width.
+
+use crate::{CrcAlgorithm, CrcParams};
 
-use crate::consts::CRC_64_NVME;
-use crate::CrcAlgorithm;
-use crate::CrcParams;
-#[cfg(feature = "alloc")]
-use crc::Algorithm;
-use crc::Table;
-
-// Caching for custom CRC algorithms to prevent repeated memory leaks
-#[cfg(feature = "alloc")]
-#[cfg(feature = "std")]
-use std::collections::HashMap;
-#[cfg(feature = "alloc")]
 #[cfg(feature = "std")]
-use std::sync::{Mutex, OnceLock};
+use std::sync::{OnceLock, RwLock};
 
-#[cfg(feature = "alloc")]
 #[cfg(all(not(feature = "std"), feature = "cache"))]
-use hashbrown::HashMap;
-#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
 #[cfg(all(not(feature = "std"), feature = "cache"))]
-use spin::{Mutex, Once};
+use spin::{Once, RwLock};
 
-// Cache key types for custom algorithms
-#[cfg(feature = "alloc")]
-#[cfg(any(feature = "std", feature = "cache"))]
-type Crc32Key = (u32, u32, bool, bool, u32, u32);
-#[cfg(feature = "alloc")]
-#[cfg(any(feature = "std", feature = "cache"))]
-type Crc64Key = (u64, u64, bool, bool, u64, u64);
+/// Slice-by-8 tables for a 32-bit CRC. `tables[k][b]` is the CRC of byte `b` followed by `k` zero
+/// bytes.
+type Tables32 = [[u32; 256]; 8];
 
-// Global caches for custom algorithms (std version)
-#[cfg(feature = "alloc")]
-#[cfg(feature = "std")]
-static CUSTOM_CRC32_CACHE: OnceLock<Mutex<HashMap<Crc32Key, &'static Algorithm<u32>>>> =
-    OnceLock::new();
-#[cfg(feature = "alloc")]
-#[cfg(feature = "std")]
-static CUSTOM_CRC64_CACHE: OnceLock<Mutex<HashMap<Crc64Key, &'static Algorithm<u64>>>> =
-    OnceLock::new();
+/// Slice-by-8 tables for a 64-bit CRC. `tables[k][b]` is the CRC of byte `b` followed by `k` zero
+/// bytes.
+type Tables64 = [[u64; 256]; 8];
 
-// Global caches for custom algorithms (no_std + cache version)
-#[cfg(feature = "alloc")]
-#[cfg(all(not(feature = "std"), feature = "cache"))]
-static CUSTOM_CRC32_CACHE: Once<Mutex<HashMap<Crc32Key, &'static Algorithm<u32>>>> = Once::new();
-#[cfg(feature = "alloc")]
-#[cfg(all(not(feature = "std"), feature = "cache"))]
-static CUSTOM_CRC64_CACHE: Once<Mutex<HashMap<Crc64Key, &'static Algorithm<u64>>>> = Once::new();
+/// Inputs shorter than this skip building a table for uncached custom parameters, since
+/// generating the table costs about as much as processing 256 bytes bit-by-bit.
+#[cfg(not(any(feature = "std", feature = "cache")))]
+const BITWISE_THRESHOLD: usize = 256;
 
-#[allow(unused)]
-const RUST_CRC32_AIXM: crc::Crc<u32, Table<16>> =
-    crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_AIXM);
+// CRC-32/AIXM
+static TABLES_814141AB_FORWARD: Tables32 = crc32_tables(0x814141ab, false);
 
-#[allow(unused)]
-const RUST_CRC32_AUTOSAR: crc::Crc<u32, Table<16>> =
-    crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_AUTOSAR);
+// CRC-32/AUTOSAR
+static TABLES_F4ACFB13_REFLECTED: Tables32 = crc32_tables(0xf4acfb13, true);
 
-#[allow(unused)]
-const RUST_CRC32_BASE91_D: crc::Crc<u32, Table<16>> =
-    crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_BASE91_D);
+// CRC-32/BASE91-D
+static TABLES_A833982B_REFLECTED: Tables32 = crc32_tables(0xa833982b, true);
 
-#[allow(unused)]
-const RUST_CRC32_BZIP2: crc::Crc<u32, Table<16>> =
-    crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_BZIP2);
+// CRC-32/BZIP2, CRC-32/CKSUM, CRC-32/MPEG-2
+static TABLES_04C11DB7_FORWARD: Tables32 = crc32_tables(0x04c11db7, false);
 
-#[allow(unused)]
-const RUST_CRC32_CD_ROM_EDC: crc::Crc<u32, Table<16>> =
-    crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_CD_ROM_EDC);
+// CRC-32/CD-ROM-EDC
+static TABLES_8001801B_REFLECTED: Tables32 = crc32_tables(0x8001801b, true);
 
-#[allow(unused)]
-const RUST_CRC32_CKSUM: crc::Crc<u32, Table<16>> =
-    crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_CKSUM);
+// CRC-32/ISCSI
+static TABLES_1EDC6F41_REFLECTED: Tables32 = crc32_tables(0x1edc6f41, true);
 
-#[allow(unused)]
-const RUST_CRC32_ISCSI: crc::Crc<u32, Table<16>> =
-    crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_ISCSI);
+// CRC-32/ISO-HDLC, CRC-32/JAMCRC
+static TABLES_04C11DB7_REFLECTED: Tables32 = crc32_tables(0x04c11db7, true);
 
-#[allow(unused)]
-const RUST_CRC32_ISO_HDLC: crc::Crc<u32, Table<16>> =
-    crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_ISO_HDLC);
+// CRC-32/MEF
+static TABLES_741B8CD7_REFLECTED: Tables32 = crc32_tables(0x741b8cd7, true);
 
-#[allow(unused)]
-const RUST_CRC32_JAMCRC: crc::Crc<u32, Table<16>> =
-    crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_JAMCRC);
+// CRC-32/XFER
+static TABLES_000000AF_FORWARD: Tables32 = crc32_tables(0x000000af, false);
 
-#[allow(unused)]
-const RUST_CRC32_MEF: crc::Crc<u32, Table<16>> = crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_MEF);
+// CRC-64/ECMA-182, CRC-64/WE
+static TABLES_42F0E1EBA9EA3693_FORWARD: Tables64 = crc64_tables(0x42f0e1eba9ea3693, false);
 
-#[allow(unused)]
-const RUST_CRC32_MPEG_2: crc::Crc<u32, Table<16>> =
-    crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_MPEG_2);
+// CRC-64/GO-ISO
+static TABLES_000000000000001B_REFLECTED: Tables64 = crc64_tables(0x000000000000001b, true);
 
-#[allow(unused)]
-const RUST_CRC32_XFER: crc::Crc<u32, Table<16>> =
-    crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_XFER);
+// CRC-64/MS
+static TABLES_259C84CBA6426349_REFLECTED: Tables64 = crc64_tables(0x259c84cba6426349, true);
 
-#[allow(unused)]
-const RUST_CRC64_ECMA_182: crc::Crc<u64, Table<16>> =
-    crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_ECMA_182);
+// CRC-64/NVME
+static TABLES_AD93D23594C93659_REFLECTED: Tables64 = crc64_tables(0xad93d23594c93659, true);
 
-#[allow(unused)]
-const RUST_CRC64_GO_ISO: crc::Crc<u64, Table<16>> =
-    crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_GO_ISO);
+// CRC-64/REDIS
+static TABLES_AD93D23594C935A9_REFLECTED: Tables64 = crc64_tables(0xad93d23594c935a9, true);
 
-#[allow(unused)]
-const RUST_CRC64_MS: crc::Crc<u64, Table<16>> = crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_MS);
+// CRC-64/XZ
+static TABLES_42F0E1EBA9EA3693_REFLECTED: Tables64 = crc64_tables(0x42f0e1eba9ea3693, true);
 
-#[allow(unused)]
-const RUST_CRC64_NVME: crc::Crc<u64, Table<16>> = crc::Crc::<u64, Table<16>>::new(&CRC_64_NVME);
+/// The number of custom polynomials whose tables are cached for each width. Each set of tables
+/// is 8KiB for CRC-32 and 16KiB for CRC-64, so once every slot is full the oldest is evicted
+/// rather than letting a process which cycles through polynomials grow without bound.
+#[cfg(any(feature = "std", feature = "cache"))]
+const CUSTOM_TABLES_SLOTS: usize = 8;
 
-#[allow(unused)]
-const RUST_CRC64_REDIS: crc::Crc<u64, Table<16>> =
-    crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_REDIS);
+/// Tables for custom parameters, keyed by polynomial and reflection, oldest first.
+#[cfg(any(feature = "std", feature = "cache"))]
+type TablesCache<T> = RwLock<Vec<((u64, bool), Box<T>)>>;
 
-#[allow(unused)]
-const RUST_CRC64_WE: crc::Crc<u64, Table<16>> = crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_WE);
+#[cfg(feature = "std")]
+static CUSTOM_TABLES_32: OnceLock<TablesCache<Tables32>> = OnceLock::new();
+#[cfg(feature = "std")]
+static CUSTOM_TABLES_64: OnceLock<TablesCache<Tables64>> = OnceLock::new();
 
-#[allow(unused)]
-const RUST_CRC64_XZ: crc::Crc<u64, Table<16>> = crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_XZ);
+#[cfg(all(not(feature = "std"), feature = "cache"))]
+static CUSTOM_TABLES_32: Once<TablesCache<Tables32>> = Once::new();
+#[cfg(all(not(feature = "std"), feature = "cache"))]
+static CUSTOM_TABLES_64: Once<TablesCache<Tables64>> = Once::new();
 
 #[allow(unused)]
 // Dispatch function that handles the generic case
 pub(crate) fn update(state: u64, data: &[u8], params: CrcParams) -> u64 {
     match params.width {
         32 => {
-            let params = match params.algorithm {
-                CrcAlgorithm::Crc32Aixm => RUST_CRC32_AIXM,
-                CrcAlgorithm::Crc32Autosar => RUST_CRC32_AUTOSAR,
-                CrcAlgorithm::Crc32Base91D => RUST_CRC32_BASE91_D,
-                CrcAlgorithm::Crc32Bzip2 => RUST_CRC32_BZIP2,
-                CrcAlgorithm::Crc32CdRomEdc => RUST_CRC32_CD_ROM_EDC,
-                CrcAlgorithm::Crc32Cksum => RUST_CRC32_CKSUM,
-                CrcAlgorithm::Crc32Iscsi => RUST_CRC32_ISCSI,
-                CrcAlgorithm::Crc32IsoHdlc => RUST_CRC32_ISO_HDLC,
-                CrcAlgorithm::Crc32Jamcrc => RUST_CRC32_JAMCRC,
-                CrcAlgorithm::Crc32Mef => RUST_CRC32_MEF,
-                CrcAlgorithm::Crc32Mpeg2 => RUST_CRC32_MPEG_2,
-                CrcAlgorithm::Crc32Xfer => RUST_CRC32_XFER,
+            let tables = match params.algorithm {
+                CrcAlgorithm::Crc32Aixm => &TABLES_814141AB_FORWARD,
+                CrcAlgorithm::Crc32Autosar => &TABLES_F4ACFB13_REFLECTED,
+                CrcAlgorithm::Crc32Base91D => &TABLES_A833982B_REFLECTED,
+                CrcAlgorithm::Crc32Bzip2 => &TABLES_04C11DB7_FORWARD,
+                CrcAlgorithm::Crc32CdRomEdc => &TABLES_8001801B_REFLECTED,
+                CrcAlgorithm::Crc32Cksum => &TABLES_04C11DB7_FORWARD,
+                CrcAlgorithm::Crc32Iscsi => &TABLES_1EDC6F41_REFLECTED,
+                CrcAlgorithm::Crc32IsoHdlc => &TABLES_04C11DB7_REFLECTED,
+                CrcAlgorithm::Crc32Jamcrc => &TABLES_04C11DB7_REFLECTED,
+                CrcAlgorithm::Crc32Mef => &TABLES_741B8CD7_REFLECTED,
+                CrcAlgorithm::Crc32Mpeg2 => &TABLES_04C11DB7_FORWARD,
+                CrcAlgorithm::Crc32Xfer => &TABLES_000000AF_FORWARD,
                 CrcAlgorithm::Crc32Custom => {
-                    #[cfg(feature = "alloc")]
-                    {
-                        extern crate alloc;
-                        use alloc::boxed::Box;
-
-                        // Use cache if std or cache feature is enabled
-                        #[cfg(any(feature = "std", feature = "cache"))]
-                        {
-                            let key: Crc32Key = (
-                                params.poly as u32,
-                                params.init as u32,
-                                params.refin,
-                                params.refout,
-                                params.xorout as u32,
-                                params.check as u32,
-                            );
-
-                            #[cfg(feature = "std")]
-                            {
-                                let cache =
-                                    CUSTOM_CRC32_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
-                                let mut cache_guard = cache.lock().unwrap();
-
-                                let static_algorithm =
-                                    cache_guard.entry(key).or_insert_with(|| {
-                                        let algorithm = Algorithm {
-                                            width: params.width,
-                                            poly: params.poly as u32,
-                                            init: params.init as u32,
-                                            refin: params.refin,
-                                            refout: params.refout,
-                                            xorout: params.xorout as u32,
-                                            check: params.check as u32,
-                                            residue: 0x00000000,
-                                        };
-                                        Box::leak(Box::new(algorithm))
-                                    });
-
-                                crc::Crc::<u32, Table<16>>::new(static_algorithm)
-                            }
-
-                            #[cfg(all(not(feature = "std"), feature = "cache"))]
-                            {
-                                let cache =
-                                    CUSTOM_CRC32_CACHE.call_once(|| Mutex::new(HashMap::new()));
-                                let mut cache_guard = cache.lock();
-
-                                let static_algorithm =
-                                    cache_guard.entry(key).or_insert_with(|| {
-                                        let algorithm = Algorithm {
-                                            width: params.width,
-                                            poly: params.poly as u32,
-                                            init: params.init as u32,
-                                            refin: params.refin,
-                                            refout: params.refout,
-                                            xorout: params.xorout as u32,
-                                            check: params.check as u32,
-                                            residue: 0x00000000,
-                                        };
-                                        Box::leak(Box::new(algorithm))
-                                    });
-
-                                crc::Crc::<u32, Table<16>>::new(static_algorithm)
-                            }
-                        }
-
-                        // Without cache, just leak (no_std without cache feature)
-                        #[cfg(not(any(feature = "std", feature = "cache")))]
-                        {
-                            let algorithm: Algorithm<u32> = Algorithm {
-                                width: params.width,
-                                poly: params.poly as u32,
-                                init: params.init as u32,
-                                refin: params.refin,
-                                refout: params.refout,
-                                xorout: params.xorout as u32,
-                                check: params.check as u32,
-                                residue: 0x00000000, // unused in this context
-                            };
-
-                            // ugly, but the crc crate is difficult to work with...
-                            let static_algorithm = Box::leak(Box::new(algorithm));
-
-                            crc::Crc::<u32, Table<16>>::new(static_algorithm)
-                        }
-                    }
-                    #[cfg(not(feature = "alloc"))]
-                    panic!("Custom CRC parameters require the 'alloc' feature")
+                    return update_custom_u32(state as u32, data, params) as u64
                 }
                 _ => panic!("Invalid algorithm for u32 CRC"),
             };
-            update_u32(state as u32, data, params) as u64
+            update_u32(state as u32, data, tables, params.refin) as u64
         }
         64 => {
-            let params = match params.algorithm {
-                CrcAlgorithm::Crc64Ecma182 => RUST_CRC64_ECMA_182,
-                CrcAlgorithm::Crc64GoIso => RUST_CRC64_GO_ISO,
-                CrcAlgorithm::Crc64Ms => RUST_CRC64_MS,
-                CrcAlgorithm::Crc64Nvme => RUST_CRC64_NVME,
-                CrcAlgorithm::Crc64Redis => RUST_CRC64_REDIS,
-                CrcAlgorithm::Crc64We => RUST_CRC64_WE,
-                CrcAlgorithm::Crc64Xz => RUST_CRC64_XZ,
-                CrcAlgorithm::Crc64Custom => {
-                    #[cfg(feature = "alloc")]
-                    {
-                        extern crate alloc;
-                        use alloc::boxed::Box;
-
-                        // Use cache if std or cache feature is enabled
-                        #[cfg(any(feature = "std", feature = "cache"))]
-                        {
-                            let key: Crc64Key = (
-                                params.poly,
-                                params.init,
-                                params.refin,
-                                params.refout,
-                                params.xorout,
-                                params.check,
-                            );
-
-                            #[cfg(feature = "std")]
-                            {
-                                let cache =
-                                    CUSTOM_CRC64_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
-                                let mut cache_guard = cache.lock().unwrap();
-
-                                let static_algorithm =
-                                    cache_guard.entry(key).or_insert_with(|| {
-                                        let algorithm = Algorithm {
-                                            width: params.width,
-                                            poly: params.poly,
-                                            init: params.init,
-                                            refin: params.refin,
-                                            refout: params.refout,
-                                            xorout: params.xorout,
-                                            check: params.check,
-                                            residue: 0x0000000000000000,
-                                        };
-                                        Box::leak(Box::new(algorithm))
-                                    });
-
-                                crc::Crc::<u64, Table<16>>::new(static_algorithm)
-                            }
-
-                            #[cfg(all(not(feature = "std"), feature = "cache"))]
-                            {
-                                let cache =
-                                    CUSTOM_CRC64_CACHE.call_once(|| Mutex::new(HashMap::new()));
-                                let mut cache_guard = cache.lock();
-
-                                let static_algorithm =
-                                    cache_guard.entry(key).or_insert_with(|| {
-                                        let algorithm = Algorithm {
-                                            width: params.width,
-                                            poly: params.poly,
-                                            init: params.init,
-                                            refin: params.refin,
-                                            refout: params.refout,
-                                            xorout: params.xorout,
-                                            check: params.check,
-                                            residue: 0x0000000000000000,
-                                        };
-                                        Box::leak(Box::new(algorithm))
-                                    });
-
-                                crc::Crc::<u64, Table<16>>::new(static_algorithm)
-                            }
-                        }
-
-                        // Without cache, just leak (no_std without cache feature)
-                        #[cfg(not(any(feature = "std", feature = "cache")))]
-                        {
-                            let algorithm: Algorithm<u64> = Algorithm {
-                                width: params.width,
-                                poly: params.poly,
-                                init: params.init,
-                                refin: params.refin,
-                                refout: params.refout,
-                                xorout: params.xorout,
-                                check: params.check,
-                                residue: 0x0000000000000000, // unused in this context
-                            };
-
-                            // ugly, but the crc crate is difficult to work with...
-                            let static_algorithm = Box::leak(Box::new(algorithm));
-
-                            crc::Crc::<u64, Table<16>>::new(static_algorithm)
-                        }
-                    }
-                    #[cfg(not(feature = "alloc"))]
-                    panic!("Custom CRC parameters require the 'alloc' feature")
-                }
+            let tables = match params.algorithm {
+                CrcAlgorithm::Crc64Ecma182 => &TABLES_42F0E1EBA9EA3693_FORWARD,
+                CrcAlgorithm::Crc64GoIso => &TABLES_000000000000001B_REFLECTED,
+                CrcAlgorithm::Crc64Ms => &TABLES_259C84CBA6426349_REFLECTED,
+                CrcAlgorithm::Crc64Nvme => &TABLES_AD93D23594C93659_REFLECTED,
+                CrcAlgorithm::Crc64Redis => &TABLES_AD93D23594C935A9_REFLECTED,
+                CrcAlgorithm::Crc64We => &TABLES_42F0E1EBA9EA3693_FORWARD,
+                CrcAlgorithm::Crc64Xz => &TABLES_42F0E1EBA9EA3693_REFLECTED,
+                CrcAlgorithm::Crc64Custom => return update_custom_u64(state, data, params),
                 _ => panic!("Invalid algorithm for u64 CRC"),
             };
-            update_u64(state, data, params)
+            update_u64(state, data, tables, params.refin)
         }
         _ => panic!("Unsupported CRC width: {}", params.width),
     }
 }
 
-// Specific implementation for u32
-fn update_u32(state: u32, data: &[u8], params: crc::Crc<u32, Table<16>>) -> u32 {
-    // apply REFIN if necessary
-    let initial = if params.algorithm.refin {
-        state.reverse_bits()
+/// Updates the raw CRC register with the first `bits` bits (0-8) of `byte`, one bit at a time.
+///
+/// Reflected CRCs take bits least significant first, so these are the low bits of `byte`, while
+/// forward CRCs take the high bits, most significant first.
+pub(crate) fn update_bits(mut state: u64, byte: u8, bits: u8, params: CrcParams) -> u64 {
+    debug_assert!(bits <= 8);
+
+    let width = params.width as u32;
+
+    if params.refin {
+        let poly = params.poly.reverse_bits() >> (64 - width);
+
+        for bit in 0..bits {
+            state ^= ((byte >> bit) & 1) as u64;
+            state = if state & 1 != 0 {
+                (state >> 1) ^ poly
+            } else {
+                state >> 1
+            };
+        }
     } else {
-        state
-    };
+        let mask = u64::MAX >> (64 - width);
+
+        for bit in 0..bits {
+            let top = ((state >> (width - 1)) ^ (byte >> (7 - bit)) as u64) & 1;
+            state = (state << 1) & mask;
+            if top != 0 {
+                state ^= params.poly;
+            }
+        }
+    }
+
+    state
+}
+
+/// CRC-32 of a single byte, processed bit-by-bit. This is the first slice-by-8 table.
+const fn crc32_byte(poly: u32, reflected: bool, byte: u8) -> u32 {
+    let mut crc;
+    let mut bit = 0;
+
+    if reflected {
+        let poly = poly.reverse_bits();
+        crc = byte as u32;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ poly
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+    } else {
+        crc = (byte as u32) << 24;
+        while bit < 8 {
+            crc = if crc & 0x8000_0000 != 0 {
+                (crc << 1) ^ poly
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+    }
+
+    crc
+}
+
+/// CRC-64 of a single byte, processed bit-by-bit. This is the first slice-by-8 table.
+const fn crc64_byte(poly: u64, reflected: bool, byte: u8) -> u64 {
+    let mut crc;
+    let mut bit = 0;
+
+    if reflected {
+        let poly = poly.reverse_bits();
+        crc = byte as u64;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ poly
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+    } else {
+        crc = (byte as u64) << 56;
+        while bit < 8 {
+            crc = if crc & 0x8000_0000_0000_0000 != 0 {
+                (crc << 1) ^ poly
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+    }
+
+    crc
+}
+
+/// Generates the slice-by-8 tables for a 32-bit polynomial.
+///
+/// Each table extends the previous one by a zero byte, so `tables[k][b]` is the contribution of
+/// byte `b` when it's followed by `k` more bytes in the same 8-byte block.
+const fn crc32_tables(poly: u32, reflected: bool) -> Tables32 {
+    let mut tables = [[0u32; 256]; 8];
+
+    let mut byte = 0;
+    while byte < 256 {
+        tables[0][byte] = crc32_byte(poly, reflected, byte as u8);
+        byte += 1;
+    }
+
+    let mut slice = 1;
+    while slice < 8 {
+        let mut byte = 0;
+        while byte < 256 {
+            let previous = tables[slice - 1][byte];
+            tables[slice][byte] = if reflected {
+                (previous >> 8) ^ tables[0][(previous & 0xff) as usize]
+            } else {
+                (previous << 8) ^ tables[0][(previous >> 24) as usize]
+            };
+            byte += 1;
+        }
+        slice += 1;
+    }
 
-    let mut digest = params.digest_with_initial(initial);
-    digest.update(data);
+    tables
+}
+
+/// Generates the slice-by-8 tables for a 64-bit polynomial.
+///
+/// Each table extends the previous one by a zero byte, so `tables[k][b]` is the contribution of
+/// byte `b` when it's followed by `k` more bytes in the same 8-byte block.
+const fn crc64_tables(poly: u64, reflected: bool) -> Tables64 {
+    let mut tables = [[0u64; 256]; 8];
+
+    let mut byte = 0;
+    while byte < 256 {
+        tables[0][byte] = crc64_byte(poly, reflected, byte as u8);
+        byte += 1;
+    }
 
-    let checksum = digest.finalize();
+    let mut slice = 1;
+    while slice < 8 {
+        let mut byte = 0;
+        while byte < 256 {
+            let previous = tables[slice - 1][byte];
+            tables[slice][byte] = if reflected {
+                (previous >> 8) ^ tables[0][(previous & 0xff) as usize]
+            } else {
+                (previous << 8) ^ tables[0][(previous >> 56) as usize]
+            };
+            byte += 1;
+        }
+        slice += 1;
+    }
 
-    // remove XOR since this will be applied in the library Digest::finalize() step instead
-    checksum ^ params.algorithm.xorout
+    tables
+}
+
+// Specific implementation for u32
+//
+// The state is the raw CRC register, which is already bit-reflected for reflected variants, so
+// it's used as-is and no XOR is applied (that happens in the library's finalize step instead).
+#[inline]
+fn update_u32(mut crc: u32, data: &[u8], tables: &Tables32, reflected: bool) -> u32 {
+    let mut chunks = data.chunks_exact(8);
+
+    if reflected {
+        for chunk in &mut chunks {
+            let low = crc ^ u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+
+            crc = tables[7][(low & 0xff) as usize]
+                ^ tables[6][((low >> 8) & 0xff) as usize]
+                ^ tables[5][((low >> 16) & 0xff) as usize]
+                ^ tables[4][(low >> 24) as usize]
+                ^ tables[3][chunk[4] as usize]
+                ^ tables[2][chunk[5] as usize]
+                ^ tables[1][chunk[6] as usize]
+                ^ tables[0][chunk[7] as usize];
+        }
+
+        for &byte in chunks.remainder() {
+            crc = (crc >> 8) ^ tables[0][((crc ^ byte as u32) & 0xff) as usize];
+        }
+    } else {
+        for chunk in &mut chunks {
+            let high = crc ^ u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+
+            crc = tables[7][(high >> 24) as usize]
+                ^ tables[6][((high >> 16) & 0xff) as usize]
+                ^ tables[5][((high >> 8) & 0xff) as usize]
+                ^ tables[4][(high & 0xff) as usize]
+                ^ tables[3][chunk[4] as usize]
+                ^ tables[2][chunk[5] as usize]
+                ^ tables[1][chunk[6] as usize]
+                ^ tables[0][chunk[7] as usize];
+        }
+
+        for &byte in chunks.remainder() {
+            crc = (crc << 8) ^ tables[0][((crc >> 24) ^ byte as u32) as usize];
+        }
+    }
+
+    crc
 }
 
 // Specific implementation for u64
-fn update_u64(state: u64, data: &[u8], params: crc::Crc<u64, Table<16>>) -> u64 {
-    // apply REFIN if necessary
-    let initial = if params.algorithm.refin {
-        state.reverse_bits()
+//
+// The state is the raw CRC register, which is already bit-reflected for reflected variants, so
+// it's used as-is and no XOR is applied (that happens in the library's finalize step instead).
+#[inline]
+fn update_u64(mut crc: u64, data: &[u8], tables: &Tables64, reflected: bool) -> u64 {
+    let mut chunks = data.chunks_exact(8);
+
+    if reflected {
+        for chunk in &mut chunks {
+            let low = crc ^ u64::from_le_bytes(chunk.try_into().unwrap());
+
+            crc = tables[7][(low & 0xff) as usize]
+                ^ tables[6][((low >> 8) & 0xff) as usize]
+                ^ tables[5][((low >> 16) & 0xff) as usize]
+                ^ tables[4][((low >> 24) & 0xff) as usize]
+                ^ tables[3][((low >> 32) & 0xff) as usize]
+                ^ tables[2][((low >> 40) & 0xff) as usize]
+                ^ tables[1][((low >> 48) & 0xff) as usize]
+                ^ tables[0][(low >> 56) as usize];
+        }
+
+        for &byte in chunks.remainder() {
+            crc = (crc >> 8) ^ tables[0][((crc ^ byte as u64) & 0xff) as usize];
+        }
     } else {
-        state
-    };
+        for chunk in &mut chunks {
+            let high = crc ^ u64::from_be_bytes(chunk.try_into().unwrap());
+
+            crc = tables[7][(high >> 56) as usize]
+                ^ tables[6][((high >> 48) & 0xff) as usize]
+                ^ tables[5][((high >> 40) & 0xff) as usize]
+                ^ tables[4][((high >> 32) & 0xff) as usize]
+                ^ tables[3][((high >> 24) & 0xff) as usize]
+                ^ tables[2][((high >> 16) & 0xff) as usize]
+                ^ tables[1][((high >> 8) & 0xff) as usize]
+                ^ tables[0][(high & 0xff) as usize];
+        }
+
+        for &byte in chunks.remainder() {
+            crc = (crc << 8) ^ tables[0][((crc >> 56) ^ byte as u64) as usize];
+        }
+    }
+
+    crc
+}
+
+/// Looks up (or generates and caches) the tables for `key`, and runs `f` with them.
+///
+/// Uses the same best-effort locking as the folding key cache: a poisoned lock falls back to
+/// uncached tables rather than failing.
+#[cfg(any(feature = "std", feature = "cache"))]
+fn with_cached_tables<T, R>(
+    cache: &TablesCache<T>,
+    key: (u64, bool),
+    generate: impl FnOnce() -> T,
+    f: impl Fn(&T) -> R,
+) -> R {
+    #[cfg(feature = "std")]
+    {
+        if let Ok(cache) = cache.read() {
+            if let Some((_, tables)) = cache.iter().find(|(k, _)| *k == key) {
+                return f(tables);
+            }
+        }
+
+        // generate outside of the write lock to minimize lock hold time
+        let tables = Box::new(generate());
+        let result = f(&tables);
+
+        if let Ok(mut cache) = cache.write() {
+            insert_tables(&mut cache, key, tables);
+        }
+
+        result
+    }
+
+    #[cfg(all(not(feature = "std"), feature = "cache"))]
+    {
+        {
+            let cache = cache.read();
+            if let Some((_, tables)) = cache.iter().find(|(k, _)| *k == key) {
+                return f(tables);
+            }
+        } // Drop read lock before generating tables
+
+        let tables = Box::new(generate());
+        let result = f(&tables);
+
+        insert_tables(&mut cache.write(), key, tables);
+
+        result
+    }
+}
+
+/// Caches `tables` for `key` unless another thread got there first, evicting the oldest tables
+/// if every slot is full.
+#[cfg(any(feature = "std", feature = "cache"))]
+fn insert_tables<T>(cache: &mut Vec<((u64, bool), Box<T>)>, key: (u64, bool), tables: Box<T>) {
+    if cache.iter().any(|(k, _)| *k == key) {
+        return;
+    }
+
+    if cache.len() >= CUSTOM_TABLES_SLOTS {
+        cache.remove(0);
+    }
+
+    cache.push((key, tables));
+}
+
+/// Updates a custom CRC-32, with tables cached by polynomial.
+#[cfg(any(feature = "std", feature = "cache"))]
+fn update_custom_u32(state: u32, data: &[u8], params: CrcParams) -> u32 {
+    #[cfg(feature = "std")]
+    let cache = CUSTOM_TABLES_32.get_or_init(|| RwLock::new(Vec::new()));
+    #[cfg(all(not(feature = "std"), feature = "cache"))]
+    let cache = CUSTOM_TABLES_32.call_once(|| RwLock::new(Vec::new()));
+
+    let poly = params.poly as u32;
+
+    with_cached_tables(
+        cache,
+        (poly as u64, params.refin),
+        || crc32_tables(poly, params.refin),
+        |tables| update_u32(state, data, tables, params.refin),
+    )
+}
+
+/// Updates a custom CRC-64, with tables cached by polynomial.
+#[cfg(any(feature = "std", feature = "cache"))]
+fn update_custom_u64(state: u64, data: &[u8], params: CrcParams) -> u64 {
+    #[cfg(feature = "std")]
+    let cache = CUSTOM_TABLES_64.get_or_init(|| RwLock::new(Vec::new()));
+    #[cfg(all(not(feature = "std"), feature = "cache"))]
+    let cache = CUSTOM_TABLES_64.call_once(|| RwLock::new(Vec::new()));
+
+    with_cached_tables(
+        cache,
+        (params.poly, params.refin),
+        || crc64_tables(params.poly, params.refin),
+        |tables| update_u64(state, data, tables, params.refin),
+    )
+}
+
+/// Updates a custom CRC-32 without a cache.
+///
+/// Slice-by-8 tables are too large to rebuild on the stack for every call, so this uses a single
+/// 256-entry table for longer inputs and bit-by-bit processing for short ones.
+#[cfg(not(any(feature = "std", feature = "cache")))]
+fn update_custom_u32(mut crc: u32, data: &[u8], params: CrcParams) -> u32 {
+    let poly = params.poly as u32;
+
+    if data.len() < BITWISE_THRESHOLD {
+        for &byte in data {
+            crc = if params.refin {
+                (crc >> 8) ^ crc32_byte(poly, true, (crc as u8) ^ byte)
+            } else {
+                (crc << 8) ^ crc32_byte(poly, false, ((crc >> 24) as u8) ^ byte)
+            };
+        }
+
+        return crc;
+    }
+
+    let mut table = [0u32; 256];
+    for (byte, entry) in table.iter_mut().enumerate() {
+        *entry = crc32_byte(poly, params.refin, byte as u8);
+    }
+
+    for &byte in data {
+        crc = if params.refin {
+            (crc >> 8) ^ table[((crc ^ byte as u32) & 0xff) as usize]
+        } else {
+            (crc << 8) ^ table[((crc >> 24) ^ byte as u32) as usize]
+        };
+    }
+
+    crc
+}
+
+/// Updates a custom CRC-64 without a cache.
+///
+/// Slice-by-8 tables are too large to rebuild on the stack for every call, so this uses a single
+/// 256-entry table for longer inputs and bit-by-bit processing for short ones.
+#[cfg(not(any(feature = "std", feature = "cache")))]
+fn update_custom_u64(mut crc: u64, data: &[u8], params: CrcParams) -> u64 {
+    let poly = params.poly;
+
+    if data.len() < BITWISE_THRESHOLD {
+        for &byte in data {
+            crc = if params.refin {
+                (crc >> 8) ^ crc64_byte(poly, true, (crc as u8) ^ byte)
+            } else {
+                (crc << 8) ^ crc64_byte(poly, false, ((crc >> 56) as u8) ^ byte)
+            };
+        }
+
+        return crc;
+    }
+
+    let mut table = [0u64; 256];
+    for (byte, entry) in table.iter_mut().enumerate() {
+        *entry = crc64_byte(poly, params.refin, byte as u8);
+    }
+
+    for &byte in data {
+        crc = if params.refin {
+            (crc >> 8) ^ table[((crc ^ byte as u64) & 0xff) as usize]
+        } else {
+            (crc << 8) ^ table[((crc >> 56) ^ byte as u64) as usize]
+        };
+    }
+
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::{TEST_ALL_CONFIGS, TEST_CHECK_STRING};
+    use crate::test::create_aligned_data;
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_check_values() {
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+            let state = update(params.init, TEST_CHECK_STRING, *params);
+
+            assert_eq!(
+                state ^ params.xorout,
+                config.get_check(),
+                "Mismatch CRC, {}",
+                config.get_name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_reference_for_all_lengths() {
+        let mut rng = rng();
+
+        for len in 0..=257 {
+            let mut data = vec![0u8; len];
+            rng.fill(&mut data[..]);
+            let data = create_aligned_data(&data);
+
+            for config in TEST_ALL_CONFIGS {
+                let params = config.get_params();
+                let state = update(params.init, &data, *params);
+
+                assert_eq!(
+                    state ^ params.xorout,
+                    config.checksum_with_reference(&data),
+                    "Mismatch CRC, {}, length {len}",
+                    config.get_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_unaligned_and_split_updates() {
+        let mut rng = rng();
+        let mut data = vec![0u8; 1031];
+        rng.fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+
+            // odd split points exercise every remainder length
+            for split in [1, 3, 7, 8, 9, 513] {
+                let (first, second) = data[1..].split_at(split);
+                let state = update(params.init, first, *params);
+                let state = update(state, second, *params);
+
+                assert_eq!(
+                    state ^ params.xorout,
+                    config.checksum_with_reference(&data[1..]),
+                    "Mismatch CRC, {}, split {split}",
+                    config.get_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_params_match_predefined() {
+        let mut rng = rng();
+        let mut data = vec![0u8; 4096];
+        rng.fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+            let custom = CrcParams::new(
+                "CRC/CUSTOM",
+                params.width,
+                params.poly,
+                params.init,
+                params.refin,
+                params.xorout,
+                params.check,
+            );
+
+            // once to populate the cache, once to use it
+            for _ in 0..2 {
+                assert_eq!(
+                    update(custom.init, &data, custom),
+                    update(params.init, &data, *params),
+                    "Mismatch CRC, {}",
+                    config.get_name()
+                );
+            }
+        }
+    }
 
-    let mut digest = params.digest_with_initial(initial);
-    digest.update(data);
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_custom_tables_cache_is_bounded() {
+        let data = b"123456789";
 
-    // remove XOR since this will be applied in the library Digest::finalize() step instead
-    digest.finalize() ^ params.algorithm.xorout
+        // more polynomials than slots, each checked against bit-by-bit processing
+        for i in 0..CUSTOM_TABLES_SLOTS as u32 * 2 {
+            let poly = 0x04c11db7 ^ (i << 8);
+            let params = CrcParams::new("CRC/CUSTOM", 32, poly as u64, 0, true, 0, 0);
+
+            let mut expected = 0u64;
+            for &byte in data {
+                expected = update_bits(expected, byte, 8, params);
+            }
+
+            assert_eq!(update(0, data, params), expected, "poly {poly:#x}");
+        }
+
+        let cache = CUSTOM_TABLES_32.get().unwrap().read().unwrap();
+        assert!(cache.len() <= CUSTOM_TABLES_SLOTS);
+    }
 }
@@ -91,6 +91,24 @@ impl ArchOps for X86SsePclmulqdqOps {
         _mm_loadu_si128(ptr as *const __m128i)
     }
 
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn store_bytes(&self, ptr: *mut u8, vector: Self::Vector) {
+        _mm_storeu_si128(ptr as *mut __m128i, vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn store_bytes_non_temporal(&self, ptr: *mut u8, vector: Self::Vector) {
+        _mm_stream_si128(ptr as *mut __m128i, vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn store_fence(&self) {
+        _mm_sfence()
+    }
+
     #[inline]
     #[target_feature(enable = "ssse3")]
     unsafe fn shuffle_bytes(&self, data: Self::Vector, mask: Self::Vector) -> Self::Vector {
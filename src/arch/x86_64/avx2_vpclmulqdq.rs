@@ -0,0 +1,678 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides AVX2 and VPCLMULQDQ-specific implementations of the ArchOps trait.
+//!
+//! It performs folding using 8 x YMM registers of 256-bits each, for CPUs which support
+//! VPCLMULQDQ but not AVX-512 (Intel Alder Lake and later client CPUs, AMD Zen 3, etc).
+
+#![cfg(target_arch = "x86_64")]
+
+#[rustversion::since(1.89)]
+use crate::arch::x86::sse::X86SsePclmulqdqOps;
+
+#[rustversion::since(1.89)]
+use crate::enums::Reflector;
+
+#[rustversion::since(1.89)]
+use crate::structs::CrcState;
+
+#[rustversion::since(1.89)]
+use crate::traits::{ArchOps, EnhancedCrcWidth};
+
+#[rustversion::since(1.89)]
+use core::arch::x86_64::*;
+
+#[rustversion::since(1.89)]
+use core::ops::BitXor;
+
+/// Implements the ArchOps trait using 256-bit AVX2 and VPCLMULQDQ instructions.
+/// Delegates to X86SsePclmulqdqOps for standard 128-bit operations
+#[rustversion::since(1.89)]
+#[derive(Debug, Copy, Clone)]
+pub struct X86_64Avx2VpclmulqdqOps(X86SsePclmulqdqOps);
+
+#[rustversion::since(1.89)]
+impl X86_64Avx2VpclmulqdqOps {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self(X86SsePclmulqdqOps)
+    }
+}
+
+// Wrapper for __m256i to make it easier to work with
+#[rustversion::since(1.89)]
+#[derive(Debug, Copy, Clone)]
+struct Simd256(__m256i);
+
+#[rustversion::since(1.89)]
+impl Simd256 {
+    #[inline]
+    #[target_feature(enable = "avx")]
+    unsafe fn new(x3: u64, x2: u64, x1: u64, x0: u64) -> Self {
+        Self(_mm256_set_epi64x(
+            x3 as i64, x2 as i64, x1 as i64, x0 as i64,
+        ))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2,vpclmulqdq")]
+    unsafe fn fold_32(&self, coeff: &Self, new_data: &Self) -> Self {
+        // No ternary logic without AVX-512, so XOR3 takes two instructions
+        Self(_mm256_xor_si256(
+            _mm256_xor_si256(
+                _mm256_clmulepi64_epi128(self.0, coeff.0, 0), // Low parts
+                _mm256_clmulepi64_epi128(self.0, coeff.0, 17), // High parts
+            ),
+            new_data.0,
+        ))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx")]
+    unsafe fn extract_u64s(&self) -> [u64; 4] {
+        let mut result = [0u64; 4];
+        _mm256_storeu_si256(result.as_mut_ptr().cast(), self.0);
+
+        result
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx")]
+    unsafe fn load_from_ptr(ptr: *const u8) -> Self {
+        Self(_mm256_loadu_si256(ptr as *const __m256i))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn to_128i_extract<const INDEX: i32>(self) -> __m128i {
+        _mm256_extracti128_si256(self.0, INDEX)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn xor(&self, other: &Self) -> Self {
+        Self(_mm256_xor_si256(self.0, other.0))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx")]
+    #[allow(unused)]
+    #[cfg(feature = "std")]
+    unsafe fn print_hex(&self, prefix: &str) {
+        let values = self.extract_u64s();
+        println!(
+            "{}={:#016x}_{:016x}_{:016x}_{:016x}",
+            prefix, values[3], values[2], values[1], values[0]
+        );
+    }
+}
+
+#[rustversion::since(1.89)]
+impl X86_64Avx2VpclmulqdqOps {
+    /// Process aligned blocks using VPCLMULQDQ with 8 x 256-bit registers
+    ///
+    /// Note that #[inline(always)] loses the inlining performance boost, despite no native
+    /// target_features being used directly. Odd since that's not how Rust's docs make it sound...
+    #[inline]
+    #[target_feature(enable = "avx2,vpclmulqdq")]
+    unsafe fn process_blocks<W: EnhancedCrcWidth>(
+        &self,
+        state: &mut CrcState<<X86_64Avx2VpclmulqdqOps as ArchOps>::Vector>,
+        first: &[__m128i; 8],
+        rest: &[[__m128i; 8]],
+        keys: [u64; 27],
+        reflected: bool,
+    ) -> W::Value
+    where
+        W::Value: Copy + BitXor<Output = W::Value>,
+    {
+        let state_u64s = self.extract_u64s(state.value);
+
+        let positioned_state = if reflected {
+            Simd256::new(0, 0, 0, state_u64s[0])
+        } else {
+            Simd256::new(state_u64s[1], 0, 0, 0)
+        };
+
+        let reflector = create_reflector256(reflected);
+
+        // Load first 256 bytes (2nd half is rest[0] since these are 128-byte blocks)
+        let first_ptr = first.as_ptr() as *const u8;
+        let first_rest_ptr = rest[0].as_ptr() as *const u8;
+
+        let mut x = [
+            reflect_bytes256(&reflector, Simd256::load_from_ptr(first_ptr)),
+            reflect_bytes256(&reflector, Simd256::load_from_ptr(first_ptr.add(32))),
+            reflect_bytes256(&reflector, Simd256::load_from_ptr(first_ptr.add(64))),
+            reflect_bytes256(&reflector, Simd256::load_from_ptr(first_ptr.add(96))),
+            reflect_bytes256(&reflector, Simd256::load_from_ptr(first_rest_ptr)),
+            reflect_bytes256(&reflector, Simd256::load_from_ptr(first_rest_ptr.add(32))),
+            reflect_bytes256(&reflector, Simd256::load_from_ptr(first_rest_ptr.add(64))),
+            reflect_bytes256(&reflector, Simd256::load_from_ptr(first_rest_ptr.add(96))),
+        ];
+
+        x[0] = positioned_state.xor(&x[0]);
+
+        let coeff = self.create_avx2_256byte_coefficient(keys, reflected);
+
+        let remaining_rest = &rest[1..];
+        let pair_count = remaining_rest.len() / 2;
+
+        for i in 0..pair_count {
+            let block1_ptr = remaining_rest[i * 2].as_ptr() as *const u8;
+            let block2_ptr = remaining_rest[i * 2 + 1].as_ptr() as *const u8;
+
+            for (j, register) in x.iter_mut().enumerate() {
+                let ptr = if j < 4 {
+                    block1_ptr.add(j * 32)
+                } else {
+                    block2_ptr.add((j - 4) * 32)
+                };
+
+                *register = register.fold_32(
+                    &coeff,
+                    &reflect_bytes256(&reflector, Simd256::load_from_ptr(ptr)),
+                );
+            }
+        }
+
+        let processed_pairs = pair_count * 2;
+        let remaining_single_count = remaining_rest.len() - processed_pairs;
+
+        if remaining_single_count > 0 {
+            // We have 1 unprocessed block (128 bytes)
+            // Fold 8×256 down to 4×256 and process the remaining block with 4-register mode
+            let folded_4reg = self.fold_from_8x256_to_4x256(x, keys, reflected);
+            let coeff_4reg = self.create_avx2_128byte_coefficient(keys, reflected);
+
+            let last_block_ptr = remaining_rest[processed_pairs].as_ptr() as *const u8;
+
+            let mut final_x = folded_4reg;
+            for (j, register) in final_x.iter_mut().enumerate() {
+                *register = register.fold_32(
+                    &coeff_4reg,
+                    &reflect_bytes256(
+                        &reflector,
+                        Simd256::load_from_ptr(last_block_ptr.add(j * 32)),
+                    ),
+                );
+            }
+
+            let folded = self.fold_from_4x256_to_1x128(final_x, keys, reflected);
+
+            return W::perform_final_reduction(folded, reflected, keys, self);
+        }
+
+        // All blocks processed in pairs - fold from 8 x 256-bit to 1 x 128-bit
+        let folded = self.fold_from_8x256_to_1x128(x, keys, reflected);
+
+        W::perform_final_reduction(folded, reflected, keys, self)
+    }
+
+    /// Create a folding coefficient for AVX2 for 128-byte folding distances
+    #[inline(always)]
+    unsafe fn create_avx2_128byte_coefficient(&self, keys: [u64; 27], reflected: bool) -> Simd256 {
+        let (k1, k2) = if reflected {
+            (keys[3], keys[4])
+        } else {
+            (keys[4], keys[3])
+        };
+
+        // Replicate the coefficient pair
+        Simd256::new(k1, k2, k1, k2)
+    }
+
+    /// Create a folding coefficient for AVX2 for 256-byte folding distances
+    #[inline(always)]
+    unsafe fn create_avx2_256byte_coefficient(&self, keys: [u64; 27], reflected: bool) -> Simd256 {
+        let (k1, k2) = if reflected {
+            (keys[21], keys[22])
+        } else {
+            (keys[22], keys[21])
+        };
+
+        // Replicate the coefficient pair
+        Simd256::new(k1, k2, k1, k2)
+    }
+
+    /// Fold from 8 x 256-bit to 1 x 128-bit
+    #[inline(always)]
+    unsafe fn fold_from_8x256_to_1x128(
+        &self,
+        x: [Simd256; 8],
+        keys: [u64; 27],
+        reflected: bool,
+    ) -> __m128i {
+        // Step 1: Fold 8 x 256-bit to 4 x 256-bit
+        let x4 = self.fold_from_8x256_to_4x256(x, keys, reflected);
+
+        // Step 2: Fold 4 x 256-bit to 1 x 128-bit
+        self.fold_from_4x256_to_1x128(x4, keys, reflected)
+    }
+
+    /// Fold from 8 x 256-bit to 4 x 256-bit
+    #[inline(always)]
+    unsafe fn fold_from_8x256_to_4x256(
+        &self,
+        x: [Simd256; 8],
+        keys: [u64; 27],
+        reflected: bool,
+    ) -> [Simd256; 4] {
+        // This folds registers that are 128 bytes apart (x[0] with x[4], x[1] with x[5], etc)
+        let coeff = self.create_avx2_128byte_coefficient(keys, reflected);
+
+        [
+            x[0].fold_32(&coeff, &x[4]),
+            x[1].fold_32(&coeff, &x[5]),
+            x[2].fold_32(&coeff, &x[6]),
+            x[3].fold_32(&coeff, &x[7]),
+        ]
+    }
+
+    /// Fold from 4 x 256-bit to 1 x 128-bit
+    #[inline(always)]
+    unsafe fn fold_from_4x256_to_1x128(
+        &self,
+        x: [Simd256; 4],
+        keys: [u64; 27],
+        reflected: bool,
+    ) -> __m128i {
+        // Create the fold coefficients for different distances
+        let fold_coefficients = [
+            self.create_vector_from_u64_pair(keys[10], keys[9], reflected), // 112 bytes
+            self.create_vector_from_u64_pair(keys[12], keys[11], reflected), // 96 bytes
+            self.create_vector_from_u64_pair(keys[14], keys[13], reflected), // 80 bytes
+            self.create_vector_from_u64_pair(keys[16], keys[15], reflected), // 64 bytes
+            self.create_vector_from_u64_pair(keys[18], keys[17], reflected), // 48 bytes
+            self.create_vector_from_u64_pair(keys[20], keys[19], reflected), // 32 bytes
+            self.create_vector_from_u64_pair(keys[2], keys[1], reflected),  // 16 bytes
+        ];
+
+        // Extract the 8 x 128-bit vectors from the 4 x 256-bit vectors, in data order
+        let v128 = if reflected {
+            [
+                x[0].to_128i_extract::<0>(),
+                x[0].to_128i_extract::<1>(),
+                x[1].to_128i_extract::<0>(),
+                x[1].to_128i_extract::<1>(),
+                x[2].to_128i_extract::<0>(),
+                x[2].to_128i_extract::<1>(),
+                x[3].to_128i_extract::<0>(),
+                x[3].to_128i_extract::<1>(),
+            ]
+        } else {
+            [
+                x[0].to_128i_extract::<1>(),
+                x[0].to_128i_extract::<0>(),
+                x[1].to_128i_extract::<1>(),
+                x[1].to_128i_extract::<0>(),
+                x[2].to_128i_extract::<1>(),
+                x[2].to_128i_extract::<0>(),
+                x[3].to_128i_extract::<1>(),
+                x[3].to_128i_extract::<0>(),
+            ]
+        };
+
+        // Fold the 8 xmm registers to 1 xmm register
+        let mut res = v128[7];
+
+        for (i, &coeff) in fold_coefficients.iter().enumerate() {
+            let folded_h = self.carryless_mul_00(v128[i], coeff);
+            let folded_l = self.carryless_mul_11(v128[i], coeff);
+            res = self.xor3_vectors(folded_h, folded_l, res);
+        }
+
+        res
+    }
+}
+
+// 256-bit version of the Reflector
+#[rustversion::since(1.89)]
+#[derive(Clone, Copy)]
+enum Reflector256 {
+    NoReflector,
+    ForwardReflector { smask: Simd256 },
+}
+
+// Function to create the appropriate reflector based on CRC parameters
+#[rustversion::since(1.89)]
+#[inline(always)]
+unsafe fn create_reflector256(reflected: bool) -> Reflector256 {
+    if reflected {
+        Reflector256::NoReflector
+    } else {
+        // Load shuffle mask
+        let smask = Simd256::new(
+            0x08090a0b0c0d0e0f,
+            0x0001020304050607,
+            0x08090a0b0c0d0e0f,
+            0x0001020304050607,
+        );
+        Reflector256::ForwardReflector { smask }
+    }
+}
+
+// Function to apply reflection to a 256-bit vector
+#[rustversion::since(1.89)]
+#[inline(always)]
+unsafe fn reflect_bytes256(reflector: &Reflector256, data: Simd256) -> Simd256 {
+    match reflector {
+        Reflector256::NoReflector => data,
+        Reflector256::ForwardReflector { smask } => shuffle_bytes256(data, *smask),
+    }
+}
+
+// Implement a 256-bit byte shuffle function
+#[rustversion::since(1.89)]
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn shuffle_bytes256(data: Simd256, mask: Simd256) -> Simd256 {
+    // Reverse the bytes within each 64-bit element, then reverse the order of the elements
+    Simd256(_mm256_permute4x64_epi64(
+        _mm256_shuffle_epi8(data.0, mask.0),
+        0b00_01_10_11,
+    ))
+}
+
+// Delegate all ArchOps methods to the inner X86SsePclmulqdqOps instance
+#[rustversion::since(1.89)]
+impl ArchOps for X86_64Avx2VpclmulqdqOps {
+    type Vector = __m128i;
+
+    #[inline(always)]
+    unsafe fn process_enhanced_simd_blocks<W: EnhancedCrcWidth>(
+        &self,
+        state: &mut CrcState<Self::Vector>,
+        first: &[Self::Vector; 8],
+        rest: &[[Self::Vector; 8]],
+        _reflector: &Reflector<Self::Vector>,
+        keys: [u64; 27],
+    ) -> bool
+    where
+        Self::Vector: Copy,
+    {
+        // Update the state with the result
+        *state = W::create_state(
+            self.process_blocks::<W>(state, first, rest, keys, state.reflected),
+            state.reflected,
+            self,
+        );
+
+        // Return true to indicate we handled it
+        true
+    }
+
+    // Delegate all other methods to X86SsePclmulqdqOps
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn create_vector_from_u64_pair(
+        &self,
+        high: u64,
+        low: u64,
+        reflected: bool,
+    ) -> Self::Vector {
+        self.0.create_vector_from_u64_pair(high, low, reflected)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn create_vector_from_u64_pair_non_reflected(
+        &self,
+        high: u64,
+        low: u64,
+    ) -> Self::Vector {
+        self.0.create_vector_from_u64_pair_non_reflected(high, low)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse4.1")]
+    unsafe fn create_vector_from_u64(&self, value: u64, high: bool) -> Self::Vector {
+        self.0.create_vector_from_u64(value, high)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn extract_u64s(&self, vector: Self::Vector) -> [u64; 2] {
+        self.0.extract_u64s(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn extract_poly64s(&self, vector: Self::Vector) -> [u64; 2] {
+        self.0.extract_poly64s(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn xor_vectors(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.xor_vectors(a, b)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn load_bytes(&self, ptr: *const u8) -> Self::Vector {
+        self.0.load_bytes(ptr)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn load_aligned(&self, ptr: *const [u64; 2]) -> Self::Vector {
+        self.0.load_aligned(ptr)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn store_bytes(&self, ptr: *mut u8, vector: Self::Vector) {
+        self.0.store_bytes(ptr, vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn store_bytes_non_temporal(&self, ptr: *mut u8, vector: Self::Vector) {
+        self.0.store_bytes_non_temporal(ptr, vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn store_fence(&self) {
+        self.0.store_fence()
+    }
+
+    #[inline]
+    #[target_feature(enable = "ssse3")]
+    unsafe fn shuffle_bytes(&self, data: Self::Vector, mask: Self::Vector) -> Self::Vector {
+        self.0.shuffle_bytes(data, mask)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse4.1")]
+    unsafe fn blend_vectors(
+        &self,
+        a: Self::Vector,
+        b: Self::Vector,
+        mask: Self::Vector,
+    ) -> Self::Vector {
+        self.0.blend_vectors(a, b, mask)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn shift_left_8(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_left_8(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn set_all_bytes(&self, value: u8) -> Self::Vector {
+        self.0.set_all_bytes(value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn create_compare_mask(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.create_compare_mask(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn and_vectors(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.and_vectors(a, b)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn shift_right_32(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_32(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn shift_left_32(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_left_32(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse4.1")]
+    unsafe fn create_vector_from_u32(&self, value: u32, high: bool) -> Self::Vector {
+        self.0.create_vector_from_u32(value, high)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn shift_left_4(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_left_4(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn shift_right_4(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_4(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn shift_right_8(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_8(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn shift_right_5(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_5(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn shift_right_6(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_6(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn shift_right_7(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_7(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn shift_right_12(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_right_12(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn shift_left_12(&self, vector: Self::Vector) -> Self::Vector {
+        self.0.shift_left_12(vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "pclmulqdq")]
+    unsafe fn carryless_mul_00(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.carryless_mul_00(a, b)
+    }
+
+    #[inline]
+    #[target_feature(enable = "pclmulqdq")]
+    unsafe fn carryless_mul_01(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.carryless_mul_01(a, b)
+    }
+
+    #[inline]
+    #[target_feature(enable = "pclmulqdq")]
+    unsafe fn carryless_mul_10(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.carryless_mul_10(a, b)
+    }
+
+    #[inline]
+    #[target_feature(enable = "pclmulqdq")]
+    unsafe fn carryless_mul_11(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector {
+        self.0.carryless_mul_11(a, b)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn xor3_vectors(
+        &self,
+        a: Self::Vector,
+        b: Self::Vector,
+        c: Self::Vector,
+    ) -> Self::Vector {
+        // No AVX-512 ternary logic on this tier, so use the SSE two-XOR implementation
+        self.0.xor3_vectors(a, b, c)
+    }
+}
+
+#[cfg(test)]
+#[rustversion::since(1.89)]
+mod tests {
+    use super::*;
+    use crate::algorithm;
+    use crate::structs::{Width32, Width64};
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use rand::{rng, Rng};
+
+    /// This tier is only selected on CPUs without AVX-512, so exercise it directly on any CPU
+    /// that supports it, regardless of which tier is actually selected.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_matches_reference() {
+        if !(is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("vpclmulqdq")
+            && is_x86_feature_detected!("pclmulqdq")
+            && is_x86_feature_detected!("sse4.1"))
+        {
+            return;
+        }
+
+        let ops = X86_64Avx2VpclmulqdqOps::new();
+        let mut rng = rng();
+
+        // every block count parity and remainder, plus a large buffer
+        for len in (0..=1152).chain([65535, 65536, 65537]) {
+            let mut data = vec![0u8; len];
+            rng.fill(&mut data[..]);
+
+            for config in TEST_ALL_CONFIGS {
+                let params = *config.get_params();
+
+                let actual = unsafe {
+                    match params.width {
+                        64 => algorithm::update::<_, Width64>(params.init, &data, params, &ops),
+                        32 => {
+                            algorithm::update::<_, Width32>(params.init as u32, &data, params, &ops)
+                                as u64
+                        }
+                        _ => panic!("Unsupported CRC width: {}", params.width),
+                    }
+                } ^ params.xorout;
+
+                assert_eq!(
+                    actual,
+                    config.checksum_with_reference(&data),
+                    "Mismatch CRC, {}, length {len}",
+                    config.get_name()
+                );
+            }
+        }
+    }
+}
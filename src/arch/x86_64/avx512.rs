@@ -23,7 +23,7 @@ pub struct X86_64Avx512PclmulqdqOps(X86SsePclmulqdqOps);
 #[rustversion::since(1.89)]
 impl X86_64Avx512PclmulqdqOps {
     #[inline(always)]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self(X86SsePclmulqdqOps)
     }
 }
@@ -82,6 +82,21 @@ impl ArchOps for X86_64Avx512PclmulqdqOps {
         self.0.load_aligned(ptr)
     }
 
+    #[inline(always)]
+    unsafe fn store_bytes(&self, ptr: *mut u8, vector: Self::Vector) {
+        self.0.store_bytes(ptr, vector)
+    }
+
+    #[inline(always)]
+    unsafe fn store_bytes_non_temporal(&self, ptr: *mut u8, vector: Self::Vector) {
+        self.0.store_bytes_non_temporal(ptr, vector)
+    }
+
+    #[inline(always)]
+    unsafe fn store_fence(&self) {
+        self.0.store_fence()
+    }
+
     #[inline(always)]
     unsafe fn shuffle_bytes(&self, data: Self::Vector, mask: Self::Vector) -> Self::Vector {
         self.0.shuffle_bytes(data, mask)
@@ -2,7 +2,8 @@
 
 //! This module provides AVX-512 and VPCLMULQDQ-specific implementations of the ArchOps trait.
 //!
-//! It performs folding using 4 x ZMM registers of 512-bits each.
+//! It performs folding using 4 x ZMM registers of 512-bits each, widening to 16 x ZMM registers
+//! (folding 1KiB at a time) for buffers of 2KiB or more.
 
 #![cfg(target_arch = "x86_64")]
 
@@ -13,7 +14,10 @@ use crate::arch::x86::sse::X86SsePclmulqdqOps;
 use crate::enums::Reflector;
 
 #[rustversion::since(1.89)]
-use crate::structs::CrcState;
+use crate::algorithm;
+
+#[rustversion::since(1.89)]
+use crate::structs::{CrcState, MultiLane};
 
 #[rustversion::since(1.89)]
 use crate::traits::{ArchOps, EnhancedCrcWidth};
@@ -33,7 +37,7 @@ pub struct X86_64Avx512VpclmulqdqOps(X86SsePclmulqdqOps);
 #[rustversion::since(1.89)]
 impl X86_64Avx512VpclmulqdqOps {
     #[inline(always)]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self(X86SsePclmulqdqOps)
     }
 }
@@ -96,6 +100,7 @@ impl Simd512 {
     #[inline]
     #[target_feature(enable = "avx512f")]
     #[allow(unused)]
+    #[cfg(feature = "std")]
     unsafe fn print_hex(&self, prefix: &str) {
         let values = self.extract_u64s();
         println!(
@@ -126,7 +131,7 @@ impl X86_64Avx512VpclmulqdqOps {
         state: &mut CrcState<<X86_64Avx512VpclmulqdqOps as ArchOps>::Vector>,
         first: &[__m128i; 8],
         rest: &[[__m128i; 8]],
-        keys: [u64; 23],
+        keys: [u64; 27],
         reflected: bool,
     ) -> W::Value
     where
@@ -142,22 +147,21 @@ impl X86_64Avx512VpclmulqdqOps {
 
         let reflector = create_reflector512(reflected);
 
-        // Load first 256 bytes (2nd half is rest[0] since these are 128-byte blocks)
-        let first_ptr = first.as_ptr() as *const u8;
-        let first_rest_ptr = rest[0].as_ptr() as *const u8;
-
-        let mut x = [
-            reflect_bytes512(&reflector, Simd512::load_from_ptr(first_ptr)),
-            reflect_bytes512(&reflector, Simd512::load_from_ptr(first_ptr.add(64))),
-            reflect_bytes512(&reflector, Simd512::load_from_ptr(first_rest_ptr)),
-            reflect_bytes512(&reflector, Simd512::load_from_ptr(first_rest_ptr.add(64))),
-        ];
+        // Large buffers get a wide 16 x ZMM front end when the 512 and 1024-byte folding keys
+        // are available (they're left as zero when they couldn't be looked up)
+        let (mut x, remaining_rest) =
+            if rest.len() + 1 >= WIDE_MIN_BLOCKS && keys[23..].iter().all(|&key| key != 0) {
+                self.process_wide_blocks(positioned_state, first, rest, &reflector, keys, reflected)
+            } else {
+                // Load first 256 bytes (2nd half is rest[0] since these are 128-byte blocks)
+                let [x0, x1] = load_block512(&reflector, first);
+                let [x2, x3] = load_block512(&reflector, &rest[0]);
 
-        x[0] = positioned_state.xor(&x[0]);
+                ([positioned_state.xor(&x0), x1, x2, x3], &rest[1..])
+            };
 
         let coeff = self.create_avx512_256byte_coefficient(keys, reflected);
 
-        let remaining_rest = &rest[1..];
         let pair_count = remaining_rest.len() / 2;
 
         for i in 0..pair_count {
@@ -215,11 +219,154 @@ impl X86_64Avx512VpclmulqdqOps {
         W::perform_final_reduction(folded, reflected, keys, self)
     }
 
+    /// Process the bulk of a large buffer using 16 x 512-bit registers folding 1KiB at a time,
+    /// then reduce to the 4 x 512-bit registers used by the standard loop.
+    ///
+    /// Returns the 4 registers and the blocks that still need to be folded.
+    #[inline(always)]
+    unsafe fn process_wide_blocks<'a>(
+        &self,
+        positioned_state: Simd512,
+        first: &[__m128i; 8],
+        rest: &'a [[__m128i; 8]],
+        reflector: &Reflector512,
+        keys: [u64; 27],
+        reflected: bool,
+    ) -> ([Simd512; 4], &'a [[__m128i; 8]]) {
+        const BLOCKS: usize = WIDE_ACCUMULATORS / 2;
+
+        let mut x = [positioned_state; WIDE_ACCUMULATORS];
+
+        // Load the first 1KiB (first + rest[0..7])
+        [x[0], x[1]] = load_block512(reflector, first);
+        for i in 1..BLOCKS {
+            [x[i * 2], x[i * 2 + 1]] = load_block512(reflector, &rest[i - 1]);
+        }
+
+        x[0] = positioned_state.xor(&x[0]);
+
+        let coeff = self.create_avx512_1024byte_coefficient(keys, reflected);
+
+        let mut chunks = rest[BLOCKS - 1..].chunks_exact(BLOCKS);
+
+        for chunk in &mut chunks {
+            // Fixed-size view so the loop fully unrolls and the accumulators stay in registers
+            let chunk: &[[__m128i; 8]; BLOCKS] = chunk.try_into().unwrap_unchecked();
+
+            for (i, block) in chunk.iter().enumerate() {
+                let [lo, hi] = load_block512(reflector, block);
+
+                x[i * 2] = x[i * 2].fold_64(&coeff, &lo);
+                x[i * 2 + 1] = x[i * 2 + 1].fold_64(&coeff, &hi);
+            }
+        }
+
+        // Fold 16 x 512-bit to 8 x 512-bit (registers 512 bytes apart)
+        let coeff = self.create_avx512_512byte_coefficient(keys, reflected);
+        for i in 0..8 {
+            x[i] = x[i].fold_64(&coeff, &x[i + 8]);
+        }
+
+        // Fold 8 x 512-bit to 4 x 512-bit (registers 256 bytes apart)
+        let coeff = self.create_avx512_256byte_coefficient(keys, reflected);
+        for i in 0..4 {
+            x[i] = x[i].fold_64(&coeff, &x[i + 4]);
+        }
+
+        ([x[0], x[1], x[2], x[3]], chunks.remainder())
+    }
+
+    /// Process aligned blocks for several CRCs at once using 4 x 512-bit registers per CRC,
+    /// loading (and byte-swapping, if any CRC is forward) each 512-bit vector only once
+    #[inline]
+    #[target_feature(enable = "avx512vl,avx512bw,vpclmulqdq")]
+    unsafe fn process_multi_blocks<const N: usize>(
+        &self,
+        lanes: &mut [MultiLane<__m128i>; N],
+        first: &[__m128i; 8],
+        rest: &[[__m128i; 8]],
+    ) {
+        let forward_reflector = create_reflector512(false);
+        let any_forward = lanes.iter().any(|lane| !lane.state.reflected);
+
+        // Load first 256 bytes (2nd half is rest[0] since these are 128-byte blocks)
+        let [d0, d1] = load_block512(&Reflector512::NoReflector, first);
+        let [d2, d3] = load_block512(&Reflector512::NoReflector, &rest[0]);
+        let data = [d0, d1, d2, d3];
+        let swapped = swap_multi_data(&forward_reflector, data, any_forward);
+
+        let mut x = [data; N];
+        let mut coeffs = [d0; N];
+
+        for (l, lane) in lanes.iter().enumerate() {
+            let reflected = lane.state.reflected;
+            let state_u64s = self.extract_u64s(lane.state.value);
+
+            let positioned_state = if reflected {
+                x[l] = data;
+                Simd512::new(0, 0, 0, 0, 0, 0, 0, state_u64s[0])
+            } else {
+                x[l] = swapped;
+                Simd512::new(state_u64s[1], 0, 0, 0, 0, 0, 0, 0)
+            };
+
+            x[l][0] = positioned_state.xor(&x[l][0]);
+
+            coeffs[l] = self.create_avx512_256byte_coefficient(lane.keys, reflected);
+        }
+
+        let mut pairs = rest[1..].chunks_exact(2);
+
+        for pair in &mut pairs {
+            let [d0, d1] = load_block512(&Reflector512::NoReflector, &pair[0]);
+            let [d2, d3] = load_block512(&Reflector512::NoReflector, &pair[1]);
+            let data = [d0, d1, d2, d3];
+            let swapped = swap_multi_data(&forward_reflector, data, any_forward);
+
+            for l in 0..N {
+                let y = if lanes[l].state.reflected {
+                    &data
+                } else {
+                    &swapped
+                };
+
+                for i in 0..4 {
+                    x[l][i] = x[l][i].fold_64(&coeffs[l], &y[i]);
+                }
+            }
+        }
+
+        for (lane, x) in lanes.iter_mut().zip(x) {
+            let reflected = lane.state.reflected;
+
+            let folded = if let [block] = pairs.remainder() {
+                // We have 1 unprocessed block (128 bytes)
+                // Fold 4×512 down to 2×512 and process the remaining block with 2-register mode
+                let reflector = create_reflector512(reflected);
+                let [lo, hi] = load_block512(&reflector, block);
+
+                let folded_2reg = self.fold_from_4x512_to_2x256(x, lane.keys, reflected);
+                let coeff_2reg = self.create_avx512_128byte_coefficient(lane.keys, reflected);
+
+                let final_x = [
+                    folded_2reg[0].fold_64(&coeff_2reg, &lo),
+                    folded_2reg[1].fold_64(&coeff_2reg, &hi),
+                ];
+
+                self.fold_from_2x512_to_1x128(final_x, lane.keys, reflected)
+            } else {
+                self.fold_from_4x512_to_1x128(x, lane.keys, reflected)
+            };
+
+            algorithm::reduce_multi_lane(lane, folded, self);
+        }
+    }
+
     /// Create a folding coefficient for AVX-512 for 128-byte folding distances
     #[inline(always)]
     unsafe fn create_avx512_128byte_coefficient(
         &self,
-        keys: [u64; 23],
+        keys: [u64; 27],
         reflected: bool,
     ) -> Simd512 {
         let (k1, k2) = if reflected {
@@ -236,7 +383,7 @@ impl X86_64Avx512VpclmulqdqOps {
     #[inline(always)]
     unsafe fn create_avx512_256byte_coefficient(
         &self,
-        keys: [u64; 23],
+        keys: [u64; 27],
         reflected: bool,
     ) -> Simd512 {
         let (k1, k2) = if reflected {
@@ -249,12 +396,46 @@ impl X86_64Avx512VpclmulqdqOps {
         Simd512::new(k1, k2, k1, k2, k1, k2, k1, k2)
     }
 
+    /// Create a folding coefficient for AVX-512 for 512-byte folding distances
+    #[inline(always)]
+    unsafe fn create_avx512_512byte_coefficient(
+        &self,
+        keys: [u64; 27],
+        reflected: bool,
+    ) -> Simd512 {
+        let (k1, k2) = if reflected {
+            (keys[23], keys[24])
+        } else {
+            (keys[24], keys[23])
+        };
+
+        // Replicate the coefficient pair
+        Simd512::new(k1, k2, k1, k2, k1, k2, k1, k2)
+    }
+
+    /// Create a folding coefficient for AVX-512 for 1024-byte folding distances
+    #[inline(always)]
+    unsafe fn create_avx512_1024byte_coefficient(
+        &self,
+        keys: [u64; 27],
+        reflected: bool,
+    ) -> Simd512 {
+        let (k1, k2) = if reflected {
+            (keys[25], keys[26])
+        } else {
+            (keys[26], keys[25])
+        };
+
+        // Replicate the coefficient pair
+        Simd512::new(k1, k2, k1, k2, k1, k2, k1, k2)
+    }
+
     /// Fold from 4 x 512-bit to 1 x 128-bit
     #[inline(always)]
     unsafe fn fold_from_4x512_to_1x128(
         &self,
         x: [Simd512; 4],
-        keys: [u64; 23],
+        keys: [u64; 27],
         reflected: bool,
     ) -> __m128i {
         // Step 1: Fold 4 x 512-bit to 2 x 512-bit
@@ -269,7 +450,7 @@ impl X86_64Avx512VpclmulqdqOps {
     unsafe fn fold_from_4x512_to_2x256(
         &self,
         x: [Simd512; 4],
-        keys: [u64; 23],
+        keys: [u64; 27],
         reflected: bool,
     ) -> [Simd512; 2] {
         // This folds registers that are 128 bytes apart (x[0] with x[2], x[1] with x[3])
@@ -286,7 +467,7 @@ impl X86_64Avx512VpclmulqdqOps {
     unsafe fn fold_from_2x512_to_1x128(
         &self,
         x: [Simd512; 2],
-        keys: [u64; 23],
+        keys: [u64; 27],
         reflected: bool,
     ) -> __m128i {
         // Create the fold coefficients for different distances
@@ -339,6 +520,49 @@ impl X86_64Avx512VpclmulqdqOps {
     }
 }
 
+/// Number of 512-bit accumulators used by the wide folding loop
+///
+/// Folding 2KiB at a time would take 32 accumulators, every ZMM register, and measured slower
+/// (49 GiB/s against 62 GiB/s for CRC-64/NVME at 1 MiB), so there are no 2048-byte keys.
+#[rustversion::since(1.89)]
+const WIDE_ACCUMULATORS: usize = 16;
+
+/// Minimum number of 128-byte blocks before the wide folding loop pays off (2KiB)
+#[rustversion::since(1.89)]
+const WIDE_MIN_BLOCKS: usize = WIDE_ACCUMULATORS;
+
+// Load a 128-byte block as 2 x 512-bit vectors, applying reflection if needed
+#[rustversion::since(1.89)]
+#[inline(always)]
+unsafe fn load_block512(reflector: &Reflector512, block: &[__m128i; 8]) -> [Simd512; 2] {
+    let ptr = block.as_ptr() as *const u8;
+
+    [
+        reflect_bytes512(reflector, Simd512::load_from_ptr(ptr)),
+        reflect_bytes512(reflector, Simd512::load_from_ptr(ptr.add(64))),
+    ]
+}
+
+// Byte-swap loaded vectors for forward CRCs, only if any lane needs them
+#[rustversion::since(1.89)]
+#[inline(always)]
+unsafe fn swap_multi_data(
+    forward_reflector: &Reflector512,
+    data: [Simd512; 4],
+    any_forward: bool,
+) -> [Simd512; 4] {
+    if !any_forward {
+        return data;
+    }
+
+    [
+        reflect_bytes512(forward_reflector, data[0]),
+        reflect_bytes512(forward_reflector, data[1]),
+        reflect_bytes512(forward_reflector, data[2]),
+        reflect_bytes512(forward_reflector, data[3]),
+    ]
+}
+
 // 512-bit version of the Reflector
 #[rustversion::since(1.89)]
 #[derive(Clone, Copy)]
@@ -382,7 +606,7 @@ unsafe fn reflect_bytes512(reflector: &Reflector512, data: Simd512) -> Simd512 {
 // pre-compute the reverse indices for 512-bit shuffling
 #[rustversion::since(1.89)]
 static REVERSE_INDICES_512: __m512i =
-    unsafe { std::mem::transmute([7u64, 6u64, 5u64, 4u64, 3u64, 2u64, 1u64, 0u64]) };
+    unsafe { core::mem::transmute([7u64, 6u64, 5u64, 4u64, 3u64, 2u64, 1u64, 0u64]) };
 
 // Implement a 512-bit byte shuffle function
 #[rustversion::since(1.89)]
@@ -408,7 +632,7 @@ impl ArchOps for X86_64Avx512VpclmulqdqOps {
         first: &[Self::Vector; 8],
         rest: &[[Self::Vector; 8]],
         _reflector: &Reflector<Self::Vector>,
-        keys: [u64; 23],
+        keys: [u64; 27],
     ) -> bool
     where
         Self::Vector: Copy,
@@ -424,6 +648,22 @@ impl ArchOps for X86_64Avx512VpclmulqdqOps {
         true
     }
 
+    #[inline(always)]
+    unsafe fn process_enhanced_multi_blocks<const N: usize>(
+        &self,
+        lanes: &mut [MultiLane<Self::Vector>; N],
+        first: &[Self::Vector; 8],
+        rest: &[[Self::Vector; 8]],
+    ) -> bool
+    where
+        Self::Vector: Copy,
+    {
+        self.process_multi_blocks(lanes, first, rest);
+
+        // Return true to indicate we handled it
+        true
+    }
+
     // Delegate all other methods to X86SsePclmulqdqOps
     #[inline]
     #[target_feature(enable = "sse2")]
@@ -482,6 +722,24 @@ impl ArchOps for X86_64Avx512VpclmulqdqOps {
         self.0.load_aligned(ptr)
     }
 
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn store_bytes(&self, ptr: *mut u8, vector: Self::Vector) {
+        self.0.store_bytes(ptr, vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn store_bytes_non_temporal(&self, ptr: *mut u8, vector: Self::Vector) {
+        self.0.store_bytes_non_temporal(ptr, vector)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn store_fence(&self) {
+        self.0.store_fence()
+    }
+
     #[inline]
     #[target_feature(enable = "ssse3")]
     unsafe fn shuffle_bytes(&self, data: Self::Vector, mask: Self::Vector) -> Self::Vector {
@@ -2,7 +2,7 @@
 
 //! This is a simple program to calculate a checksum from the command line
 
-use crc_fast::{checksum, checksum_file, CrcAlgorithm};
+use crc_fast::{checksum, checksum_file, checksum_file_blocks, BlockManifest, CrcAlgorithm};
 use std::env;
 use std::process::ExitCode;
 use std::str::FromStr;
@@ -14,6 +14,14 @@ struct Config {
     string: Option<String>,
     format: OutputFormat,
     benchmark: Option<BenchmarkConfig>,
+    blocks: Option<BlockConfig>,
+}
+
+#[derive(Debug)]
+struct BlockConfig {
+    size: usize,
+    manifest: Option<String>,
+    diff: Option<String>,
 }
 
 #[derive(Debug)]
@@ -185,6 +193,9 @@ fn generate_random_data(size: usize) -> Result<Vec<u8>, String> {
 
 fn print_usage() {
     println!("Usage: checksum -a algorithm [-f file] [-s string] [--format hex|decimal]");
+    println!(
+        "       checksum -a algorithm --blocks bytes [--manifest out] [--diff manifest] [-f file] [-s string]"
+    );
     println!(
         "       checksum -a algorithm -b [--size bytes] [--duration seconds] [-f file] [-s string]"
     );
@@ -192,6 +203,7 @@ fn print_usage() {
     println!("Example: checksum -a CRC-32/ISCSI -f myfile.txt");
     println!("Example: checksum -a CRC-64/NVME -s 'Hello, world!' --format decimal");
     println!("Example: checksum -a CRC-32/ISCSI -b --size 1048576 --duration 5.0");
+    println!("Example: checksum -a CRC-64/NVME --blocks 4096 --diff old.manifest -f myfile.bin");
     println!();
     println!("Options:");
     println!("  -a algorithm        Specify the checksum algorithm (required)");
@@ -205,6 +217,11 @@ fn print_usage() {
     println!("  --duration seconds  Benchmark duration in seconds (default: 10.0)");
     println!("  --size bytes        Data size for random generation in benchmark mode (default: 1048576 [1MiB])");
     println!();
+    println!("Block manifests:");
+    println!("  --blocks bytes      Print the checksum of each block, then of the whole input");
+    println!("  --manifest file     Write the block manifest to the specified file");
+    println!("  --diff file         Print the offset and length of each range changed since the specified manifest");
+    println!();
     println!();
     println!("Note: In normal mode, either -f or -s must be provided, but not both.");
     println!("      In benchmark mode (-b), -f or -s are optional for using specific data.");
@@ -229,6 +246,9 @@ fn parse_args() -> Result<Config, String> {
     let mut benchmark_mode = false;
     let mut benchmark_size: Option<usize> = None;
     let mut benchmark_duration = 10.0; // Default duration
+    let mut block_size: Option<usize> = None;
+    let mut manifest: Option<String> = None;
+    let mut diff: Option<String> = None;
 
     let mut i = 1; // Skip program name
     while i < args.len() {
@@ -300,6 +320,31 @@ fn parse_args() -> Result<Config, String> {
                     .map_err(|_| format!("Invalid duration value: {}", args[i + 1]))?;
                 i += 2;
             }
+            "--blocks" => {
+                if i + 1 >= args.len() {
+                    return Err("Missing size value after --blocks flag".to_string());
+                }
+                block_size = Some(
+                    args[i + 1]
+                        .parse::<usize>()
+                        .map_err(|_| format!("Invalid block size value: {}", args[i + 1]))?,
+                );
+                i += 2;
+            }
+            "--manifest" => {
+                if i + 1 >= args.len() {
+                    return Err("Missing filename after --manifest flag".to_string());
+                }
+                manifest = Some(args[i + 1].clone());
+                i += 2;
+            }
+            "--diff" => {
+                if i + 1 >= args.len() {
+                    return Err("Missing filename after --diff flag".to_string());
+                }
+                diff = Some(args[i + 1].clone());
+                i += 2;
+            }
             arg => {
                 return Err(format!("Unknown argument: {}", arg));
             }
@@ -314,6 +359,23 @@ fn parse_args() -> Result<Config, String> {
         return Err("--size and --duration can only be used with -b flag".to_string());
     }
 
+    // Create block config if block manifests were requested
+    let blocks = match block_size {
+        Some(_) if benchmark_mode => {
+            return Err("--blocks cannot be used with -b flag".to_string());
+        }
+        Some(0) => return Err("Block size must be greater than 0".to_string()),
+        Some(size) => Some(BlockConfig {
+            size,
+            manifest,
+            diff,
+        }),
+        None if manifest.is_some() || diff.is_some() => {
+            return Err("--manifest and --diff can only be used with --blocks flag".to_string());
+        }
+        None => None,
+    };
+
     // Create benchmark config if in benchmark mode
     let benchmark = if benchmark_mode {
         let config = BenchmarkConfig {
@@ -343,6 +405,7 @@ fn parse_args() -> Result<Config, String> {
         string,
         format,
         benchmark,
+        blocks,
     })
 }
 
@@ -355,6 +418,10 @@ fn calculate_checksum(config: &Config) -> Result<(), String> {
         return run_benchmark(config, benchmark_config, algorithm);
     }
 
+    if let Some(block_config) = &config.blocks {
+        return calculate_blocks(config, block_config, algorithm);
+    }
+
     let checksum = if let Some(ref filename) = config.file {
         checksum_file(algorithm, filename, None).unwrap()
     } else if let Some(ref text) = config.string {
@@ -371,6 +438,58 @@ fn calculate_checksum(config: &Config) -> Result<(), String> {
     Ok(())
 }
 
+fn format_checksum(checksum: u64, format: &OutputFormat) -> String {
+    match format {
+        OutputFormat::Hex => format!("{:#x?}", checksum),
+        OutputFormat::Decimal => format!("{}", checksum),
+    }
+}
+
+fn calculate_blocks(
+    config: &Config,
+    block_config: &BlockConfig,
+    algorithm: CrcAlgorithm,
+) -> Result<(), String> {
+    let manifest = if let Some(ref filename) = config.file {
+        checksum_file_blocks(algorithm, filename, block_config.size)
+            .map_err(|e| format!("Failed to read file: {}", e))?
+    } else if let Some(ref text) = config.string {
+        BlockManifest::new(algorithm, text.as_bytes(), block_config.size)
+    } else {
+        return Err("No input provided for checksum calculation".to_string());
+    };
+
+    if let Some(ref filename) = block_config.manifest {
+        std::fs::write(filename, manifest.to_bytes())
+            .map_err(|e| format!("Failed to write manifest: {}", e))?;
+    }
+
+    if let Some(ref filename) = block_config.diff {
+        let bytes =
+            std::fs::read(filename).map_err(|e| format!("Failed to read manifest: {}", e))?;
+        let previous = BlockManifest::from_bytes(algorithm, &bytes)
+            .map_err(|e| format!("{}: {}", filename, e))?;
+
+        for (offset, len) in previous.diff(&manifest).map_err(|e| e.to_string())? {
+            println!("{} {}", offset, len);
+        }
+
+        return Ok(());
+    }
+
+    for (i, checksum) in manifest.blocks().iter().enumerate() {
+        println!(
+            "{} {}",
+            i * block_config.size,
+            format_checksum(*checksum, &config.format)
+        );
+    }
+
+    println!("{}", format_checksum(manifest.checksum(), &config.format));
+
+    Ok(())
+}
+
 fn run_benchmark(
     config: &Config,
     benchmark_config: &BenchmarkConfig,
@@ -613,6 +732,7 @@ mod tests {
                 size: Some(1024),
                 duration: 5.0,
             }),
+            blocks: None,
         };
 
         assert!(config.benchmark.is_some());
@@ -629,6 +749,7 @@ mod tests {
             string: None,
             format: OutputFormat::Hex,
             benchmark: None,
+            blocks: None,
         };
 
         assert!(config.benchmark.is_none());
@@ -647,6 +768,7 @@ mod tests {
                 size: None,
                 duration: 1.0,
             }),
+            blocks: None,
         };
 
         // This would be tested in the run_benchmark function
@@ -666,6 +788,7 @@ mod tests {
                 size: None,
                 duration: 1.0,
             }),
+            blocks: None,
         };
 
         assert!(config.file.is_none());
@@ -683,6 +806,7 @@ mod tests {
                 size: Some(1024),
                 duration: 1.0,
             }),
+            blocks: None,
         };
 
         // When neither file nor string is provided, generated data should be used
@@ -2,6 +2,7 @@
 
 //! This is a simple program to get custom CRC parameters from the command line.
 
+use crc_fast::analysis;
 use std::env;
 use std::process::ExitCode;
 
@@ -14,6 +15,9 @@ struct Config {
     xorout: Option<u64>,
     check: Option<u64>,
     name: Option<String>,
+    analyze: bool,
+    rank: bool,
+    length: Option<u64>,
 }
 
 impl Config {
@@ -26,6 +30,9 @@ impl Config {
             xorout: None,
             check: None,
             name: None,
+            analyze: false,
+            rank: false,
+            length: None,
         }
     }
 
@@ -38,6 +45,11 @@ impl Config {
             && self.check.is_some()
             && self.name.is_some()
     }
+
+    /// Analysis only depends on the polynomial, so the other parameters are optional.
+    fn is_complete_for_analysis(&self) -> bool {
+        self.width.is_some() && self.polynomial.is_some()
+    }
 }
 
 fn parse_hex_or_decimal(s: &str) -> Result<u64, String> {
@@ -116,6 +128,21 @@ fn parse_args(args: &[String]) -> Result<Config, String> {
                 config.check = Some(parse_hex_or_decimal(&args[i + 1])?);
                 i += 2;
             }
+            "-l" => {
+                if i + 1 >= args.len() {
+                    return Err("Missing value for -l (length)".to_string());
+                }
+                config.length = Some(parse_hex_or_decimal(&args[i + 1])?);
+                i += 2;
+            }
+            "--analyze" => {
+                config.analyze = true;
+                i += 1;
+            }
+            "--rank" => {
+                config.rank = true;
+                i += 1;
+            }
             arg => {
                 return Err(format!("Unknown argument: {arg}",));
             }
@@ -127,6 +154,8 @@ fn parse_args(args: &[String]) -> Result<Config, String> {
 
 fn print_usage() {
     println!("Usage: get-custom-params -n <name> -w <width> -p <polynomial> -i <init> -r <reflected> -x <xorout> -c <check>");
+    println!("       get-custom-params --analyze -w <width> -p <polynomial> [-l <length>]");
+    println!("       get-custom-params --rank [-l <length>]");
     println!();
     println!("Example: get-custom-params -n CRC-32/ISCSI -w 32 -p 0x1edc6f41 -i 0xFFFFFFFF -r true -x 0xFFFFFFFF -c 0xe3069283");
     println!("Example: get-custom-params -n CRC-64/NVME -w 64 -p 0xad93d23594c93659 -i 0xffffffffffffffff -r true -x 0xffffffffffffffff -c 0xae8b14860a799888");
@@ -139,6 +168,89 @@ fn print_usage() {
     println!("  -r <reflected>  Reflected input/output (true/false)");
     println!("  -x <xorout>     XOR output value (hex or decimal)");
     println!("  -c <check>      Check value (hex or decimal)");
+    println!();
+    println!("Analysis:");
+    println!("  --analyze       Report Hamming distance vs message length, undetected errors, and burst detection");
+    println!(
+        "  --rank          Rank the predefined algorithms by error detection at the message length"
+    );
+    println!("  -l <length>     Message length in bytes (default: {DEFAULT_ANALYSIS_LENGTH})");
+}
+
+/// Long enough to show where most 32-bit polynomials drop to HD=4, short enough to run quickly.
+const DEFAULT_ANALYSIS_LENGTH: u64 = 512;
+
+fn print_analysis(config: &Config) -> Result<(), String> {
+    let width = config.width.unwrap();
+    let poly = config.polynomial.unwrap();
+    let length = config.length.unwrap_or(DEFAULT_ANALYSIS_LENGTH);
+
+    if width != 32 && width != 64 {
+        return Err(format!(
+            "Unsupported width for --analyze: {width} (use 32 or 64)"
+        ));
+    }
+
+    // only the polynomial matters for analysis, so the rest are placeholders
+    let params = crc_fast::CrcParams::new("CRC/ANALYZE", width as u8, poly, 0, false, 0, 0);
+    let analysis = analysis::analyze(params, length * 8);
+
+    println!();
+    println!("Polynomial: 0x{poly:x} (width {width})");
+    println!(
+        "Burst detection: every burst up to {} bits",
+        analysis.burst_detection_bits
+    );
+    println!();
+    println!("Hamming distance by message length:");
+
+    for range in &analysis.hd_profile {
+        println!(
+            "  HD {:<4} up to {} bits ({} bytes)",
+            range.hd.to_string(),
+            range.max_data_bits,
+            range.max_data_bits / 8
+        );
+    }
+
+    println!();
+    match analysis::undetected_errors(params, length * 8) {
+        Some(errors) => println!(
+            "Undetected errors at {length} bytes: {} patterns of weight {}",
+            errors.count, errors.weight
+        ),
+        None => println!("Undetected errors at {length} bytes: beyond the search limits"),
+    }
+    println!();
+
+    Ok(())
+}
+
+fn print_ranking(config: &Config) {
+    let length = config.length.unwrap_or(DEFAULT_ANALYSIS_LENGTH);
+
+    println!();
+    println!("Ranking for {length}-byte messages:");
+    println!();
+    println!(
+        "  {:<18} {:<5} {:<5} Undetected at HD",
+        "Algorithm", "Width", "HD"
+    );
+
+    for entry in analysis::rank_catalogue(length as usize) {
+        let undetected = match entry.undetected {
+            Some(errors) => errors.count.to_string(),
+            None => "-".to_string(),
+        };
+
+        println!(
+            "  {:<18} {:<5} {:<5} {undetected}",
+            entry.name,
+            entry.width,
+            entry.hd.to_string()
+        );
+    }
+    println!();
 }
 
 fn main() -> ExitCode {
@@ -159,6 +271,27 @@ fn main() -> ExitCode {
         }
     };
 
+    if config.rank {
+        print_ranking(&config);
+        return ExitCode::from(0);
+    }
+
+    if config.analyze {
+        if !config.is_complete_for_analysis() {
+            eprintln!("Error: --analyze requires -w and -p");
+            println!();
+            print_usage();
+            return ExitCode::from(1);
+        }
+
+        if let Err(error) = print_analysis(&config) {
+            eprintln!("Error: {error}");
+            return ExitCode::from(1);
+        }
+
+        return ExitCode::from(0);
+    }
+
     // Check if all required arguments are provided
     if !config.is_complete() {
         eprintln!("Error: All arguments are required");
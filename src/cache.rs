@@ -40,6 +40,16 @@ static CACHE: OnceLock<RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>>> = OnceLock
 #[cfg(all(not(feature = "std"), feature = "cache"))]
 static CACHE: Once<RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>>> = Once::new();
 
+/// Global cache storage for the wide (512 and 1024-byte distance) folding keys
+///
+/// Kept apart from `CACHE` because these keys live outside `CrcKeysStorage` and are only
+/// needed by the AVX-512 VPCLMULQDQ tier when folding large buffers.
+#[cfg(feature = "std")]
+static WIDE_CACHE: OnceLock<RwLock<HashMap<CrcParamsCacheKey, [u64; 4]>>> = OnceLock::new();
+
+#[cfg(all(not(feature = "std"), feature = "cache"))]
+static WIDE_CACHE: Once<RwLock<HashMap<CrcParamsCacheKey, [u64; 4]>>> = Once::new();
+
 /// Cache key for storing CRC parameters that affect key generation
 ///
 /// Only includes parameters that directly influence the mathematical computation
@@ -91,6 +101,16 @@ fn get_cache() -> &'static RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>> {
     CACHE.call_once(|| RwLock::new(HashMap::new()))
 }
 
+#[cfg(feature = "std")]
+fn get_wide_cache() -> &'static RwLock<HashMap<CrcParamsCacheKey, [u64; 4]>> {
+    WIDE_CACHE.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+#[cfg(all(not(feature = "std"), feature = "cache"))]
+fn get_wide_cache() -> &'static RwLock<HashMap<CrcParamsCacheKey, [u64; 4]>> {
+    WIDE_CACHE.call_once(|| RwLock::new(HashMap::new()))
+}
+
 /// Get cached keys or generate and cache them if not present
 ///
 /// This function implements a read-then-write pattern optimized for the common case
@@ -171,6 +191,63 @@ pub fn get_or_generate_keys(width: u8, poly: u64, reflected: bool) -> [u64; 23]
     }
 }
 
+/// Get cached wide folding keys or generate and cache them if not present
+///
+/// Follows the same read-then-write pattern as `get_or_generate_keys()`. Without a cache,
+/// returns all zeros rather than regenerating the keys on every call, which makes the
+/// AVX-512 VPCLMULQDQ tier skip its wide folding loop and fold at 256-byte distances instead.
+///
+/// # Returns
+///
+/// The 512 and 1024-byte distance folding keys, in the order of `generate::wide_keys()`
+pub(crate) fn get_or_generate_wide_keys(width: u8, poly: u64, reflected: bool) -> [u64; 4] {
+    #[cfg(feature = "std")]
+    {
+        let cache_key = CrcParamsCacheKey::new(width, poly, reflected);
+
+        if let Ok(cache) = get_wide_cache().read() {
+            if let Some(keys) = cache.get(&cache_key) {
+                return *keys;
+            }
+        }
+
+        let keys = generate::wide_keys(width, poly, reflected);
+
+        let _ = get_wide_cache()
+            .write()
+            .map(|mut cache| cache.insert(cache_key, keys));
+
+        keys
+    }
+
+    #[cfg(all(not(feature = "std"), feature = "cache"))]
+    {
+        let cache_key = CrcParamsCacheKey::new(width, poly, reflected);
+
+        {
+            let cache = get_wide_cache().read();
+            if let Some(keys) = cache.get(&cache_key) {
+                return *keys;
+            }
+        }
+
+        let keys = generate::wide_keys(width, poly, reflected);
+
+        {
+            let mut cache = get_wide_cache().write();
+            cache.insert(cache_key, keys);
+        }
+
+        keys
+    }
+
+    #[cfg(not(any(feature = "std", feature = "cache")))]
+    {
+        let _ = (width, poly, reflected);
+        [0; 4]
+    }
+}
+
 /// Clear all cached CRC parameter keys
 ///
 /// This function is primarily intended for testing scenarios where you need to reset
@@ -192,6 +269,7 @@ pub(crate) fn clear_cache() {
         // Best-effort cache clear - if lock is poisoned or unavailable, silently continue
         // This ensures the function never panics or blocks program execution
         let _ = get_cache().write().map(|mut cache| cache.clear());
+        let _ = get_wide_cache().write().map(|mut cache| cache.clear());
     }
 
     #[cfg(all(not(feature = "std"), feature = "cache"))]
@@ -199,6 +277,7 @@ pub(crate) fn clear_cache() {
         // spin::RwLock doesn't use Result wrapper
         let mut cache = get_cache().write();
         cache.clear();
+        get_wide_cache().write().clear();
     }
 }
 
@@ -0,0 +1,415 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Content-defined chunking, for deduplication.
+//!
+//! Splits data into variable-size chunks at boundaries chosen by the content itself, so inserting
+//! or removing bytes only changes the chunks around the edit, and the rest still deduplicate.
+//!
+//! A boundary falls wherever the CRC-32C of the preceding 64 bytes, maintained with a
+//! [`RollingCrc`], has its low bits all zero, subject to the minimum and maximum chunk sizes.
+//! Each chunk's CRC-32C is calculated a block at a time as the boundary search advances, while
+//! the block is still in cache, so the data is only read from memory once.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::chunking::Chunker;
+//! use crc_fast::{checksum, CrcAlgorithm::Crc32Iscsi};
+//!
+//! let data: Vec<u8> = (0..100_000u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect();
+//!
+//! let mut offset = 0;
+//! for chunk in Chunker::new(&data, 2048, 8192, 32768) {
+//!     assert_eq!(chunk.offset, offset);
+//!     assert_eq!(chunk.checksum, checksum(Crc32Iscsi, chunk.data));
+//!
+//!     offset += chunk.data.len() as u64;
+//! }
+//!
+//! assert_eq!(offset, data.len() as u64);
+//! ```
+
+use crate::{CrcAlgorithm, Digest, RollingCrc};
+
+#[cfg(feature = "std")]
+use std::io::{ErrorKind, Read};
+
+/// The number of bytes the rolling CRC covers when looking for boundaries.
+const WINDOW: usize = 64;
+
+/// The number of bytes scanned for a boundary before they're added to the chunk's CRC.
+const BLOCK: usize = 4096;
+
+/// A chunk of data, its offset in the input, and its CRC-32C.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk<T> {
+    pub offset: u64,
+    pub data: T,
+    pub checksum: u64,
+}
+
+/// Splits a slice into content-defined chunks.
+#[derive(Clone, Debug)]
+pub struct Chunker<'a> {
+    data: &'a [u8],
+    offset: u64,
+    boundaries: Boundaries,
+}
+
+impl<'a> Chunker<'a> {
+    /// Creates a new `Chunker` over `data`, with chunks of `min` to `max` bytes, aiming for
+    /// `avg` bytes on average. Only the last chunk can be shorter than `min`.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `64 <= min < avg <= max`.
+    pub fn new(data: &'a [u8], min: usize, avg: usize, max: usize) -> Self {
+        Self {
+            data,
+            offset: 0,
+            boundaries: Boundaries::new(min, avg, max),
+        }
+    }
+}
+
+impl<'a> Iterator for Chunker<'a> {
+    type Item = Chunk<&'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let (length, checksum) = self.boundaries.next_chunk(self.data);
+        let (data, rest) = self.data.split_at(length);
+
+        let chunk = Chunk {
+            offset: self.offset,
+            data,
+            checksum,
+        };
+        self.data = rest;
+        self.offset += length as u64;
+
+        Some(chunk)
+    }
+}
+
+/// Splits a reader's data into content-defined chunks, buffering at most one maximum-size chunk
+/// at a time.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ReadChunker<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    eof: bool,
+    offset: u64,
+    boundaries: Boundaries,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> ReadChunker<R> {
+    /// Creates a new `ReadChunker` over `reader`, with chunks of `min` to `max` bytes, aiming
+    /// for `avg` bytes on average. Only the last chunk can be shorter than `min`.
+    ///
+    /// The chunks are identical to those from [`Chunker`] over the same data, however the reads
+    /// are split.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `64 <= min < avg <= max`.
+    pub fn new(reader: R, min: usize, avg: usize, max: usize) -> Self {
+        Self {
+            reader,
+            buffer: Vec::with_capacity(max),
+            eof: false,
+            offset: 0,
+            boundaries: Boundaries::new(min, avg, max),
+        }
+    }
+
+    /// Reads until the buffer holds a maximum-size chunk, or the reader is exhausted.
+    fn fill(&mut self) -> std::io::Result<()> {
+        while self.buffer.len() < self.boundaries.max && !self.eof {
+            let filled = self.buffer.len();
+            self.buffer.resize(self.boundaries.max, 0);
+
+            let result = self.reader.read(&mut self.buffer[filled..]);
+            let read = match result {
+                Ok(0) => {
+                    self.eof = true;
+                    0
+                }
+                Ok(read) => read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => 0,
+                Err(e) => {
+                    self.buffer.truncate(filled);
+                    return Err(e);
+                }
+            };
+            self.buffer.truncate(filled + read);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> Iterator for ReadChunker<R> {
+    type Item = std::io::Result<Chunk<Vec<u8>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(e) = self.fill() {
+            return Some(Err(e));
+        }
+        if self.buffer.is_empty() {
+            return None;
+        }
+
+        let (length, checksum) = self.boundaries.next_chunk(&self.buffer);
+        // The chunk gets an exactly sized copy, while the buffer keeps its capacity for refilling
+        let data = self.buffer[..length].to_vec();
+        self.buffer.drain(..length);
+
+        let chunk = Chunk {
+            offset: self.offset,
+            data,
+            checksum,
+        };
+        self.offset += length as u64;
+
+        Some(Ok(chunk))
+    }
+}
+
+/// The boundary search shared by both chunkers.
+#[derive(Clone, Debug)]
+struct Boundaries {
+    rolling: RollingCrc,
+    min: usize,
+    max: usize,
+    mask: u64,
+}
+
+impl Boundaries {
+    fn new(min: usize, avg: usize, max: usize) -> Self {
+        assert!(
+            min >= WINDOW,
+            "Minimum chunk size must be at least {WINDOW} bytes"
+        );
+        assert!(
+            min < avg && avg <= max,
+            "Chunk sizes must satisfy min < avg <= max"
+        );
+
+        // A boundary is expected every 2^bits bytes past the minimum
+        let bits = (avg - min).ilog2();
+
+        Self {
+            rolling: RollingCrc::new(CrcAlgorithm::Crc32Iscsi, WINDOW),
+            min,
+            max,
+            mask: (1 << bits) - 1,
+        }
+    }
+
+    /// Returns the length and CRC-32C of the chunk at the start of `data`, which holds all of the
+    /// remaining input, or at least a maximum-size chunk.
+    fn next_chunk(&mut self, data: &[u8]) -> (usize, u64) {
+        let limit = data.len().min(self.max);
+        let mut digest = Digest::new(CrcAlgorithm::Crc32Iscsi);
+
+        if limit <= self.min {
+            digest.update(&data[..limit]);
+
+            return (limit, digest.finalize());
+        }
+
+        digest.update(&data[..self.min]);
+        self.rolling.reset(&data[self.min - WINDOW..self.min]);
+
+        // The boundary test applies to the window ending at `end`, so a chunk ends at `end`
+        let mut start = self.min;
+        let mut end = self.min;
+        loop {
+            let block_end = (start + BLOCK).min(limit);
+
+            while self.rolling.checksum() & self.mask != 0 && end < block_end {
+                self.rolling.roll(data[end - WINDOW], data[end]);
+                end += 1;
+            }
+
+            digest.update(&data[start..end]);
+
+            if end == limit || self.rolling.checksum() & self.mask == 0 {
+                return (end, digest.finalize());
+            }
+            start = end;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum;
+    use rand::{rng, Rng};
+    use std::collections::HashSet;
+
+    /// Returns at most `limit` bytes per read, to split chunks across reads.
+    struct ShortReader<'a> {
+        data: &'a [u8],
+        limit: usize,
+    }
+
+    impl Read for ShortReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let length = buf.len().min(self.limit).min(self.data.len());
+            buf[..length].copy_from_slice(&self.data[..length]);
+            self.data = &self.data[length..];
+
+            Ok(length)
+        }
+    }
+
+    fn random_data(length: usize) -> Vec<u8> {
+        let mut data = vec![0u8; length];
+        rng().fill(&mut data[..]);
+
+        data
+    }
+
+    #[test]
+    fn test_chunks_cover_data() {
+        let data = random_data(1_000_000);
+        let (min, avg, max) = (1024, 4096, 16384);
+
+        let chunks: Vec<_> = Chunker::new(&data, min, avg, max).collect();
+        let last = chunks.len() - 1;
+
+        let mut offset = 0;
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.offset, offset as u64);
+            assert_eq!(chunk.data, &data[offset..offset + chunk.data.len()]);
+            assert_eq!(
+                chunk.checksum,
+                checksum(CrcAlgorithm::Crc32Iscsi, chunk.data)
+            );
+            assert!(chunk.data.len() <= max);
+            assert!(chunk.data.len() >= min || i == last);
+
+            offset += chunk.data.len();
+        }
+        assert_eq!(offset, data.len());
+
+        // around 1 MB / (1 KiB + 2 KiB) chunks, allowing for randomness
+        assert!((200..500).contains(&chunks.len()), "{}", chunks.len());
+    }
+
+    #[test]
+    fn test_boundaries_are_content_defined() {
+        let data = random_data(500_000);
+        let mut edited = data.clone();
+        edited.splice(1000..1000, random_data(100));
+
+        let checksums = |data: &[u8]| -> HashSet<u64> {
+            Chunker::new(data, 512, 2048, 8192)
+                .map(|chunk| chunk.checksum)
+                .collect()
+        };
+        let original = checksums(&data);
+        let shared = checksums(&edited).intersection(&original).count();
+
+        // only the chunks around the insertion change
+        assert!(
+            shared >= original.len() - 3,
+            "{shared} of {}",
+            original.len()
+        );
+    }
+
+    #[test]
+    fn test_small_and_uniform_data() {
+        assert_eq!(Chunker::new(&[], 64, 128, 256).count(), 0);
+
+        let chunks: Vec<_> = Chunker::new(b"123456789", 64, 128, 256).collect();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].checksum, 0xe3069283);
+
+        // every window is the same, so every chunk is the same size
+        let zeros = vec![0u8; 10_000];
+        let sizes: HashSet<usize> = Chunker::new(&zeros, 64, 128, 256)
+            .map(|chunk| chunk.data.len())
+            .take(39)
+            .collect();
+        assert_eq!(sizes.len(), 1);
+    }
+
+    #[test]
+    fn test_read_chunker_matches_chunker() {
+        let data = random_data(300_000);
+        let expected: Vec<_> = Chunker::new(&data, 256, 1024, 4096 * 3).collect();
+
+        for limit in [1000, 4096, 100_000] {
+            let reader = ShortReader { data: &data, limit };
+            let chunks: Vec<_> = ReadChunker::new(reader, 256, 1024, 4096 * 3)
+                .map(Result::unwrap)
+                .collect();
+
+            assert_eq!(chunks.len(), expected.len());
+            for (chunk, expected) in chunks.iter().zip(&expected) {
+                assert_eq!(chunk.offset, expected.offset);
+                assert_eq!(chunk.data, expected.data);
+                assert_eq!(chunk.checksum, expected.checksum);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_boundaries() {
+        // a fixed input, with boundaries found by checking the CRC-32C of every 64-byte window
+        // against the mask (511 for these sizes) outside this crate
+        let data: Vec<u8> = (0..20_000u32)
+            .map(|i| (i.wrapping_mul(2654435761) >> 13) as u8)
+            .collect();
+        let expected = [
+            1224, 1689, 2147, 2511, 2970, 4021, 4419, 4896, 6006, 6524, 7351, 8020, 9108, 9921,
+            10633, 12021, 14399, 15388, 16024, 16377, 17287, 17843, 18533, 19742, 20000,
+        ];
+
+        let ends: Vec<u64> = Chunker::new(&data, 256, 1024, 4096)
+            .map(|chunk| chunk.offset + chunk.data.len() as u64)
+            .collect();
+        assert_eq!(ends, expected);
+
+        let reader = ShortReader {
+            data: &data,
+            limit: 1000,
+        };
+        let chunks: Vec<_> = ReadChunker::new(reader, 256, 1024, 4096)
+            .map(Result::unwrap)
+            .collect();
+        let ends: Vec<u64> = chunks
+            .iter()
+            .map(|chunk| chunk.offset + chunk.data.len() as u64)
+            .collect();
+        assert_eq!(ends, expected);
+
+        // each chunk owns only its own bytes, rather than a maximum-size buffer
+        for chunk in &chunks {
+            assert_eq!(chunk.data.capacity(), chunk.data.len());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Minimum chunk size must be at least 64 bytes")]
+    fn test_min_smaller_than_window() {
+        Chunker::new(&[], 32, 128, 256);
+    }
+
+    #[test]
+    #[should_panic(expected = "Chunk sizes must satisfy min < avg <= max")]
+    fn test_invalid_sizes() {
+        Chunker::new(&[], 1024, 512, 4096);
+    }
+}
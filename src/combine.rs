@@ -50,139 +50,56 @@
      http://reveng.sourceforge.net/crc-catalogue/all.htm
 */
 
+use crate::arch::software;
+use crate::gf2::{self, register_form};
 use crate::CrcParams;
 
-/* Multiply the GF(2) vector vec by the GF(2) matrix mat, returning the
-resulting vector.  The vector is stored as bits in a crc_t.  The matrix is
-similarly stored with each column as a crc_t, where the number of columns is
-at least enough to cover the position of the most significant 1 bit in the
-vector (so a dimension parameter is not needed). */
-fn gf2_matrix_times(mat: &[u64; 64], mut vec: u64) -> u64 {
-    let mut sum = 0;
-    let mut idx = 0;
-    while vec > 0 {
-        if vec & 1 == 1 {
-            sum ^= mat[idx];
-        }
-        vec >>= 1;
-        idx += 1;
-    }
-
-    sum
-}
-
-/* Multiply the matrix mat by itself, returning the result in square.  WIDTH is
-the dimension of the matrices, i.e., the number of bits in each crc_t
-(rows), and the number of crc_t's (columns). */
-fn gf2_matrix_square(square: &mut [u64; 64], mat: &[u64; 64]) {
-    for n in 0..64 {
-        square[n] = gf2_matrix_times(mat, mat[n]);
-    }
-}
-
 /* Combine the CRCs of two successive sequences, where crc1 is the CRC of the
 first sequence of bytes, crc2 is the CRC of the immediately following
 sequence of bytes, and len2 is the length of the second sequence.  The CRC
 of the combined sequence is returned. */
-pub fn checksums(mut crc1: u64, crc2: u64, mut len2: u64, params: CrcParams) -> u64 {
-    let mut col: u64;
-    let mut even = [0u64; 64]; /* even-power-of-two zeros operator */
-    let mut odd = [0u64; 64]; /* odd-power-of-two zeros operator */
-
-    /* exclusive-or the result with len2 zeros applied to the CRC of an empty
-    sequence */
-    crc1 ^= params.init ^ params.xorout;
-
-    /* construct the operator for one zero bit and put in odd[] */
-    if params.refin && params.refout {
-        // use the reflected POLY
-        odd[0] = reflect_poly(params.poly, params.width as u32);
-        col = 1;
-        for n in 1..params.width {
-            odd[n as usize] = col;
-            col <<= 1;
-        }
-    } else if !params.refin && !params.refout {
-        col = 2;
-        for n in 0..params.width - 1 {
-            odd[n as usize] = col;
-            col <<= 1;
-        }
-        // Put poly at the last valid index (width-1)
-        odd[(params.width - 1) as usize] = params.poly;
-    } else {
-        panic!("Unsupported CRC configuration");
-    }
-
-    /* put operator for two zero bits in even */
-    gf2_matrix_square(&mut even, &odd);
+pub fn checksums(crc1: u64, crc2: u64, len2: u64, params: CrcParams) -> u64 {
+    Shift::new(len2, params).combine(crc1, crc2)
+}
 
-    /* put operator for four zero bits in odd */
-    gf2_matrix_square(&mut odd, &even);
+/// Combines the CRCs of two successive bit sequences, where `len2_bits` is the length of the
+/// second in bits, which needn't be a whole number of bytes.
+pub fn checksums_bits(crc1: u64, crc2: u64, len2_bits: u64, params: CrcParams) -> u64 {
+    // Shifting in zeros is linear, so shift crc1 by the whole bytes, then the remaining bits
+    let shifted = checksums(crc1, 0, len2_bits / 8, params);
 
-    /* apply len2 zeros to crc1 (first square will put the operator for one
-    zero byte, eight zero bits, in even) */
-    loop {
-        /* apply zeros operator for this bit of len2 */
-        gf2_matrix_square(&mut even, &odd);
-        if len2 & 1 == 1 {
-            crc1 = gf2_matrix_times(&even, crc1);
-        }
-        len2 >>= 1;
+    software::update_bits(shifted, 0, (len2_bits % 8) as u8, params) ^ crc2
+}
 
-        /* if no more bits set, then done */
-        if len2 == 0 {
-            break;
-        }
+/// The operator which shifts a CRC over a fixed number of zero bytes, for combining many CRCs whose
+/// second sequences all have the same length without rebuilding the operator each time.
+pub struct Shift {
+    factor: u64,
+    params: CrcParams,
+}
 
-        /* another iteration of the loop with odd and even swapped */
-        gf2_matrix_square(&mut odd, &even);
-        if len2 & 1 == 1 {
-            crc1 = gf2_matrix_times(&odd, crc1);
-        }
-        len2 >>= 1;
+impl Shift {
+    /// Builds the operator for sequences of `len` bytes, which is `x^(8 * len) mod P(x)`, since
+    /// shifting a CRC over zero bits multiplies its (forward) register by `x` for each bit.
+    pub fn new(len: u64, params: CrcParams) -> Self {
+        let zero_byte = gf2::x_pow_mod(8, params.poly, params.width);
 
-        /* if no more bits set, then done */
-        if len2 == 0 {
-            break;
+        Self {
+            factor: gf2::pow_mod(zero_byte, len, params.poly, params.width),
+            params,
         }
     }
 
-    /* return combined crc */
-    crc1 ^= crc2;
-
-    crc1
-}
-
-fn reflect_poly(poly: u64, width: u32) -> u64 {
-    assert!(width <= 64, "Width must be <= 64 bits");
+    /// Combines the CRCs of two successive sequences, where the second is the length the operator
+    /// was built for.
+    pub fn combine(&self, crc1: u64, crc2: u64) -> u64 {
+        let params = self.params;
 
-    // First reverse all bits
-    let reversed = bit_reverse(poly);
+        /* exclusive-or crc1 with len2 zeros applied to the CRC of an empty
+        sequence */
+        let register = register_form(crc1 ^ params.init ^ params.xorout, params);
+        let shifted = gf2::mul_mod(register, self.factor, params.poly, params.width);
 
-    // Shift right to get the significant bits in the correct position
-    // For a 32-bit poly, we need to shift right by (64 - 32) = 32 bits
-    let shifted = reversed >> (64 - width);
-
-    // Create mask for the target width
-    let mask = if width == 64 {
-        u64::MAX
-    } else {
-        (1u64 << width) - 1
-    };
-
-    // Apply mask to ensure we only keep the bits we want
-    shifted & mask
-}
-
-fn bit_reverse(mut forward: u64) -> u64 {
-    let mut reversed = 0;
-
-    for _ in 0..64 {
-        reversed <<= 1;
-        reversed |= forward & 1;
-        forward >>= 1;
+        register_form(shifted, params) ^ crc2
     }
-
-    reversed
 }
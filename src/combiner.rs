@@ -0,0 +1,453 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides a combiner for the CRCs of byte ranges computed out of order.
+//!
+//! Each range is merged with the runs immediately before and after it as soon as it's added, using
+//! the same `x^n mod P(x)` shifts as `checksum_combine()`, so the combiner only ever holds one CRC
+//! per contiguous run, and the whole-object CRC is ready as soon as the last gap is filled.
+
+use crate::{combine, get_calculator_params, CombineError, CrcAlgorithm, CrcCombiner, CrcParams};
+use core::fmt::{Display, Formatter};
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+/// Identifies a serialized `CrcCombiner`, followed by the format version.
+const SNAPSHOT_MAGIC: &[u8] = b"CRCC";
+const SNAPSHOT_VERSION: u8 = 1;
+
+/// The size of the snapshot header, up to and including the number of runs.
+const SNAPSHOT_HEADER_SIZE: usize = 4 + 1 + 1 + 1 + 8 * 5;
+
+impl CrcCombiner {
+    /// Creates a new `CrcCombiner` for an object of `len` bytes, using the specified CRC
+    /// algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum, CrcCombiner, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let data = b"123456789";
+    /// let mut combiner = CrcCombiner::new(Crc32IsoHdlc, 9);
+    ///
+    /// // ranges can arrive in any order
+    /// combiner.add(6, 3, checksum(Crc32IsoHdlc, &data[6..])).unwrap();
+    /// combiner.add(0, 2, checksum(Crc32IsoHdlc, &data[..2])).unwrap();
+    /// assert!(!combiner.is_complete());
+    ///
+    /// combiner.add(2, 4, checksum(Crc32IsoHdlc, &data[2..6])).unwrap();
+    /// assert_eq!(combiner.checksum(), Ok(0xcbf43926));
+    /// ```
+    pub fn new(algorithm: CrcAlgorithm, len: u64) -> Self {
+        let (_, params) = get_calculator_params(algorithm);
+
+        Self::new_with_params(params, len)
+    }
+
+    /// Creates a new `CrcCombiner` for an object of `len` bytes, using custom CRC parameters.
+    pub fn new_with_params(params: CrcParams, len: u64) -> Self {
+        Self {
+            params,
+            len,
+            runs: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the length of the whole object.
+    pub fn total_len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns the number of bytes whose CRCs have been added.
+    pub fn received(&self) -> u64 {
+        self.runs.values().map(|&(len, _)| len).sum()
+    }
+
+    /// Returns true once the ranges added cover the whole object.
+    pub fn is_complete(&self) -> bool {
+        self.received() == self.len
+    }
+
+    /// Adds the CRC of the `len` bytes at `offset`, merging it with any adjacent ranges already
+    /// added.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CombineError::Overlap` if the range overlaps bytes already added, and
+    /// `CombineError::OutOfBounds` if it extends past the end of the object, leaving the combiner
+    /// unchanged.
+    pub fn add(&mut self, offset: u64, len: u64, crc: u64) -> Result<(), CombineError> {
+        let end = offset
+            .checked_add(len)
+            .filter(|&end| end <= self.len)
+            .ok_or(CombineError::OutOfBounds)?;
+
+        if len == 0 {
+            return Ok(());
+        }
+
+        let before = self.runs.range(..=offset).next_back();
+        let after = self.runs.range(offset + 1..).next();
+
+        for (&run_offset, &(run_len, _)) in before.into_iter().chain(after) {
+            if run_offset < end && offset < run_offset + run_len {
+                return Err(CombineError::Overlap {
+                    offset: run_offset,
+                    len: run_len,
+                });
+            }
+        }
+
+        let (mut offset, mut len, mut crc) = (offset, len, crc);
+
+        if let Some((&run_offset, &(run_len, run_crc))) = before {
+            if run_offset + run_len == offset {
+                self.runs.remove(&run_offset);
+                crc = combine::checksums(run_crc, crc, len, self.params);
+                offset = run_offset;
+                len += run_len;
+            }
+        }
+
+        if let Some((run_len, run_crc)) = self.runs.remove(&end) {
+            crc = combine::checksums(crc, run_crc, run_len, self.params);
+            len += run_len;
+        }
+
+        self.runs.insert(offset, (len, crc));
+
+        Ok(())
+    }
+
+    /// Returns the offset and length of each range not yet added, in order.
+    pub fn gaps(&self) -> impl Iterator<Item = (u64, u64)> + '_ {
+        self.runs
+            .iter()
+            .map(|(&offset, &(len, _))| (offset, offset + len))
+            .chain(core::iter::once((self.len, self.len)))
+            .scan(0, |next, (start, end)| {
+                let gap = (*next < start).then(|| (*next, start - *next));
+                *next = end;
+
+                Some(gap)
+            })
+            .flatten()
+    }
+
+    /// Returns the CRC of the whole object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CombineError::Gap` with the first missing range if the object isn't complete.
+    pub fn checksum(&self) -> Result<u64, CombineError> {
+        if let Some((offset, len)) = self.gaps().next() {
+            return Err(CombineError::Gap { offset, len });
+        }
+
+        Ok(match self.runs.get(&0) {
+            Some(&(_, crc)) => crc,
+            None => crate::checksum_with_params(self.params, &[]),
+        })
+    }
+
+    /// Serializes the combiner's state, so an interrupted download can be resumed with
+    /// `restore()`.
+    ///
+    /// The snapshot records the CRC parameters it was taken with, and can only be restored with
+    /// the same ones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum, CrcCombiner, CrcAlgorithm::Crc64Nvme};
+    ///
+    /// let data = b"123456789";
+    /// let mut combiner = CrcCombiner::new(Crc64Nvme, 9);
+    /// combiner.add(0, 4, checksum(Crc64Nvme, &data[..4])).unwrap();
+    ///
+    /// let snapshot = combiner.snapshot();
+    ///
+    /// let mut resumed = CrcCombiner::restore(Crc64Nvme, &snapshot).unwrap();
+    /// assert_eq!(resumed.gaps().collect::<Vec<_>>(), [(4, 5)]);
+    ///
+    /// resumed.add(4, 5, checksum(Crc64Nvme, &data[4..])).unwrap();
+    /// assert_eq!(resumed.checksum(), Ok(0xae8b14860a799888));
+    /// ```
+    pub fn snapshot(&self) -> Vec<u8> {
+        let mut snapshot = Vec::with_capacity(SNAPSHOT_HEADER_SIZE + self.runs.len() * 24);
+
+        snapshot.extend_from_slice(SNAPSHOT_MAGIC);
+        snapshot.push(SNAPSHOT_VERSION);
+        snapshot.push(self.params.width);
+        snapshot.push(self.params.refin as u8);
+
+        for value in [
+            self.params.poly,
+            self.params.init,
+            self.params.xorout,
+            self.len,
+            self.runs.len() as u64,
+        ] {
+            snapshot.extend_from_slice(&value.to_le_bytes());
+        }
+
+        for (&offset, &(len, crc)) in &self.runs {
+            for value in [offset, len, crc] {
+                snapshot.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        snapshot
+    }
+
+    /// Restores a combiner from a `snapshot()`, using the specified CRC algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CombineError::InvalidSnapshot` if the snapshot is malformed, or was taken with a
+    /// different CRC algorithm.
+    pub fn restore(algorithm: CrcAlgorithm, snapshot: &[u8]) -> Result<Self, CombineError> {
+        let (_, params) = get_calculator_params(algorithm);
+
+        Self::restore_with_params(params, snapshot)
+    }
+
+    /// Restores a combiner from a `snapshot()`, using custom CRC parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CombineError::InvalidSnapshot` if the snapshot is malformed, or was taken with
+    /// different CRC parameters.
+    pub fn restore_with_params(params: CrcParams, snapshot: &[u8]) -> Result<Self, CombineError> {
+        let (header, runs) = snapshot
+            .split_at_checked(SNAPSHOT_HEADER_SIZE)
+            .ok_or(CombineError::InvalidSnapshot)?;
+
+        let value =
+            |offset: usize| u64::from_le_bytes(header[offset..offset + 8].try_into().unwrap());
+        let [poly, init, xorout, len, count] = [7, 15, 23, 31, 39].map(value);
+
+        if &header[..4] != SNAPSHOT_MAGIC
+            || header[4] != SNAPSHOT_VERSION
+            || header[5] != params.width
+            || header[6] != params.refin as u8
+            || (poly, init, xorout) != (params.poly, params.init, params.xorout)
+            || count.checked_mul(24) != Some(runs.len() as u64)
+        {
+            return Err(CombineError::InvalidSnapshot);
+        }
+
+        let mut combiner = Self::new_with_params(params, len);
+
+        for run in runs.chunks_exact(24) {
+            let [offset, len, crc] =
+                [0, 8, 16].map(|i| u64::from_le_bytes(run[i..i + 8].try_into().unwrap()));
+
+            combiner
+                .add(offset, len, crc)
+                .map_err(|_| CombineError::InvalidSnapshot)?;
+        }
+
+        Ok(combiner)
+    }
+}
+
+impl Display for CombineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CombineError::Overlap { offset, len } => write!(
+                f,
+                "Range overlaps the {len} bytes already added at offset {offset}"
+            ),
+            CombineError::OutOfBounds => write!(f, "Range extends past the end of the object"),
+            CombineError::Gap { offset, len } => {
+                write!(f, "Missing {len} bytes at offset {offset}")
+            }
+            CombineError::InvalidSnapshot => write!(f, "Invalid snapshot"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CombineError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use crate::{checksum, checksum_with_params};
+    use rand::seq::SliceRandom;
+    use rand::{rng, Rng};
+
+    /// Splits `len` bytes into random ranges, in random order.
+    fn random_ranges(len: usize) -> Vec<(usize, usize)> {
+        let mut ranges = Vec::new();
+        let mut offset = 0;
+
+        while offset < len {
+            let range_len = rng().random_range(1..=(len - offset).min(300));
+            ranges.push((offset, range_len));
+            offset += range_len;
+        }
+
+        ranges.shuffle(&mut rng());
+        ranges
+    }
+
+    #[test]
+    fn test_out_of_order_all_configs() {
+        let mut data = vec![0u8; 2000];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+            let mut combiner = CrcCombiner::new_with_params(params, data.len() as u64);
+
+            for (offset, len) in random_ranges(data.len()) {
+                assert!(combiner.checksum().is_err());
+
+                let crc = checksum_with_params(params, &data[offset..offset + len]);
+                combiner.add(offset as u64, len as u64, crc).unwrap();
+            }
+
+            assert!(combiner.is_complete());
+            assert_eq!(
+                combiner.checksum(),
+                Ok(checksum_with_params(params, &data)),
+                "{}",
+                config.get_name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let data = b"0123456789abcdef";
+        let crc = |offset: usize, len: usize| {
+            checksum(CrcAlgorithm::Crc32Iscsi, &data[offset..offset + len])
+        };
+
+        let mut combiner = CrcCombiner::new(CrcAlgorithm::Crc32Iscsi, 16);
+        combiner.add(4, 4, crc(4, 4)).unwrap();
+        combiner.add(8, 2, crc(8, 2)).unwrap();
+
+        assert_eq!(
+            combiner.add(2, 3, crc(2, 3)),
+            Err(CombineError::Overlap { offset: 4, len: 6 })
+        );
+        assert_eq!(
+            combiner.add(9, 1, crc(9, 1)),
+            Err(CombineError::Overlap { offset: 4, len: 6 })
+        );
+        assert_eq!(
+            combiner.add(4, 6, crc(4, 6)),
+            Err(CombineError::Overlap { offset: 4, len: 6 })
+        );
+        assert_eq!(combiner.add(14, 3, 0), Err(CombineError::OutOfBounds));
+        assert_eq!(combiner.add(u64::MAX, 2, 0), Err(CombineError::OutOfBounds));
+
+        // empty ranges are accepted anywhere within the object
+        assert_eq!(combiner.add(5, 0, 0), Ok(()));
+
+        assert_eq!(combiner.received(), 6);
+        assert_eq!(combiner.gaps().collect::<Vec<_>>(), [(0, 4), (10, 6)]);
+        assert_eq!(
+            combiner.checksum(),
+            Err(CombineError::Gap { offset: 0, len: 4 })
+        );
+
+        combiner.add(0, 4, crc(0, 4)).unwrap();
+        combiner.add(10, 6, crc(10, 6)).unwrap();
+
+        assert_eq!(combiner.gaps().count(), 0);
+        assert_eq!(
+            combiner.checksum(),
+            Ok(checksum(CrcAlgorithm::Crc32Iscsi, data))
+        );
+    }
+
+    #[test]
+    fn test_empty_object() {
+        let combiner = CrcCombiner::new(CrcAlgorithm::Crc64Nvme, 0);
+
+        assert!(combiner.is_complete());
+        assert_eq!(
+            combiner.checksum(),
+            Ok(checksum(CrcAlgorithm::Crc64Nvme, b""))
+        );
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut data = vec![0u8; 1000];
+        rng().fill(&mut data[..]);
+
+        let ranges = random_ranges(data.len());
+        let (first, second) = ranges.split_at(ranges.len() / 2);
+        let crc = |&(offset, len): &(usize, usize)| {
+            checksum(CrcAlgorithm::Crc32IsoHdlc, &data[offset..offset + len])
+        };
+
+        let mut combiner = CrcCombiner::new(CrcAlgorithm::Crc32IsoHdlc, data.len() as u64);
+        for range in first {
+            combiner
+                .add(range.0 as u64, range.1 as u64, crc(range))
+                .unwrap();
+        }
+
+        let snapshot = combiner.snapshot();
+        let mut resumed = CrcCombiner::restore(CrcAlgorithm::Crc32IsoHdlc, &snapshot).unwrap();
+
+        assert_eq!(resumed.total_len(), 1000);
+        assert_eq!(resumed.received(), combiner.received());
+        assert!(resumed.gaps().eq(combiner.gaps()));
+
+        for range in second {
+            resumed
+                .add(range.0 as u64, range.1 as u64, crc(range))
+                .unwrap();
+        }
+
+        assert_eq!(
+            resumed.checksum(),
+            Ok(checksum(CrcAlgorithm::Crc32IsoHdlc, &data))
+        );
+    }
+
+    #[test]
+    fn test_invalid_snapshots() {
+        let mut combiner = CrcCombiner::new(CrcAlgorithm::Crc32IsoHdlc, 100);
+        combiner.add(10, 10, 0x1234).unwrap();
+        let snapshot = combiner.snapshot();
+
+        assert!(CrcCombiner::restore(CrcAlgorithm::Crc32IsoHdlc, &snapshot).is_ok());
+
+        for invalid in [
+            &snapshot[..snapshot.len() - 1],
+            &snapshot[..SNAPSHOT_HEADER_SIZE - 1],
+            &[],
+        ] {
+            assert_eq!(
+                CrcCombiner::restore(CrcAlgorithm::Crc32IsoHdlc, invalid).unwrap_err(),
+                CombineError::InvalidSnapshot
+            );
+        }
+
+        // a different algorithm
+        assert_eq!(
+            CrcCombiner::restore(CrcAlgorithm::Crc32Iscsi, &snapshot).unwrap_err(),
+            CombineError::InvalidSnapshot
+        );
+
+        // a run past the end of the object
+        let mut invalid = snapshot.clone();
+        invalid[SNAPSHOT_HEADER_SIZE] = 95;
+        assert_eq!(
+            CrcCombiner::restore(CrcAlgorithm::Crc32IsoHdlc, &invalid).unwrap_err(),
+            CombineError::InvalidSnapshot
+        );
+    }
+}
@@ -0,0 +1,586 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Interoperability with the [`crc`](https://crates.io/crates/crc) crate.
+//!
+//! Provides conversions between [`crc::Algorithm`](Algorithm) and [`CrcParams`], and a [`Crc`]
+//! type which mirrors `crc::Crc<W>`, so migrating a call site is a one-line import change:
+//!
+//! ```rust
+//! // use crc::{Crc, CRC_32_ISCSI};
+//! use crc_fast::compat::crc::{Crc, CRC_32_ISCSI};
+//!
+//! const CASTAGNOLI: Crc<u32> = Crc::<u32>::new(&CRC_32_ISCSI);
+//!
+//! assert_eq!(CASTAGNOLI.checksum(b"123456789"), 0xe3069283);
+//!
+//! let mut digest = CASTAGNOLI.digest();
+//! digest.update(b"1234");
+//! digest.update(b"56789");
+//! assert_eq!(digest.finalize(), 0xe3069283);
+//! ```
+//!
+//! The `crc` crate's catalogue of algorithm constants is re-exported from this module. Only
+//! 32-bit and 64-bit algorithms whose input and output reflection match are supported, and
+//! [`Crc::checksum`] and [`Crc::digest`] panic otherwise; use the [`TryFrom`] conversions to check
+//! an algorithm first.
+//!
+//! Algorithms matching one of the predefined [`CrcAlgorithm`]s use its precomputed keys (and
+//! fusion kernels, where available), while others have their keys generated through the cache.
+//!
+//! Requires the `crc` feature.
+
+use crate::enums::CATALOGUE;
+use crate::{checksum_with_params, CrcParams};
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+
+pub use ::crc::*;
+
+/// Error returned when a CRC algorithm can't be converted between [`Algorithm`] and
+/// [`CrcParams`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The width isn't supported by the target type.
+    UnsupportedWidth(u8),
+    /// The input and output reflection differ, which `CrcParams` doesn't support.
+    MismatchedReflection,
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConversionError::UnsupportedWidth(width) => {
+                write!(f, "Unsupported CRC width: {width}")
+            }
+            ConversionError::MismatchedReflection => {
+                write!(f, "Input and output reflection must match")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+impl TryFrom<&Algorithm<u32>> for CrcParams {
+    type Error = ConversionError;
+
+    fn try_from(algorithm: &Algorithm<u32>) -> Result<Self, Self::Error> {
+        to_params(
+            algorithm.width,
+            algorithm.poly as u64,
+            algorithm.init as u64,
+            algorithm.refin,
+            algorithm.refout,
+            algorithm.xorout as u64,
+            algorithm.check as u64,
+        )
+    }
+}
+
+impl TryFrom<&Algorithm<u64>> for CrcParams {
+    type Error = ConversionError;
+
+    fn try_from(algorithm: &Algorithm<u64>) -> Result<Self, Self::Error> {
+        to_params(
+            algorithm.width,
+            algorithm.poly,
+            algorithm.init,
+            algorithm.refin,
+            algorithm.refout,
+            algorithm.xorout,
+            algorithm.check,
+        )
+    }
+}
+
+impl TryFrom<CrcParams> for Algorithm<u32> {
+    type Error = ConversionError;
+
+    fn try_from(params: CrcParams) -> Result<Self, Self::Error> {
+        if params.width != 32 {
+            return Err(ConversionError::UnsupportedWidth(params.width));
+        }
+
+        let algorithm = Algorithm::<u64>::from(params);
+
+        Ok(Algorithm {
+            width: algorithm.width,
+            poly: algorithm.poly as u32,
+            init: algorithm.init as u32,
+            refin: algorithm.refin,
+            refout: algorithm.refout,
+            xorout: algorithm.xorout as u32,
+            check: algorithm.check as u32,
+            residue: algorithm.residue as u32,
+        })
+    }
+}
+
+impl From<CrcParams> for Algorithm<u64> {
+    fn from(params: CrcParams) -> Self {
+        Algorithm {
+            width: params.width,
+            poly: params.poly,
+            init: register_to_init(params.init, params.width, params.refin),
+            refin: params.refin,
+            refout: params.refout,
+            xorout: params.xorout,
+            check: params.check,
+            residue: residue(params),
+        }
+    }
+}
+
+/// A CRC calculator for a [`crc::Algorithm`](Algorithm), mirroring `crc::Crc<W>`.
+///
+/// Unlike `crc::Crc`, there's no table to build, so `table()` isn't available. Algorithms
+/// matching a predefined [`CrcAlgorithm`] are resolved to its parameters by [`Crc::new`], at
+/// compile time for a `const`, while others are converted (with their keys cached) on each use.
+#[derive(Clone)]
+pub struct Crc<W: Width> {
+    pub algorithm: &'static Algorithm<W>,
+    params: Option<CrcParams>,
+}
+
+/// An incremental CRC calculation, mirroring `crc::Digest<'a, W>`.
+#[derive(Clone)]
+pub struct Digest<'a, W: Width> {
+    digest: crate::Digest,
+    _crc: PhantomData<&'a Crc<W>>,
+}
+
+impl Crc<u32> {
+    /// Creates a calculator for the algorithm.
+    pub const fn new(algorithm: &'static Algorithm<u32>) -> Self {
+        let params = predefined_params(
+            algorithm.width,
+            algorithm.poly as u64,
+            algorithm.init as u64,
+            algorithm.refin,
+            algorithm.refout,
+            algorithm.xorout as u64,
+        );
+
+        Self { algorithm, params }
+    }
+
+    /// Calculates the CRC of `bytes`.
+    ///
+    /// # Panics
+    /// Panics if the algorithm isn't supported.
+    pub fn checksum(&self, bytes: &[u8]) -> u32 {
+        checksum_with_params(self.params(), bytes) as u32
+    }
+
+    /// Creates a digest for calculating the CRC incrementally.
+    ///
+    /// # Panics
+    /// Panics if the algorithm isn't supported.
+    pub fn digest(&self) -> Digest<'_, u32> {
+        Digest::new(self.params())
+    }
+
+    /// Creates a digest starting from `initial` instead of the algorithm's `init`.
+    ///
+    /// # Panics
+    /// Panics if the algorithm isn't supported.
+    pub fn digest_with_initial(&self, initial: u32) -> Digest<'_, u32> {
+        Digest::new(self.params_with_initial(initial as u64))
+    }
+
+    fn params(&self) -> CrcParams {
+        match self.params {
+            Some(params) => params,
+            None => CrcParams::try_from(self.algorithm).expect("Unsupported CRC algorithm"),
+        }
+    }
+
+    fn params_with_initial(&self, initial: u64) -> CrcParams {
+        let mut params = self.params();
+        params.init = register_to_init(initial, params.width, params.refin);
+
+        params
+    }
+}
+
+impl Crc<u64> {
+    /// Creates a calculator for the algorithm.
+    pub const fn new(algorithm: &'static Algorithm<u64>) -> Self {
+        let params = predefined_params(
+            algorithm.width,
+            algorithm.poly,
+            algorithm.init,
+            algorithm.refin,
+            algorithm.refout,
+            algorithm.xorout,
+        );
+
+        Self { algorithm, params }
+    }
+
+    /// Calculates the CRC of `bytes`.
+    ///
+    /// # Panics
+    /// Panics if the algorithm isn't supported.
+    pub fn checksum(&self, bytes: &[u8]) -> u64 {
+        checksum_with_params(self.params(), bytes)
+    }
+
+    /// Creates a digest for calculating the CRC incrementally.
+    ///
+    /// # Panics
+    /// Panics if the algorithm isn't supported.
+    pub fn digest(&self) -> Digest<'_, u64> {
+        Digest::new(self.params())
+    }
+
+    /// Creates a digest starting from `initial` instead of the algorithm's `init`.
+    ///
+    /// # Panics
+    /// Panics if the algorithm isn't supported.
+    pub fn digest_with_initial(&self, initial: u64) -> Digest<'_, u64> {
+        Digest::new(self.params_with_initial(initial))
+    }
+
+    fn params(&self) -> CrcParams {
+        match self.params {
+            Some(params) => params,
+            None => CrcParams::try_from(self.algorithm).expect("Unsupported CRC algorithm"),
+        }
+    }
+
+    fn params_with_initial(&self, initial: u64) -> CrcParams {
+        let mut params = self.params();
+        params.init = register_to_init(initial, params.width, params.refin);
+
+        params
+    }
+}
+
+impl<W: Width> Digest<'_, W> {
+    fn new(params: CrcParams) -> Self {
+        Self {
+            digest: crate::Digest::new_with_params(params),
+            _crc: PhantomData,
+        }
+    }
+
+    /// Updates the CRC with `bytes`.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.digest.update(bytes);
+    }
+}
+
+impl Digest<'_, u32> {
+    /// Returns the CRC of all the bytes.
+    pub fn finalize(self) -> u32 {
+        self.digest.finalize() as u32
+    }
+}
+
+impl Digest<'_, u64> {
+    /// Returns the CRC of all the bytes.
+    pub fn finalize(self) -> u64 {
+        self.digest.finalize()
+    }
+}
+
+fn to_params(
+    width: u8,
+    poly: u64,
+    init: u64,
+    refin: bool,
+    refout: bool,
+    xorout: u64,
+    check: u64,
+) -> Result<CrcParams, ConversionError> {
+    if width != 32 && width != 64 {
+        return Err(ConversionError::UnsupportedWidth(width));
+    }
+
+    if refin != refout {
+        return Err(ConversionError::MismatchedReflection);
+    }
+
+    if let Some(params) = predefined_params(width, poly, init, refin, refout, xorout) {
+        return Ok(params);
+    }
+
+    // The catalogue gives init unreflected, while CrcParams holds the register's initial state
+    let init = register_to_init(init, width, refin);
+
+    let name = match width {
+        32 => "CRC-32/CUSTOM",
+        _ => "CRC-64/CUSTOM",
+    };
+
+    Ok(CrcParams::new(
+        name, width, poly, init, refin, xorout, check,
+    ))
+}
+
+/// Finds the predefined algorithm matching the catalogue parameters, to use its precomputed keys
+/// and kernels.
+///
+/// This is a `const fn` so [`Crc::new`] can resolve its parameters at compile time.
+const fn predefined_params(
+    width: u8,
+    poly: u64,
+    init: u64,
+    refin: bool,
+    refout: bool,
+    xorout: u64,
+) -> Option<CrcParams> {
+    if (width != 32 && width != 64) || refin != refout {
+        return None;
+    }
+
+    // The catalogue gives init unreflected, while CrcParams holds the register's initial state
+    let init = register_to_init(init, width, refin);
+
+    let mut i = 0;
+    while i < CATALOGUE.len() {
+        let params = crate::get_params(CATALOGUE[i]);
+
+        if params.width == width
+            && params.poly == poly
+            && params.init == init
+            && params.refin == refin
+            && params.xorout == xorout
+        {
+            return Some(params);
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+/// Converts between the catalogue's `init` and the register's initial state, which are
+/// reflections of each other for reflected CRCs.
+const fn register_to_init(value: u64, width: u8, reflected: bool) -> u64 {
+    if reflected {
+        // gf2::reflect() isn't a const fn
+        value.reverse_bits() >> (64 - width as u32)
+    } else {
+        value
+    }
+}
+
+/// The catalogue's residue: the register after processing an error-free codeword, before the
+/// final XOR.
+fn residue(params: CrcParams) -> u64 {
+    let crc = checksum_with_params(params, &[]);
+    let bytes = params.width as usize / 8;
+
+    // The CRC is appended in the order it's shifted out: little-endian when reflected
+    let mut codeword = [0u8; 8];
+    if params.refout {
+        codeword[..bytes].copy_from_slice(&crc.to_le_bytes()[..bytes]);
+    } else {
+        codeword[..bytes].copy_from_slice(&crc.to_be_bytes()[8 - bytes..]);
+    }
+
+    checksum_with_params(params, &codeword[..bytes]) ^ params.xorout
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use crate::test::enums::AnyCrcTestConfig;
+    use crate::CrcAlgorithm;
+    use rand::{rng, Rng};
+
+    const CRC_32_ASYMMETRIC: Algorithm<u32> = Algorithm {
+        width: 32,
+        poly: 0x04c11db7,
+        init: 0x12345678,
+        refin: true,
+        refout: true,
+        xorout: 0xffffffff,
+        check: 0xf8b7431,
+        residue: 0xdebb20e3,
+    };
+
+    const CRC_64_ASYMMETRIC: Algorithm<u64> = Algorithm {
+        width: 64,
+        poly: 0x42f0e1eba9ea3693,
+        init: 0x123456789,
+        refin: true,
+        refout: true,
+        xorout: 0,
+        check: 0x57036646bb4bfc0f,
+        residue: 0,
+    };
+
+    fn assert_same<W: Width + Copy + core::fmt::Debug + PartialEq>(
+        a: &Algorithm<W>,
+        b: &Algorithm<W>,
+    ) {
+        assert_eq!(
+            (a.width, a.poly, a.init, a.refin, a.refout),
+            (b.width, b.poly, b.init, b.refin, b.refout)
+        );
+        assert_eq!(
+            (a.xorout, a.check, a.residue),
+            (b.xorout, b.check, b.residue)
+        );
+    }
+
+    #[test]
+    fn test_facade_matches_crc() {
+        let mut rng = rng();
+        let mut data = vec![0u8; 4096];
+        rng.fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+
+            match config {
+                AnyCrcTestConfig::CRC32(config) => {
+                    let reference = &config.reference_impl;
+                    let facade = Crc::<u32>::new(reference.algorithm);
+
+                    assert_eq!(facade.checksum(&data), reference.checksum(&data));
+
+                    let mut digest = facade.digest();
+                    digest.update(&data[..1000]);
+                    digest.update(&data[1000..]);
+                    assert_eq!(digest.finalize(), reference.checksum(&data));
+
+                    let mut digest = facade.digest_with_initial(0x12345678);
+                    let mut expected = reference.digest_with_initial(0x12345678);
+                    digest.update(&data);
+                    expected.update(&data);
+                    assert_eq!(digest.finalize(), expected.finalize(), "{}", params.name);
+                }
+                AnyCrcTestConfig::CRC64(config) => {
+                    let reference = &config.reference_impl;
+                    let facade = Crc::<u64>::new(reference.algorithm);
+
+                    assert_eq!(facade.checksum(&data), reference.checksum(&data));
+
+                    let mut digest = facade.digest();
+                    digest.update(&data[..1000]);
+                    digest.update(&data[1000..]);
+                    assert_eq!(digest.finalize(), reference.checksum(&data));
+
+                    let mut digest = facade.digest_with_initial(0x123456789abcdef);
+                    let mut expected = reference.digest_with_initial(0x123456789abcdef);
+                    digest.update(&data);
+                    expected.update(&data);
+                    assert_eq!(digest.finalize(), expected.finalize(), "{}", params.name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_conversions_round_trip() {
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+
+            // predefined algorithms convert to their own parameters
+            let algorithm = Algorithm::<u64>::from(*params);
+            let converted = CrcParams::try_from(&algorithm).unwrap();
+            assert_eq!(converted.algorithm, params.algorithm, "{}", params.name);
+            assert_eq!(converted.name, params.name);
+
+            match config {
+                AnyCrcTestConfig::CRC32(config) => {
+                    let expected = config.reference_impl.algorithm;
+
+                    assert_same(&Algorithm::<u32>::try_from(*params).unwrap(), expected);
+                    assert_eq!(
+                        CrcParams::try_from(expected).unwrap().algorithm,
+                        params.algorithm
+                    );
+                }
+                AnyCrcTestConfig::CRC64(config) => {
+                    assert_same(&algorithm, config.reference_impl.algorithm);
+                    assert_eq!(
+                        Algorithm::<u32>::try_from(*params).err(),
+                        Some(ConversionError::UnsupportedWidth(64))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_algorithms() {
+        const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_ASYMMETRIC);
+        const CRC64: Crc<u64> = Crc::<u64>::new(&CRC_64_ASYMMETRIC);
+
+        assert_eq!(CRC32.checksum(b"123456789"), CRC_32_ASYMMETRIC.check);
+        assert_eq!(CRC64.checksum(b"123456789"), CRC_64_ASYMMETRIC.check);
+
+        let params = CrcParams::try_from(&CRC_32_ASYMMETRIC).unwrap();
+        assert_eq!(params.algorithm, CrcAlgorithm::Crc32Custom);
+        assert_same(
+            &Algorithm::<u32>::try_from(params).unwrap(),
+            &CRC_32_ASYMMETRIC,
+        );
+
+        let params = CrcParams::try_from(&CRC_64_ASYMMETRIC).unwrap();
+        assert_eq!(params.algorithm, CrcAlgorithm::Crc64Custom);
+        assert_same(&Algorithm::<u64>::from(params), &CRC_64_ASYMMETRIC);
+
+        // 32-bit algorithms can also be described with 64-bit values
+        let params =
+            Algorithm::<u64>::from(crate::get_calculator_params(CrcAlgorithm::Crc32Iscsi).1);
+        assert_eq!(
+            CrcParams::try_from(&params).unwrap().algorithm,
+            CrcAlgorithm::Crc32Iscsi
+        );
+    }
+
+    #[test]
+    fn test_predefined_params_resolved_in_new() {
+        const CASTAGNOLI: Crc<u32> = Crc::<u32>::new(&CRC_32_ISCSI);
+        const XZ: Crc<u64> = Crc::<u64>::new(&CRC_64_XZ);
+        const CUSTOM: Crc<u32> = Crc::<u32>::new(&CRC_32_ASYMMETRIC);
+
+        assert_eq!(
+            CASTAGNOLI.params.map(|params| params.algorithm),
+            Some(CrcAlgorithm::Crc32Iscsi)
+        );
+        assert_eq!(
+            XZ.params.map(|params| params.algorithm),
+            Some(CrcAlgorithm::Crc64Xz)
+        );
+        assert!(CUSTOM.params.is_none());
+    }
+
+    #[test]
+    fn test_unsupported_algorithms() {
+        let crc24 = Algorithm::<u32> {
+            width: 24,
+            ..CRC_32_ISCSI
+        };
+        assert_eq!(
+            CrcParams::try_from(&crc24).unwrap_err(),
+            ConversionError::UnsupportedWidth(24)
+        );
+
+        let mismatched = Algorithm::<u64> {
+            refout: false,
+            ..CRC_64_XZ
+        };
+        assert_eq!(
+            CrcParams::try_from(&mismatched).unwrap_err(),
+            ConversionError::MismatchedReflection
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported CRC algorithm")]
+    fn test_unsupported_checksum() {
+        const CRC_24: Crc<u32> = Crc::<u32>::new(&CRC_24_OPENPGP);
+
+        CRC_24.checksum(b"123456789");
+    }
+}
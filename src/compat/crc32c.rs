@@ -0,0 +1,95 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! A drop-in replacement for the [`crc32c`](https://crates.io/crates/crc32c) crate's API,
+//! calculating CRC-32/ISCSI (CRC-32C).
+//!
+//! ```rust
+//! // use crc32c::{crc32c, crc32c_append};
+//! use crc_fast::compat::crc32c::{crc32c, crc32c_append};
+//!
+//! assert_eq!(crc32c(b"123456789"), 0xe3069283);
+//! assert_eq!(crc32c_append(crc32c(b"1234"), b"56789"), 0xe3069283);
+//! ```
+//!
+//! Requires the `crc32c` feature.
+
+use crate::{CrcAlgorithm, Digest};
+
+/// Computes the CRC-32C of `data`.
+pub fn crc32c(data: &[u8]) -> u32 {
+    crate::checksum(CrcAlgorithm::Crc32Iscsi, data) as u32
+}
+
+/// Computes the CRC-32C of `data`, continuing from the CRC-32C `crc` of some earlier data.
+pub fn crc32c_append(crc: u32, data: &[u8]) -> u32 {
+    let mut digest = Digest::new_with_init_state(CrcAlgorithm::Crc32Iscsi, !crc as u64);
+    digest.update(data);
+
+    digest.finalize() as u32
+}
+
+/// Computes the CRC-32C of two concatenated buffers from their CRC-32Cs, given the length of the
+/// second.
+pub fn crc32c_combine(crc1: u32, crc2: u32, len2: usize) -> u32 {
+    crate::checksum_combine(
+        CrcAlgorithm::Crc32Iscsi,
+        crc1 as u64,
+        crc2 as u64,
+        len2 as u64,
+    ) as u32
+}
+
+/// A [`Hasher`](core::hash::Hasher) which computes the CRC-32C of the bytes written.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Crc32cHasher {
+    checksum: u32,
+}
+
+impl Crc32cHasher {
+    /// Creates a new `Crc32cHasher`, continuing from the CRC-32C `initial`.
+    pub fn new(initial: u32) -> Self {
+        Self { checksum: initial }
+    }
+}
+
+impl core::hash::Hasher for Crc32cHasher {
+    fn finish(&self) -> u64 {
+        self.checksum as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.checksum = crc32c_append(self.checksum, bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::RUST_CRC32_ISCSI;
+    use core::hash::Hasher;
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_crc32c() {
+        let mut data = vec![0u8; 1024];
+        rng().fill(&mut data[..]);
+        let expected = RUST_CRC32_ISCSI.checksum(&data);
+
+        assert_eq!(crc32c(&data), expected);
+        assert_eq!(crc32c_append(crc32c(&data[..100]), &data[100..]), expected);
+        assert_eq!(crc32c_append(0, &data), expected);
+        assert_eq!(
+            crc32c_combine(crc32c(&data[..100]), crc32c(&data[100..]), 924),
+            expected
+        );
+
+        let mut hasher = Crc32cHasher::default();
+        hasher.write(&data[..500]);
+        hasher.write(&data[500..]);
+        assert_eq!(hasher.finish(), expected as u64);
+
+        let mut hasher = Crc32cHasher::new(crc32c(&data[..500]));
+        hasher.write(&data[500..]);
+        assert_eq!(hasher.finish(), expected as u64);
+    }
+}
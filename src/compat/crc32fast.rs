@@ -0,0 +1,140 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! A drop-in replacement for the [`crc32fast`](https://crates.io/crates/crc32fast) crate's API,
+//! calculating CRC-32/ISO-HDLC.
+//!
+//! ```rust
+//! // use crc32fast::Hasher;
+//! use crc_fast::compat::crc32fast::Hasher;
+//!
+//! let mut hasher = Hasher::new();
+//! hasher.update(b"123456789");
+//!
+//! assert_eq!(hasher.finalize(), 0xcbf43926);
+//! ```
+//!
+//! Requires the `crc32fast` feature.
+
+use crate::{CrcAlgorithm, Digest};
+
+/// Computes the CRC-32 of `buf`.
+pub fn hash(buf: &[u8]) -> u32 {
+    crate::checksum(CrcAlgorithm::Crc32IsoHdlc, buf) as u32
+}
+
+/// Represents an in-progress CRC-32 computation.
+#[derive(Clone, Debug)]
+pub struct Hasher {
+    digest: Digest,
+}
+
+impl Hasher {
+    /// Creates a new `Hasher`.
+    pub fn new() -> Self {
+        Self::new_with_initial(0)
+    }
+
+    /// Creates a new `Hasher` which continues from the CRC-32 of some earlier data.
+    pub fn new_with_initial(init: u32) -> Self {
+        Self::new_with_initial_len(init, 0)
+    }
+
+    /// Creates a new `Hasher` which continues from the CRC-32 of `amount` bytes of earlier data,
+    /// so that it can be combined with another `Hasher`.
+    pub fn new_with_initial_len(init: u32, amount: u64) -> Self {
+        let mut digest = Digest::new_with_init_state(CrcAlgorithm::Crc32IsoHdlc, !init as u64);
+        digest.amount = amount;
+
+        Self { digest }
+    }
+
+    /// Processes the given bytes.
+    pub fn update(&mut self, buf: &[u8]) {
+        self.digest.update(buf);
+    }
+
+    /// Finalizes the `Hasher`, returning the computed CRC-32.
+    pub fn finalize(self) -> u32 {
+        self.digest.finalize() as u32
+    }
+
+    /// Resets the `Hasher` to its initial state.
+    pub fn reset(&mut self) {
+        self.digest.reset();
+    }
+
+    /// Combines the CRC-32 of `other`, as if its data had followed this `Hasher`'s.
+    pub fn combine(&mut self, other: &Self) {
+        self.digest.combine(&other.digest);
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::hash::Hasher for Hasher {
+    fn finish(&self) -> u64 {
+        self.digest.finalize()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::RUST_CRC32_ISO_HDLC;
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_hasher() {
+        let mut data = vec![0u8; 1024];
+        rng().fill(&mut data[..]);
+        let expected = RUST_CRC32_ISO_HDLC.checksum(&data);
+
+        assert_eq!(hash(&data), expected);
+        assert_eq!(hash(b"123456789"), 0xcbf43926);
+
+        let mut hasher = Hasher::new();
+        hasher.update(&data[..100]);
+        let partial = hasher.clone().finalize();
+        hasher.update(&data[100..]);
+        assert_eq!(hasher.clone().finalize(), expected);
+        assert_eq!(core::hash::Hasher::finish(&hasher), expected as u64);
+
+        let mut resumed = Hasher::new_with_initial(partial);
+        resumed.update(&data[100..]);
+        assert_eq!(resumed.finalize(), expected);
+
+        hasher.reset();
+        assert_eq!(hasher.finalize(), 0);
+    }
+
+    #[test]
+    fn test_combine() {
+        let mut data = vec![0u8; 1024];
+        rng().fill(&mut data[..]);
+
+        let mut first = Hasher::new();
+        first.update(&data[..300]);
+
+        let mut second = Hasher::default();
+        second.update(&data[300..]);
+
+        first.combine(&second);
+        assert_eq!(first.finalize(), RUST_CRC32_ISO_HDLC.checksum(&data));
+
+        // a resumed hasher combines using the length it was given
+        let mut second = Hasher::new_with_initial_len(hash(&data[300..600]), 300);
+        second.update(&data[600..]);
+
+        let mut first = Hasher::new_with_initial(hash(&data[..300]));
+        first.combine(&second);
+        assert_eq!(first.finalize(), RUST_CRC32_ISO_HDLC.checksum(&data));
+    }
+}
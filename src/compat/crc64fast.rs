@@ -0,0 +1,74 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! A drop-in replacement for the [`crc64fast`](https://crates.io/crates/crc64fast) crate's API,
+//! calculating CRC-64/XZ.
+//!
+//! ```rust
+//! // use crc64fast::Digest;
+//! use crc_fast::compat::crc64fast::Digest;
+//!
+//! let mut digest = Digest::new();
+//! digest.write(b"123456789");
+//!
+//! assert_eq!(digest.sum64(), 0x995dc9bbdf1939fa);
+//! ```
+//!
+//! Requires the `crc64fast` feature.
+
+use crate::CrcAlgorithm;
+
+/// Represents an in-progress CRC-64 computation.
+#[derive(Clone, Copy, Debug)]
+pub struct Digest {
+    digest: crate::Digest,
+}
+
+impl Digest {
+    /// Creates a new `Digest`.
+    pub fn new() -> Self {
+        Self {
+            digest: crate::Digest::new(CrcAlgorithm::Crc64Xz),
+        }
+    }
+
+    /// Processes the given bytes.
+    pub fn write(&mut self, bytes: &[u8]) {
+        self.digest.update(bytes);
+    }
+
+    /// Returns the CRC-64 of all the bytes written so far.
+    pub fn sum64(&self) -> u64 {
+        self.digest.finalize()
+    }
+}
+
+impl Default for Digest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::RUST_CRC64_XZ;
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_digest() {
+        let mut data = vec![0u8; 1024];
+        rng().fill(&mut data[..]);
+
+        let mut digest = Digest::default();
+        assert_eq!(digest.sum64(), 0);
+
+        digest.write(&data[..100]);
+        digest.write(&data[100..]);
+        assert_eq!(digest.sum64(), RUST_CRC64_XZ.checksum(&data));
+
+        // summing doesn't consume or reset the digest
+        digest.write(b"more");
+        data.extend_from_slice(b"more");
+        assert_eq!(digest.sum64(), RUST_CRC64_XZ.checksum(&data));
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Compatibility with the APIs of other CRC crates, so existing call sites can switch to this
+//! crate's accelerated implementations with minimal changes.
+//!
+//! Each module is behind a feature of the same name. To switch a dependency graph over, a shim
+//! crate can re-export one (such as `pub use crc_fast::compat::crc32fast::*;`) and be substituted
+//! with `[patch]`.
+
+#[cfg(feature = "crc")]
+pub mod crc;
+#[cfg(feature = "crc32c")]
+pub mod crc32c;
+#[cfg(feature = "crc32fast")]
+pub mod crc32fast;
+#[cfg(feature = "crc64fast")]
+pub mod crc64fast;
@@ -9,22 +9,18 @@ use crate::consts::{
 };
 use crate::CrcAlgorithm;
 use crate::CrcParams;
-use crc::{
-    CRC_32_AIXM, CRC_32_AUTOSAR, CRC_32_BASE91_D, CRC_32_BZIP2, CRC_32_CD_ROM_EDC, CRC_32_CKSUM,
-    CRC_32_ISCSI, CRC_32_ISO_HDLC, CRC_32_JAMCRC, CRC_32_MEF, CRC_32_MPEG_2, CRC_32_XFER,
-};
 
 // width=32 poly=0x814141ab init=0x00000000 refin=false refout=false xorout=0x00000000 check=0x3010bf7f residue=0x00000000 name="CRC-32/AIXM"
 pub const CRC32_AIXM: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Aixm,
     name: NAME_CRC32_AIXM,
     width: 32,
-    poly: CRC_32_AIXM.poly as u64,
-    init: CRC_32_AIXM.init as u64,
-    refin: CRC_32_AIXM.refin,   // false
-    refout: CRC_32_AIXM.refout, // false
-    xorout: CRC_32_AIXM.xorout as u64,
-    check: CRC_32_AIXM.check as u64,
+    poly: 0x814141ab,
+    init: 0x00000000,
+    refin: false,
+    refout: false,
+    xorout: 0x00000000,
+    check: 0x3010bf7f,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_814141AB_FORWARD),
 };
 
@@ -33,12 +29,12 @@ pub const CRC32_AUTOSAR: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Autosar,
     name: NAME_CRC32_AUTOSAR,
     width: 32,
-    poly: CRC_32_AUTOSAR.poly as u64,
-    init: CRC_32_AUTOSAR.init as u64,
-    refin: CRC_32_AUTOSAR.refin,   // true
-    refout: CRC_32_AUTOSAR.refout, // true
-    xorout: CRC_32_AUTOSAR.xorout as u64,
-    check: CRC_32_AUTOSAR.check as u64,
+    poly: 0xf4acfb13,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffff,
+    check: 0x1697d06a,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_F4ACFB13_REFLECTED),
 };
 
@@ -47,12 +43,12 @@ pub const CRC32_BASE91_D: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Base91D,
     name: NAME_CRC32_BASE91_D,
     width: 32,
-    poly: CRC_32_BASE91_D.poly as u64,
-    init: CRC_32_BASE91_D.init as u64,
-    refin: CRC_32_BASE91_D.refin,   // true
-    refout: CRC_32_BASE91_D.refout, // true
-    xorout: CRC_32_BASE91_D.xorout as u64,
-    check: CRC_32_BASE91_D.check as u64,
+    poly: 0xa833982b,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffff,
+    check: 0x87315576,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_A833982B_REFLECTED),
 };
 
@@ -61,12 +57,12 @@ pub const CRC32_BZIP2: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Bzip2,
     name: NAME_CRC32_BZIP2,
     width: 32,
-    poly: CRC_32_BZIP2.poly as u64,
-    init: CRC_32_BZIP2.init as u64,
-    refin: CRC_32_BZIP2.refin,   // false
-    refout: CRC_32_BZIP2.refout, // false
-    xorout: CRC_32_BZIP2.xorout as u64,
-    check: CRC_32_BZIP2.check as u64,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    refin: false,
+    refout: false,
+    xorout: 0xffffffff,
+    check: 0xfc891918,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_04C11DB7_FORWARD),
 };
 
@@ -75,12 +71,12 @@ pub const CRC32_CD_ROM_EDC: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32CdRomEdc,
     name: NAME_CRC32_CD_ROM_EDC,
     width: 32,
-    poly: CRC_32_CD_ROM_EDC.poly as u64,
-    init: CRC_32_CD_ROM_EDC.init as u64,
-    refin: CRC_32_CD_ROM_EDC.refin,   // true
-    refout: CRC_32_CD_ROM_EDC.refout, // true
-    xorout: CRC_32_CD_ROM_EDC.xorout as u64,
-    check: CRC_32_CD_ROM_EDC.check as u64,
+    poly: 0x8001801b,
+    init: 0x00000000,
+    refin: true,
+    refout: true,
+    xorout: 0x00000000,
+    check: 0x6ec2edc4,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_8001801B_REFLECTED),
 };
 
@@ -89,12 +85,12 @@ pub const CRC32_CKSUM: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Cksum,
     name: NAME_CRC32_CKSUM,
     width: 32,
-    poly: CRC_32_CKSUM.poly as u64,
-    init: CRC_32_CKSUM.init as u64,
-    refin: CRC_32_CKSUM.refin,   // false
-    refout: CRC_32_CKSUM.refout, // false
-    xorout: CRC_32_CKSUM.xorout as u64,
-    check: CRC_32_CKSUM.check as u64,
+    poly: 0x04c11db7,
+    init: 0x00000000,
+    refin: false,
+    refout: false,
+    xorout: 0xffffffff,
+    check: 0x765e7680,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_04C11DB7_FORWARD),
 };
 
@@ -103,12 +99,12 @@ pub const CRC32_ISCSI: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Iscsi,
     name: NAME_CRC32_ISCSI,
     width: 32,
-    poly: CRC_32_ISCSI.poly as u64,
-    init: CRC_32_ISCSI.init as u64,
-    refin: CRC_32_ISCSI.refin,   // true
-    refout: CRC_32_ISCSI.refout, // true
-    xorout: CRC_32_ISCSI.xorout as u64,
-    check: CRC_32_ISCSI.check as u64,
+    poly: 0x1edc6f41,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffff,
+    check: 0xe3069283,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_1EDC6F41_REFLECTED),
 };
 
@@ -117,12 +113,12 @@ pub const CRC32_ISO_HDLC: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32IsoHdlc,
     name: NAME_CRC32_ISO_HDLC,
     width: 32,
-    poly: CRC_32_ISO_HDLC.poly as u64,
-    init: CRC_32_ISO_HDLC.init as u64,
-    refin: CRC_32_ISO_HDLC.refin,   // true
-    refout: CRC_32_ISO_HDLC.refout, // true
-    xorout: CRC_32_ISO_HDLC.xorout as u64,
-    check: CRC_32_ISO_HDLC.check as u64,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffff,
+    check: 0xcbf43926,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_04C11DB7_REFLECTED),
 };
 
@@ -131,12 +127,12 @@ pub const CRC32_JAMCRC: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Jamcrc,
     name: NAME_CRC32_JAMCRC,
     width: 32,
-    poly: CRC_32_JAMCRC.poly as u64,
-    init: CRC_32_JAMCRC.init as u64,
-    refin: CRC_32_JAMCRC.refin,   // true
-    refout: CRC_32_JAMCRC.refout, // true
-    xorout: CRC_32_JAMCRC.xorout as u64,
-    check: CRC_32_JAMCRC.check as u64,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0x00000000,
+    check: 0x340bc6d9,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_04C11DB7_REFLECTED),
 };
 
@@ -145,12 +141,12 @@ pub const CRC32_MEF: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Mef,
     name: NAME_CRC32_MEF,
     width: 32,
-    poly: CRC_32_MEF.poly as u64,
-    init: CRC_32_MEF.init as u64,
-    refin: CRC_32_MEF.refin,   // true
-    refout: CRC_32_MEF.refout, // true
-    xorout: CRC_32_MEF.xorout as u64,
-    check: CRC_32_MEF.check as u64,
+    poly: 0x741b8cd7,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0x00000000,
+    check: 0xd2c22f51,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_741B8CD7_REFLECTED),
 };
 
@@ -159,12 +155,12 @@ pub const CRC32_MPEG_2: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Mpeg2,
     name: NAME_CRC32_MPEG_2,
     width: 32,
-    poly: CRC_32_MPEG_2.poly as u64,
-    init: CRC_32_MPEG_2.init as u64,
-    refin: CRC_32_MPEG_2.refin,   // false
-    refout: CRC_32_MPEG_2.refout, // false
-    xorout: CRC_32_MPEG_2.xorout as u64,
-    check: CRC_32_MPEG_2.check as u64,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    refin: false,
+    refout: false,
+    xorout: 0x00000000,
+    check: 0x0376e6e7,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_04C11DB7_FORWARD),
 };
 
@@ -173,12 +169,12 @@ pub const CRC32_XFER: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc32Xfer,
     name: NAME_CRC32_XFER,
     width: 32,
-    poly: CRC_32_XFER.poly as u64,
-    init: CRC_32_XFER.init as u64,
-    refin: CRC_32_XFER.refin,   // false
-    refout: CRC_32_XFER.refout, // false
-    xorout: CRC_32_XFER.xorout as u64,
-    check: CRC_32_XFER.check as u64,
+    poly: 0x000000af,
+    init: 0x00000000,
+    refin: false,
+    refout: false,
+    xorout: 0x00000000,
+    check: 0xbd0be338,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_000000AF_FORWARD),
 };
 
@@ -209,6 +205,14 @@ pub const KEYS_814141AB_FORWARD: [u64; 23] = [
     0x93a03b8800000000,
 ];
 
+// CRC-32/AIXM
+const WIDE_KEYS_814141AB_FORWARD: [u64; 4] = [
+    0x906103ff00000000,
+    0x9fe4d6e700000000,
+    0x2708848e00000000,
+    0x204dc4ba00000000,
+];
+
 // CRC-32/AUTOSAR
 pub const KEYS_F4ACFB13_REFLECTED: [u64; 23] = [
     0x0000000000000000,
@@ -236,6 +240,14 @@ pub const KEYS_F4ACFB13_REFLECTED: [u64; 23] = [
     0x00000000000cbd7c,
 ];
 
+// CRC-32/AUTOSAR
+const WIDE_KEYS_F4ACFB13_REFLECTED: [u64; 4] = [
+    0x000000010d01fcaa,
+    0x000000005bff600c,
+    0x00000000c0b394a8,
+    0x000000005a7de00a,
+];
+
 // CRC-32/BASE91-D
 pub const KEYS_A833982B_REFLECTED: [u64; 23] = [
     0x0000000000000000,
@@ -263,6 +275,14 @@ pub const KEYS_A833982B_REFLECTED: [u64; 23] = [
     0x000000015920d7a6,
 ];
 
+// CRC-32/BASE91-D
+const WIDE_KEYS_A833982B_REFLECTED: [u64; 4] = [
+    0x0000000182fbbbfa,
+    0x0000000103c9c7ce,
+    0x00000001d38d6010,
+    0x0000000122244ac0,
+];
+
 // CRC-32/CD-ROM-EDC
 pub const KEYS_8001801B_REFLECTED: [u64; 23] = [
     0x0000000000000000,
@@ -290,6 +310,14 @@ pub const KEYS_8001801B_REFLECTED: [u64; 23] = [
     0x000000010d925102,
 ];
 
+// CRC-32/CD-ROM-EDC
+const WIDE_KEYS_8001801B_REFLECTED: [u64; 4] = [
+    0x0000000147596180,
+    0x0000000099918100,
+    0x000000014be969a2,
+    0x0000000041909100,
+];
+
 // CRC-32/MEF
 pub const KEYS_741B8CD7_REFLECTED: [u64; 23] = [
     0x0000000000000000,
@@ -317,6 +345,14 @@ pub const KEYS_741B8CD7_REFLECTED: [u64; 23] = [
     0x00000001f91b48f0,
 ];
 
+// CRC-32/MEF
+const WIDE_KEYS_741B8CD7_REFLECTED: [u64; 4] = [
+    0x0000000049a14d4a,
+    0x000000012608b282,
+    0x0000000087d12dba,
+    0x0000000031ad1e68,
+];
+
 // CRC-32/XFER
 pub const KEYS_000000AF_FORWARD: [u64; 23] = [
     0x0000000000000000,
@@ -344,87 +380,136 @@ pub const KEYS_000000AF_FORWARD: [u64; 23] = [
     0x6d2b811a00000000,
 ];
 
+// CRC-32/XFER
+const WIDE_KEYS_000000AF_FORWARD: [u64; 4] = [
+    0x7993632b00000000,
+    0xf77ae7a100000000,
+    0x5f56c30500000000,
+    0xe915341800000000,
+];
+
 // CRC-32/ISO-HDLC (aka 'crc32'), CRC-32/JAMCRC
 const KEYS_04C11DB7_REFLECTED: [u64; 23] = [
-    0x0000000000000000, // unused placeholder to match 1-based indexing
-    0x00000000ccaa009e, // (2^(32* 3) mod P(x))' << 1
-    0x00000001751997d0, // (2^(32* 5) mod P(x))' << 1
-    0x000000014a7fe880, // (2^(32*31) mod P(x))' << 1
-    0x00000001e88ef372, // (2^(32*33) mod P(x))' << 1
-    0x00000000ccaa009e, // (2^(32* 3) mod P(x))' << 1
-    0x0000000163cd6124, // (2^(32* 2) mod P(x))' << 1
-    0x00000001f7011641, // (floor(2^64/P(x)))'
-    0x00000001db710641, // (P(x))'
-    0x00000001d7cfc6ac, // (2^(32*27) mod P(x))' << 1
-    0x00000001ea89367e, // (2^(32*29) mod P(x))' << 1
-    0x000000018cb44e58, // (2^(32*23) mod P(x))' << 1
-    0x00000000df068dc2, // (2^(32*25) mod P(x))' << 1
-    0x00000000ae0b5394, // (2^(32*19) mod P(x))' << 1
-    0x00000001c7569e54, // (2^(32*21) mod P(x))' << 1
-    0x00000001c6e41596, // (2^(32*15) mod P(x))' << 1
-    0x0000000154442bd4, // (2^(32*17) mod P(x))' << 1
-    0x0000000174359406, // (2^(32*11) mod P(x))' << 1
-    0x000000003db1ecdc, // (2^(32*13) mod P(x))' << 1
-    0x000000015a546366, // (2^(32* 7) mod P(x))' << 1
-    0x00000000f1da05aa, // (2^(32* 9) mod P(x))' << 1
+    0x0000000000000000, // unused placeholder to match 1-based indexing,
+    0x00000000ccaa009e, // (2^(32* 3) mod P(x))' << 1,
+    0x00000001751997d0, // (2^(32* 5) mod P(x))' << 1,
+    0x000000014a7fe880, // (2^(32*31) mod P(x))' << 1,
+    0x00000001e88ef372, // (2^(32*33) mod P(x))' << 1,
+    0x00000000ccaa009e, // (2^(32* 3) mod P(x))' << 1,
+    0x0000000163cd6124, // (2^(32* 2) mod P(x))' << 1,
+    0x00000001f7011641, // (floor(2^64/P(x)))',
+    0x00000001db710641, // (P(x))',
+    0x00000001d7cfc6ac, // (2^(32*27) mod P(x))' << 1,
+    0x00000001ea89367e, // (2^(32*29) mod P(x))' << 1,
+    0x000000018cb44e58, // (2^(32*23) mod P(x))' << 1,
+    0x00000000df068dc2, // (2^(32*25) mod P(x))' << 1,
+    0x00000000ae0b5394, // (2^(32*19) mod P(x))' << 1,
+    0x00000001c7569e54, // (2^(32*21) mod P(x))' << 1,
+    0x00000001c6e41596, // (2^(32*15) mod P(x))' << 1,
+    0x0000000154442bd4, // (2^(32*17) mod P(x))' << 1,
+    0x0000000174359406, // (2^(32*11) mod P(x))' << 1,
+    0x000000003db1ecdc, // (2^(32*13) mod P(x))' << 1,
+    0x000000015a546366, // (2^(32* 7) mod P(x))' << 1,
+    0x00000000f1da05aa, // (2^(32* 9) mod P(x))' << 1,
     0x00000001322d1430,
     0x000000011542778a,
 ];
 
+// CRC-32/ISO-HDLC (aka 'crc32'), CRC-32/JAMCRC
+const WIDE_KEYS_04C11DB7_REFLECTED: [u64; 4] = [
+    0x00000001d741f35c,
+    0x000000001072db28,
+    0x00000000bbf2f6d6,
+    0x0000000123e0f72e,
+];
+
 // CRC-32/ISCSI (aka 'crc32c')
 const KEYS_1EDC6F41_REFLECTED: [u64; 23] = [
-    0x0000000000000000, // unused placeholder to match 1-based indexing
-    0x000000014cd00bd6, // (2^(32* 3) mod P(x))' << 1
-    0x00000000f20c0dfe, // (2^(32* 5) mod P(x))' << 1
-    0x000000000d3b6092, // (2^(32*31) mod P(x))' << 1
-    0x000000006992cea2, // (2^(32*33) mod P(x))' << 1
-    0x000000014cd00bd6, // (2^(32* 3) mod P(x))' << 1
-    0x00000000dd45aab8, // (2^(32* 2) mod P(x))' << 1
-    0x00000000dea713f1, // (floor(2^64/P(x)))'
-    0x0000000105ec76f1, // (P(x))'
-    0x000000014237f5e6, // (2^(32*27) mod P(x))' << 1
-    0x000000002ad91c30, // (2^(32*29) mod P(x))' << 1
-    0x0000000102f9b8a2, // (2^(32*23) mod P(x))' << 1
-    0x00000001c1733996, // (2^(32*25) mod P(x))' << 1
-    0x0000000039d3b296, // (2^(32*19) mod P(x))' << 1
-    0x00000000083a6eec, // (2^(32*21) mod P(x))' << 1
-    0x000000009e4addf8, // (2^(32*15) mod P(x))' << 1
-    0x00000000740eef02, // (2^(32*17) mod P(x))' << 1
-    0x00000001d82c63da, // (2^(32*11) mod P(x))' << 1
-    0x000000001c291d04, // (2^(32*13) mod P(x))' << 1
-    0x00000000ba4fc28e, // (2^(32* 7) mod P(x))' << 1
-    0x00000001384aa63a, // (2^(32* 9) mod P(x))' << 1
+    0x0000000000000000, // unused placeholder to match 1-based indexing,
+    0x000000014cd00bd6, // (2^(32* 3) mod P(x))' << 1,
+    0x00000000f20c0dfe, // (2^(32* 5) mod P(x))' << 1,
+    0x000000000d3b6092, // (2^(32*31) mod P(x))' << 1,
+    0x000000006992cea2, // (2^(32*33) mod P(x))' << 1,
+    0x000000014cd00bd6, // (2^(32* 3) mod P(x))' << 1,
+    0x00000000dd45aab8, // (2^(32* 2) mod P(x))' << 1,
+    0x00000000dea713f1, // (floor(2^64/P(x)))',
+    0x0000000105ec76f1, // (P(x))',
+    0x000000014237f5e6, // (2^(32*27) mod P(x))' << 1,
+    0x000000002ad91c30, // (2^(32*29) mod P(x))' << 1,
+    0x0000000102f9b8a2, // (2^(32*23) mod P(x))' << 1,
+    0x00000001c1733996, // (2^(32*25) mod P(x))' << 1,
+    0x0000000039d3b296, // (2^(32*19) mod P(x))' << 1,
+    0x00000000083a6eec, // (2^(32*21) mod P(x))' << 1,
+    0x000000009e4addf8, // (2^(32*15) mod P(x))' << 1,
+    0x00000000740eef02, // (2^(32*17) mod P(x))' << 1,
+    0x00000001d82c63da, // (2^(32*11) mod P(x))' << 1,
+    0x000000001c291d04, // (2^(32*13) mod P(x))' << 1,
+    0x00000000ba4fc28e, // (2^(32* 7) mod P(x))' << 1,
+    0x00000001384aa63a, // (2^(32* 9) mod P(x))' << 1,
     0x00000000b9e02b86,
     0x00000000dcb17aa4,
 ];
 
+// CRC-32/ISCSI (aka 'crc32c')
+const WIDE_KEYS_1EDC6F41_REFLECTED: [u64; 4] = [
+    0x00000000dd7e3b0c,
+    0x00000000bd6f81f8,
+    0x00000000170076fa,
+    0x00000000fe314258,
+];
+
 // CRC-32/BZIP2, CRC-32/CKSUM, CRC-32/MPEG-2
 const KEYS_04C11DB7_FORWARD: [u64; 23] = [
-    0x0000000000000000, // unused placeholder to match 1-based indexing
-    0xf200aa6600000000, // 2^(32* 3) mod P(x) << 32
-    0x17d3315d00000000, // 2^(32* 5) mod P(x) << 32
-    0x022ffca500000000, // 2^(32*31) mod P(x) << 32
-    0x9d9ee22f00000000, // 2^(32*33) mod P(x) << 32
-    0xf200aa6600000000, // 2^(32* 3) mod P(x) << 32
-    0x490d678d00000000, // 2^(32* 2) mod P(x) << 32
-    0x0000000104d101df, // floor(2^64/P(x))
-    0x0000000104c11db7, // P(x)
-    0x6ac7e7d700000000, // 2^(32*27) mod P(x) << 32
-    0xfcd922af00000000, // 2^(32*29) mod P(x) << 32
-    0x34e45a6300000000, // 2^(32*23) mod P(x) << 32
-    0x8762c1f600000000, // 2^(32*25) mod P(x) << 32
-    0x5395a0ea00000000, // 2^(32*19) mod P(x) << 32
-    0x54f2d5c700000000, // 2^(32*21) mod P(x) << 32
-    0xd3504ec700000000, // 2^(32*15) mod P(x) << 32
-    0x57a8445500000000, // 2^(32*17) mod P(x) << 32
-    0xc053585d00000000, // 2^(32*11) mod P(x) << 32
-    0x766f1b7800000000, // 2^(32*13) mod P(x) << 32
-    0xcd8c54b500000000, // 2^(32* 7) mod P(x) << 32
-    0xab40b71e00000000, // 2^(32* 9) mod P(x) << 32
+    0x0000000000000000, // unused placeholder to match 1-based indexing,
+    0xf200aa6600000000, // 2^(32* 3) mod P(x) << 32,
+    0x17d3315d00000000, // 2^(32* 5) mod P(x) << 32,
+    0x022ffca500000000, // 2^(32*31) mod P(x) << 32,
+    0x9d9ee22f00000000, // 2^(32*33) mod P(x) << 32,
+    0xf200aa6600000000, // 2^(32* 3) mod P(x) << 32,
+    0x490d678d00000000, // 2^(32* 2) mod P(x) << 32,
+    0x0000000104d101df, // floor(2^64/P(x)),
+    0x0000000104c11db7, // P(x),
+    0x6ac7e7d700000000, // 2^(32*27) mod P(x) << 32,
+    0xfcd922af00000000, // 2^(32*29) mod P(x) << 32,
+    0x34e45a6300000000, // 2^(32*23) mod P(x) << 32,
+    0x8762c1f600000000, // 2^(32*25) mod P(x) << 32,
+    0x5395a0ea00000000, // 2^(32*19) mod P(x) << 32,
+    0x54f2d5c700000000, // 2^(32*21) mod P(x) << 32,
+    0xd3504ec700000000, // 2^(32*15) mod P(x) << 32,
+    0x57a8445500000000, // 2^(32*17) mod P(x) << 32,
+    0xc053585d00000000, // 2^(32*11) mod P(x) << 32,
+    0x766f1b7800000000, // 2^(32*13) mod P(x) << 32,
+    0xcd8c54b500000000, // 2^(32* 7) mod P(x) << 32,
+    0xab40b71e00000000, // 2^(32* 9) mod P(x) << 32,
     0x1851689900000000,
     0xa3dc855100000000,
 ];
 
+// CRC-32/BZIP2, CRC-32/CKSUM, CRC-32/MPEG-2
+const WIDE_KEYS_04C11DB7_FORWARD: [u64; 4] = [
+    0x759f05d700000000,
+    0x29b69c1000000000,
+    0xd6de9fba00000000,
+    0xe9de0f8900000000,
+];
+
+/// Returns the precomputed 512 and 1024-byte folding keys for a predefined CRC-32 polynomial,
+/// in the same order as `generate::wide_keys()`.
+pub(crate) const fn wide_keys(poly: u64, reflected: bool) -> Option<[u64; 4]> {
+    match (poly, reflected) {
+        (0x814141ab, false) => Some(WIDE_KEYS_814141AB_FORWARD),
+        (0xf4acfb13, true) => Some(WIDE_KEYS_F4ACFB13_REFLECTED),
+        (0xa833982b, true) => Some(WIDE_KEYS_A833982B_REFLECTED),
+        (0x8001801b, true) => Some(WIDE_KEYS_8001801B_REFLECTED),
+        (0x741b8cd7, true) => Some(WIDE_KEYS_741B8CD7_REFLECTED),
+        (0x000000af, false) => Some(WIDE_KEYS_000000AF_FORWARD),
+        (0x04c11db7, true) => Some(WIDE_KEYS_04C11DB7_REFLECTED),
+        (0x1edc6f41, true) => Some(WIDE_KEYS_1EDC6F41_REFLECTED),
+        (0x04c11db7, false) => Some(WIDE_KEYS_04C11DB7_FORWARD),
+        _ => None,
+    }
+}
+
 pub(crate) const SIMD_CONSTANTS: [[u64; 2]; 4] = [
     [0x08090a0b0c0d0e0f, 0x0001020304050607], // smask
     [0x8080808080808080, 0x8080808080808080], // mask1
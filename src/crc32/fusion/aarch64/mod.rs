@@ -16,14 +16,55 @@ mod iscsi;
 mod iso_hdlc;
 
 use core::arch::aarch64::*;
-#[cfg(feature = "std")]
+#[cfg(all(test, feature = "std"))]
 use std::arch::is_aarch64_feature_detected;
 
+use crate::feature_detection::PerformanceTier;
+use crate::{CalculatorFn, CrcParams};
+
 use iscsi::crc_pmull::crc32_iscsi_v12e_v1;
 use iscsi::crc_pmull_sha3::crc32_iscsi_eor3_v9s3x2e_s3;
 use iso_hdlc::crc_pmull::crc32_iso_hdlc_v12e_v1;
 use iso_hdlc::crc_pmull_sha3::crc32_iso_hdlc_eor3_v9s3x2e_s3;
 
+/// Returns the CRC-32/ISCSI fusion kernel for the selected AArch64 performance tier
+#[inline(always)]
+pub(crate) fn crc32_iscsi_kernel(tier: PerformanceTier) -> CalculatorFn {
+    match tier {
+        PerformanceTier::AArch64AesSha3 => crc32_iscsi_aes_sha3_kernel,
+        _ => crc32_iscsi_aes_kernel,
+    }
+}
+
+/// Returns the CRC-32/ISO-HDLC fusion kernel for the selected AArch64 performance tier
+#[inline(always)]
+pub(crate) fn crc32_iso_hdlc_kernel(tier: PerformanceTier) -> CalculatorFn {
+    match tier {
+        PerformanceTier::AArch64AesSha3 => crc32_iso_hdlc_aes_sha3_kernel,
+        _ => crc32_iso_hdlc_aes_kernel,
+    }
+}
+
+// The kernels below are only handed out once the matching performance tier has been selected,
+// which guarantees the CPU supports the target features they require.
+
+fn crc32_iscsi_aes_sha3_kernel(state: u64, data: &[u8], _params: CrcParams) -> u64 {
+    unsafe { crc32_iscsi_aes_sha3(state as u32, data) as u64 }
+}
+
+fn crc32_iscsi_aes_kernel(state: u64, data: &[u8], _params: CrcParams) -> u64 {
+    unsafe { crc32_iscsi_aes(state as u32, data) as u64 }
+}
+
+fn crc32_iso_hdlc_aes_sha3_kernel(state: u64, data: &[u8], _params: CrcParams) -> u64 {
+    unsafe { crc32_iso_hdlc_aes_sha3(state as u32, data) as u64 }
+}
+
+fn crc32_iso_hdlc_aes_kernel(state: u64, data: &[u8], _params: CrcParams) -> u64 {
+    unsafe { crc32_iso_hdlc_aes(state as u32, data) as u64 }
+}
+
+#[cfg(test)]
 #[inline(always)]
 pub fn crc32_iscsi(crc: u32, data: &[u8]) -> u32 {
     #[cfg(feature = "std")]
@@ -38,6 +79,7 @@ pub fn crc32_iscsi(crc: u32, data: &[u8]) -> u32 {
     }
 }
 
+#[cfg(test)]
 #[inline(always)]
 pub fn crc32_iso_hdlc(crc: u32, data: &[u8]) -> u32 {
     #[cfg(feature = "std")]
@@ -11,23 +11,26 @@
 mod aarch64;
 mod x86;
 
+use crate::feature_detection::PerformanceTier;
+use crate::CalculatorFn;
+
 /// Only AArch64 has native CRC-32/ISO-HDLC instructions
 #[inline(always)]
 #[cfg(target_arch = "aarch64")]
-pub(crate) fn crc32_iso_hdlc(state: u32, data: &[u8]) -> u32 {
-    aarch64::crc32_iso_hdlc(state, data)
+pub(crate) fn crc32_iso_hdlc_kernel(tier: PerformanceTier) -> CalculatorFn {
+    aarch64::crc32_iso_hdlc_kernel(tier)
 }
 
 /// Both AArch64 and x86 have native CRC-32/ISCSI instructions
 #[inline(always)]
-pub(crate) fn crc32_iscsi(state: u32, data: &[u8]) -> u32 {
+pub(crate) fn crc32_iscsi_kernel(tier: PerformanceTier) -> CalculatorFn {
     #[cfg(target_arch = "aarch64")]
     {
-        aarch64::crc32_iscsi(state, data)
+        aarch64::crc32_iscsi_kernel(tier)
     }
 
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     {
-        x86::crc32_iscsi(state, data)
+        x86::crc32_iscsi_kernel(tier)
     }
 }
@@ -11,7 +11,7 @@ use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
-use crate::fusion::x86::*;
+use crate::crc32::fusion::x86::*;
 
 /// Converted to Rust from the original C code generated by https://github.com/corsix/fast-crc32/
 /// with the help of Claude.ai using:
@@ -28,11 +28,55 @@ use iscsi::avx512_pclmulqdq::crc32_iscsi_avx512_v4s3x3;
 #[rustversion::since(1.89)]
 use iscsi::avx512_vpclmulqdq::crc32_iscsi_avx512_vpclmulqdq_v3x2;
 
+use crate::feature_detection::PerformanceTier;
+use crate::{CalculatorFn, CrcParams};
+
+/// Returns the CRC-32/ISCSI fusion kernel for the selected performance tier, for Rust versions
+/// before 1.89 (pre-AVX-512 support), where only the SSE implementation is available.
+#[rustversion::before(1.89)]
+#[inline(always)]
+pub(crate) fn crc32_iscsi_kernel(_tier: PerformanceTier) -> CalculatorFn {
+    crc32_iscsi_sse_kernel
+}
+
+/// Returns the CRC-32/ISCSI fusion kernel for the selected performance tier after Rust 1.89
+/// (post-AVX-512 support)
+#[rustversion::since(1.89)]
+#[inline(always)]
+pub(crate) fn crc32_iscsi_kernel(tier: PerformanceTier) -> CalculatorFn {
+    match tier {
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64Avx512Vpclmulqdq => crc32_iscsi_avx512_vpclmulqdq_kernel,
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64Avx512Pclmulqdq => crc32_iscsi_avx512_kernel,
+        _ => crc32_iscsi_sse_kernel,
+    }
+}
+
+// The kernels below are only handed out once the matching performance tier has been selected,
+// which guarantees the CPU supports the target features they require.
+
+#[rustversion::since(1.89)]
+#[cfg(target_arch = "x86_64")]
+fn crc32_iscsi_avx512_vpclmulqdq_kernel(state: u64, data: &[u8], _params: CrcParams) -> u64 {
+    unsafe { crc32_iscsi_avx512_vpclmulqdq_v3x2(state as u32, data.as_ptr(), data.len()) as u64 }
+}
+
+#[rustversion::since(1.89)]
+#[cfg(target_arch = "x86_64")]
+fn crc32_iscsi_avx512_kernel(state: u64, data: &[u8], _params: CrcParams) -> u64 {
+    unsafe { crc32_iscsi_avx512_v4s3x3(state as u32, data.as_ptr(), data.len()) as u64 }
+}
+
+fn crc32_iscsi_sse_kernel(state: u64, data: &[u8], _params: CrcParams) -> u64 {
+    unsafe { crc32_iscsi_sse_v4s3x3(state as u32, data.as_ptr(), data.len()) as u64 }
+}
+
 /// CRC32 iSCSI calculation for Rust versions before 1.89 (pre-AVX-512 support)
 ///
-///
-/// This function is called by the wrapper layer after feature detection has been performed.
+/// Only used by tests, since the hot paths resolve a kernel once via `crc32_iscsi_kernel()`.
 /// For older Rust versions, only SSE implementation is available.
+#[cfg(test)]
 #[rustversion::before(1.89)]
 #[inline(always)]
 pub fn crc32_iscsi(crc: u32, data: &[u8]) -> u32 {
@@ -44,24 +88,32 @@ pub fn crc32_iscsi(crc: u32, data: &[u8]) -> u32 {
 /// CRC32 iSCSI calculation using the highest available instruction set after Rust 1.89
 /// (post-AVX-512 support)
 ///
-/// This function is called by the wrapper layer after feature detection has been performed.
-/// The wrapper layer ensures that only the appropriate implementation is called based on
-/// cached feature detection results, removing runtime checks from the hot path.
+/// Only used by tests, since the hot paths resolve a kernel once via `crc32_iscsi_kernel()`.
+#[cfg(test)]
 #[rustversion::since(1.89)]
 #[inline(always)]
 pub fn crc32_iscsi(crc: u32, data: &[u8]) -> u32 {
     #[cfg(target_arch = "x86_64")]
     {
-        // AVX512 + VPCLMULQDQ
+        #[cfg(feature = "std")]
+        let has_avx512vl = is_x86_feature_detected!("avx512vl");
+        #[cfg(not(feature = "std"))]
+        let has_avx512vl = cfg!(target_feature = "avx512vl");
+
+        #[cfg(feature = "std")]
+        let has_vpclmulqdq = is_x86_feature_detected!("vpclmulqdq");
+        #[cfg(not(feature = "std"))]
+        let has_vpclmulqdq = cfg!(target_feature = "vpclmulqdq");
 
-        if is_x86_feature_detected!("avx512vl") && is_x86_feature_detected!("vpclmulqdq") {
+        // AVX512 + VPCLMULQDQ
+        if has_avx512vl && has_vpclmulqdq {
             unsafe {
                 return crc32_iscsi_avx512_vpclmulqdq_v3x2(crc, data.as_ptr(), data.len());
             }
         }
 
         // AVX512
-        if is_x86_feature_detected!("avx512vl") {
+        if has_avx512vl {
             unsafe {
                 return crc32_iscsi_avx512_v4s3x3(crc, data.as_ptr(), data.len());
             }
@@ -5,19 +5,18 @@
 use crate::consts::*;
 use crate::CrcAlgorithm;
 use crate::CrcParams;
-use crc::{CRC_64_ECMA_182, CRC_64_GO_ISO, CRC_64_MS, CRC_64_REDIS, CRC_64_WE, CRC_64_XZ};
 
 // width=64 poly=0x42f0e1eba9ea3693 init=0x0000000000000000 refin=false refout=false xorout=0x0000000000000000 check=0x6c40df5f0b497347 residue=0x0000000000000000 name="CRC-64/ECMA-182"
 pub const CRC64_ECMA_182: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64Ecma182,
     name: NAME_CRC64_ECMA_182,
-    width: CRC_64_ECMA_182.width,
-    poly: CRC_64_ECMA_182.poly,
-    init: CRC_64_ECMA_182.init,
-    refin: CRC_64_ECMA_182.refin,   // false
-    refout: CRC_64_ECMA_182.refout, // false
-    xorout: CRC_64_ECMA_182.xorout,
-    check: CRC_64_ECMA_182.check,
+    width: 64,
+    poly: 0x42f0e1eba9ea3693,
+    init: 0x0000000000000000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000000000000000,
+    check: 0x6c40df5f0b497347,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_42F0E1EBA9EA3693_FORWARD),
 };
 
@@ -25,13 +24,13 @@ pub const CRC64_ECMA_182: CrcParams = CrcParams {
 pub const CRC64_GO_ISO: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64GoIso,
     name: NAME_CRC64_GO_ISO,
-    width: CRC_64_GO_ISO.width,
-    poly: CRC_64_GO_ISO.poly,
-    init: CRC_64_GO_ISO.init,
-    refin: CRC_64_GO_ISO.refin,   // true
-    refout: CRC_64_GO_ISO.refout, // true
-    xorout: CRC_64_GO_ISO.xorout,
-    check: CRC_64_GO_ISO.check,
+    width: 64,
+    poly: 0x000000000000001b,
+    init: 0xffffffffffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffffffffffff,
+    check: 0xb90956c775a41001,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_000000000000001B_REFLECTED),
 };
 
@@ -40,12 +39,12 @@ pub const CRC64_MS: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64Ms,
     name: NAME_CRC64_MS,
     width: 64,
-    poly: CRC_64_MS.poly,
-    init: CRC_64_MS.init,
-    refin: CRC_64_MS.refin,   // true
-    refout: CRC_64_MS.refout, // true
-    xorout: CRC_64_MS.xorout,
-    check: CRC_64_MS.check,
+    poly: 0x259c84cba6426349,
+    init: 0xffffffffffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0x0000000000000000,
+    check: 0x75d4b74f024eceea,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_259C84CBA6426349_REFLECTED),
 };
 
@@ -54,13 +53,13 @@ pub const CRC64_MS: CrcParams = CrcParams {
 pub const CRC64_NVME: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64Nvme,
     name: NAME_CRC64_NVME,
-    width: CRC_64_NVME.width,
-    poly: CRC_64_NVME.poly,
-    init: CRC_64_NVME.init,
-    refin: CRC_64_NVME.refin,   // true
-    refout: CRC_64_NVME.refout, // true
-    xorout: CRC_64_NVME.xorout,
-    check: CRC_64_NVME.check,
+    width: 64,
+    poly: 0xad93d23594c93659,
+    init: 0xffffffffffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffffffffffff,
+    check: 0xae8b14860a799888,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_AD93D23594C93659_REFLECTED),
 };
 
@@ -68,13 +67,13 @@ pub const CRC64_NVME: CrcParams = CrcParams {
 pub const CRC64_REDIS: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64Redis,
     name: NAME_CRC64_REDIS,
-    width: CRC_64_REDIS.width,
-    poly: CRC_64_REDIS.poly,
-    init: CRC_64_REDIS.init,
-    refin: CRC_64_REDIS.refin,   // true
-    refout: CRC_64_REDIS.refout, // true
-    xorout: CRC_64_REDIS.xorout,
-    check: CRC_64_REDIS.check,
+    width: 64,
+    poly: 0xad93d23594c935a9,
+    init: 0x0000000000000000,
+    refin: true,
+    refout: true,
+    xorout: 0x0000000000000000,
+    check: 0xe9c6d914c4b8d9ca,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_AD93D23594C935A9_REFLECTED),
 };
 
@@ -82,13 +81,13 @@ pub const CRC64_REDIS: CrcParams = CrcParams {
 pub const CRC64_WE: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64We,
     name: NAME_CRC64_WE,
-    width: CRC_64_WE.width,
-    poly: CRC_64_WE.poly,
-    init: CRC_64_WE.init,
-    refin: CRC_64_WE.refin,   // false
-    refout: CRC_64_WE.refout, // false
-    xorout: CRC_64_WE.xorout,
-    check: CRC_64_WE.check,
+    width: 64,
+    poly: 0x42f0e1eba9ea3693,
+    init: 0xffffffffffffffff,
+    refin: false,
+    refout: false,
+    xorout: 0xffffffffffffffff,
+    check: 0x62ec59e3f1a4f00a,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_42F0E1EBA9EA3693_FORWARD),
 };
 
@@ -96,13 +95,13 @@ pub const CRC64_WE: CrcParams = CrcParams {
 pub const CRC64_XZ: CrcParams = CrcParams {
     algorithm: CrcAlgorithm::Crc64Xz,
     name: NAME_CRC64_XZ,
-    width: CRC_64_XZ.width,
-    poly: CRC_64_XZ.poly,
-    init: CRC_64_XZ.init,
-    refin: CRC_64_XZ.refin,   // true
-    refout: CRC_64_XZ.refout, // true
-    xorout: CRC_64_XZ.xorout,
-    check: CRC_64_XZ.check,
+    width: 64,
+    poly: 0x42f0e1eba9ea3693,
+    init: 0xffffffffffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffffffffffff,
+    check: 0x995dc9bbdf1939fa,
     keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_42F0E1EBA9EA3693_REFLECTED),
 };
 
@@ -133,6 +132,14 @@ const KEYS_259C84CBA6426349_REFLECTED: [u64; 23] = [
     0x8dccaf9d6169d0fa,
 ];
 
+// CRC-64/MS
+const WIDE_KEYS_259C84CBA6426349_REFLECTED: [u64; 4] = [
+    0x6e94c3e6ceb173bb,
+    0x36f00b55ece1f124,
+    0x5be147799a724ed6,
+    0xe480232e1e5d2477,
+];
+
 // CRC-64/REDIS
 const KEYS_AD93D23594C935A9_REFLECTED: [u64; 23] = [
     0x0000000000000000,
@@ -160,98 +167,130 @@ const KEYS_AD93D23594C935A9_REFLECTED: [u64; 23] = [
     0x9a8908341a6d6d52,
 ];
 
+// CRC-64/REDIS
+const WIDE_KEYS_AD93D23594C935A9_REFLECTED: [u64; 4] = [
+    0x517c365ad57671f6,
+    0x5f76ba258de12275,
+    0xd5e1bb65fafe70e1,
+    0xe6f5da828ed0953a,
+];
+
 // CRC-64/ECMA-182, CRC-64/WE
 const KEYS_42F0E1EBA9EA3693_FORWARD: [u64; 23] = [
-    0x0000000000000000, // unused placeholder to match 1-based indexing
-    0x05f5c3c7eb52fab6, // 2^(64* 2) mod P(x)
-    0x4eb938a7d257740e, // 2^(64* 3) mod P(x)
-    0x05cf79dea9ac37d6, // 2^(64*16) mod P(x)
-    0x001067e571d7d5c2, // 2^(64*17) mod P(x)
-    0x05f5c3c7eb52fab6, // 2^(64* 2) mod P(x)
-    0x0000000000000000, // 2^(64* 1) mod P(x)
-    0x578d29d06cc4f872, // floor(2^128/P(x)) - 2^64, mu
-    0x42f0e1eba9ea3693, // P(x) - 2^64, poly_simd
-    0xe464f4df5fb60ac1, // 2^(64*14) mod P(x)
-    0xb649c5b35a759cf2, // 2^(64*15) mod P(x)
-    0x9af04e1eff82d0dd, // 2^(64*12) mod P(x)
-    0x6e82e609297f8fe8, // 2^(64*13) mod P(x)
-    0x097c516e98bd2e73, // 2^(64*10) mod P(x)
-    0x0b76477b31e22e7b, // 2^(64*11) mod P(x)
-    0x5f6843ca540df020, // 2^(64* 8) mod P(x)
-    0xddf4b6981205b83f, // 2^(64* 9) mod P(x)
-    0x54819d8713758b2c, // 2^(64* 6) mod P(x)
-    0x4a6b90073eb0af5a, // 2^(64* 7) mod P(x)
-    0x571bee0a227ef92b, // 2^(64* 4) mod P(x)
-    0x44bef2a201b5200c, // 2^(64* 5) mod P(x)
+    0x0000000000000000, // unused placeholder to match 1-based indexing,
+    0x05f5c3c7eb52fab6, // 2^(64* 2) mod P(x),
+    0x4eb938a7d257740e, // 2^(64* 3) mod P(x),
+    0x05cf79dea9ac37d6, // 2^(64*16) mod P(x),
+    0x001067e571d7d5c2, // 2^(64*17) mod P(x),
+    0x05f5c3c7eb52fab6, // 2^(64* 2) mod P(x),
+    0x0000000000000000, // 2^(64* 1) mod P(x),
+    0x578d29d06cc4f872, // floor(2^128/P(x)) - 2^64, mu,
+    0x42f0e1eba9ea3693, // P(x) - 2^64, poly_simd,
+    0xe464f4df5fb60ac1, // 2^(64*14) mod P(x),
+    0xb649c5b35a759cf2, // 2^(64*15) mod P(x),
+    0x9af04e1eff82d0dd, // 2^(64*12) mod P(x),
+    0x6e82e609297f8fe8, // 2^(64*13) mod P(x),
+    0x097c516e98bd2e73, // 2^(64*10) mod P(x),
+    0x0b76477b31e22e7b, // 2^(64*11) mod P(x),
+    0x5f6843ca540df020, // 2^(64* 8) mod P(x),
+    0xddf4b6981205b83f, // 2^(64* 9) mod P(x),
+    0x54819d8713758b2c, // 2^(64* 6) mod P(x),
+    0x4a6b90073eb0af5a, // 2^(64* 7) mod P(x),
+    0x571bee0a227ef92b, // 2^(64* 4) mod P(x),
+    0x44bef2a201b5200c, // 2^(64* 5) mod P(x),
     0x7f52691a60ddc70d,
     0x7036b0389f6a0c82,
 ];
 
+// CRC-64/ECMA-182, CRC-64/WE
+const WIDE_KEYS_42F0E1EBA9EA3693_FORWARD: [u64; 4] = [
+    0x1c6f_fb45_e31e_a184,
+    0x04df_74f1_878d_4dac,
+    0x4f3a_bb24_f049_ddb6,
+    0x5ce7_e0c2_63e9_c861,
+];
+
 // CRC-64/XZ
 const KEYS_42F0E1EBA9EA3693_REFLECTED: [u64; 23] = [
-    0x0000000000000000, // unused placeholder to match 1-based indexing
-    0xdabe95afc7875f40, // 2^((64* 2)-1) mod P(x)
-    0xe05dd497ca393ae4, // 2^((64* 3)-1) mod P(x)
-    0xd7d86b2af73de740, // 2^((64*16)-1) mod P(x)
-    0x8757d71d4fcc1000, // 2^((64*17)-1) mod P(x)
-    0xdabe95afc7875f40, // 2^((64* 2)-1) mod P(x)
-    0x0000000000000000, // 2^((64* 1)-1) mod P(x)
-    0x9c3e466c172963d5, // floor((2^127)/(P(x)), mu
-    0x92d8af2baf0e1e85, // P(x) - 1, poly_simd
-    0x947874de595052cb, // 2^((64*14)-1) mod P(x)
-    0x9e735cb59b4724da, // 2^((64*15)-1) mod P(x)
-    0xe4ce2cd55fea0037, // 2^((64*12)-1) mod P(x)
-    0x2fe3fd2920ce82ec, // 2^((64*13)-1) mod P(x)
-    0x0e31d519421a63a5, // 2^((64*10)-1) mod P(x)
-    0x2e30203212cac325, // 2^((64*11)-1) mod P(x)
-    0x081f6054a7842df4, // 2^((64* 8)-1) mod P(x)
-    0x6ae3efbb9dd441f3, // 2^((64* 9)-1) mod P(x)
-    0x69a35d91c3730254, // 2^((64* 6)-1) mod P(x)
-    0xb5ea1af9c013aca4, // 2^((64* 7)-1) mod P(x)
-    0x3be653a30fe1af51, // 2^((64* 4)-1) mod P(x)
-    0x60095b008a9efa44, // 2^((64* 5)-1) mod P(x)
+    0x0000000000000000, // unused placeholder to match 1-based indexing,
+    0xdabe95afc7875f40, // 2^((64* 2)-1) mod P(x),
+    0xe05dd497ca393ae4, // 2^((64* 3)-1) mod P(x),
+    0xd7d86b2af73de740, // 2^((64*16)-1) mod P(x),
+    0x8757d71d4fcc1000, // 2^((64*17)-1) mod P(x),
+    0xdabe95afc7875f40, // 2^((64* 2)-1) mod P(x),
+    0x0000000000000000, // 2^((64* 1)-1) mod P(x),
+    0x9c3e466c172963d5, // floor((2^127)/(P(x)), mu,
+    0x92d8af2baf0e1e85, // P(x) - 1, poly_simd,
+    0x947874de595052cb, // 2^((64*14)-1) mod P(x),
+    0x9e735cb59b4724da, // 2^((64*15)-1) mod P(x),
+    0xe4ce2cd55fea0037, // 2^((64*12)-1) mod P(x),
+    0x2fe3fd2920ce82ec, // 2^((64*13)-1) mod P(x),
+    0x0e31d519421a63a5, // 2^((64*10)-1) mod P(x),
+    0x2e30203212cac325, // 2^((64*11)-1) mod P(x),
+    0x081f6054a7842df4, // 2^((64* 8)-1) mod P(x),
+    0x6ae3efbb9dd441f3, // 2^((64* 9)-1) mod P(x),
+    0x69a35d91c3730254, // 2^((64* 6)-1) mod P(x),
+    0xb5ea1af9c013aca4, // 2^((64* 7)-1) mod P(x),
+    0x3be653a30fe1af51, // 2^((64* 4)-1) mod P(x),
+    0x60095b008a9efa44, // 2^((64* 5)-1) mod P(x),
     0xf31fd9271e228b79,
     0x8260adf2381ad81c,
 ];
 
+// CRC-64/XZ
+const WIDE_KEYS_42F0E1EBA9EA3693_REFLECTED: [u64; 4] = [
+    0x430a_f18f_45bf_ec70,
+    0x6b65_63c3_1e5d_f640,
+    0xdb77_241e_49ba_b9e4,
+    0x9eff_80a7_2901_d0f1,
+];
+
 // CRC-64/GO-ISO
 const KEYS_000000000000001B_REFLECTED: [u64; 23] = [
-    0x0000000000000000, // unused placeholder to match 1-based indexing
-    0xf500000000000001, // 2^((64* 2)-1) mod P(x)
-    0x6b70000000000001, // 2^((64* 3)-1) mod P(x)
-    0xb001000000010000, // 2^((64*16)-1) mod P(x)
-    0xf501b0000001b000, // 2^((64*17)-1) mod P(x)
-    0xf500000000000001, // 2^((64* 2)-1) mod P(x)
-    0x0000000000000000, // 2^((64* 1)-1) mod P(x)
-    0xb000000000000001, // floor((2^127)/(P(x)), mu
-    0xb000000000000001, // P(x) - 1, poly_simd
-    0xe014514514501501, // 2^((64*14)-1) mod P(x)
-    0x771db6db6db71c71, // 2^((64*15)-1) mod P(x)
-    0xa101101101110001, // 2^((64*12)-1) mod P(x)
-    0x1ab1ab1ab1aab001, // 2^((64*13)-1) mod P(x)
-    0xf445014445000001, // 2^((64*10)-1) mod P(x)
-    0x6aab71daab700001, // 2^((64*11)-1) mod P(x)
-    0xb100010100000001, // 2^((64* 8)-1) mod P(x)
-    0x01b001b1b0000001, // 2^((64* 9)-1) mod P(x)
-    0xe145150000000001, // 2^((64* 6)-1) mod P(x)
-    0x76db6c7000000001, // 2^((64* 7)-1) mod P(x)
-    0xa011000000000001, // 2^((64* 4)-1) mod P(x)
-    0x1b1ab00000000001, // 2^((64* 5)-1) mod P(x)
+    0x0000000000000000, // unused placeholder to match 1-based indexing,
+    0xf500000000000001, // 2^((64* 2)-1) mod P(x),
+    0x6b70000000000001, // 2^((64* 3)-1) mod P(x),
+    0xb001000000010000, // 2^((64*16)-1) mod P(x),
+    0xf501b0000001b000, // 2^((64*17)-1) mod P(x),
+    0xf500000000000001, // 2^((64* 2)-1) mod P(x),
+    0x0000000000000000, // 2^((64* 1)-1) mod P(x),
+    0xb000000000000001, // floor((2^127)/(P(x)), mu,
+    0xb000000000000001, // P(x) - 1, poly_simd,
+    0xe014514514501501, // 2^((64*14)-1) mod P(x),
+    0x771db6db6db71c71, // 2^((64*15)-1) mod P(x),
+    0xa101101101110001, // 2^((64*12)-1) mod P(x),
+    0x1ab1ab1ab1aab001, // 2^((64*13)-1) mod P(x),
+    0xf445014445000001, // 2^((64*10)-1) mod P(x),
+    0x6aab71daab700001, // 2^((64*11)-1) mod P(x),
+    0xb100010100000001, // 2^((64* 8)-1) mod P(x),
+    0x01b001b1b0000001, // 2^((64* 9)-1) mod P(x),
+    0xe145150000000001, // 2^((64* 6)-1) mod P(x),
+    0x76db6c7000000001, // 2^((64* 7)-1) mod P(x),
+    0xa011000000000001, // 2^((64* 4)-1) mod P(x),
+    0x1b1ab00000000001, // 2^((64* 5)-1) mod P(x),
     0x45000000b0000000,
     0x6b700000f5000000,
 ];
 
+// CRC-64/GO-ISO
+const WIDE_KEYS_000000000000001B_REFLECTED: [u64; 4] = [
+    0x7b61_0000_0000_0000,
+    0x4e0b_b000_0000_0000,
+    0x1545_1401_0000_0000,
+    0x1c1b_6dc1_b000_0000,
+];
+
 // CRC-64/NVME
 const KEYS_AD93D23594C93659_REFLECTED: [u64; 23] = [
-    0x0000000000000000, // unused placeholder to match 1-based indexing
+    0x0000000000000000, // unused placeholder to match 1-based indexing,
     0x21e9_761e_2526_21ac,
     0xeadc_41fd_2ba3_d420,
     0x5f85_2fb6_1e8d_92dc,
     0xa1ca_681e_733f_9c40,
     0x21e9_761e_2526_21ac,
     0x0000000000000000,
-    0x27ec_fa32_9aef_9f77, // mu
-    0x34d9_2653_5897_936b, // poly
+    0x27ec_fa32_9aef_9f77, // mu,
+    0x34d9_2653_5897_936b, // poly,
     0x9465_8840_3d4a_dcbc,
     0xd083_dd59_4d96_319d,
     0x34f5_a24e_22d6_6e90,
@@ -268,6 +307,28 @@ const KEYS_AD93D23594C93659_REFLECTED: [u64; 23] = [
     0x37cc_d3e1_4069_cabc,
 ];
 
+// CRC-64/NVME
+const WIDE_KEYS_AD93D23594C93659_REFLECTED: [u64; 4] = [
+    0xc240_9e25_37aa_5eb1,
+    0xd0b3_aa0e_d6d5_4ae0,
+    0xe095_04c2_e6dd_bb7f,
+    0x6f60_f540_1265_f28b,
+];
+
+/// Returns the precomputed 512 and 1024-byte folding keys for a predefined CRC-64 polynomial,
+/// in the same order as `generate::wide_keys()`.
+pub(crate) const fn wide_keys(poly: u64, reflected: bool) -> Option<[u64; 4]> {
+    match (poly, reflected) {
+        (0x259c84cba6426349, true) => Some(WIDE_KEYS_259C84CBA6426349_REFLECTED),
+        (0xad93d23594c935a9, true) => Some(WIDE_KEYS_AD93D23594C935A9_REFLECTED),
+        (0x42f0e1eba9ea3693, false) => Some(WIDE_KEYS_42F0E1EBA9EA3693_FORWARD),
+        (0x42f0e1eba9ea3693, true) => Some(WIDE_KEYS_42F0E1EBA9EA3693_REFLECTED),
+        (0x000000000000001b, true) => Some(WIDE_KEYS_000000000000001B_REFLECTED),
+        (0xad93d23594c93659, true) => Some(WIDE_KEYS_AD93D23594C93659_REFLECTED),
+        _ => None,
+    }
+}
+
 pub const SIMD_CONSTANTS: [[u64; 2]; 4] = [
     [0x08090a0b0c0d0e0f, 0x0001020304050607], // smask
     [0x8080808080808080, 0x8080808080808080], // mask1
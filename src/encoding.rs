@@ -0,0 +1,343 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides wire-format encoding of CRC results.
+
+use crate::{ByteOrder, CrcBytes};
+use core::fmt::{Debug, Formatter, LowerHex, UpperHex};
+use core::ops::Deref;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+impl CrcBytes {
+    /// Encodes a CRC checksum of the given width (32 or 64) in the given byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum, ByteOrder, CrcBytes, CrcAlgorithm::Crc64Nvme};
+    ///
+    /// let checksum = checksum(Crc64Nvme, b"123456789");
+    /// let bytes = CrcBytes::new(checksum, 64, ByteOrder::BigEndian);
+    ///
+    /// assert_eq!(format!("{bytes:x}"), "ae8b14860a799888");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` isn't 32 or 64.
+    pub fn new(checksum: u64, width: u8, order: ByteOrder) -> Self {
+        let len = match width {
+            32 | 64 => width as usize / 8,
+            _ => panic!("Unsupported width: {width}"),
+        };
+
+        let mut bytes = [0u8; 8];
+        match order {
+            ByteOrder::BigEndian => {
+                bytes[..len].copy_from_slice(&checksum.to_be_bytes()[8 - len..])
+            }
+            ByteOrder::LittleEndian => bytes[..len].copy_from_slice(&checksum.to_le_bytes()[..len]),
+        }
+
+        Self { bytes, len }
+    }
+
+    /// Formats the bytes, in order, as lowercase hex.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{ByteOrder, CrcBytes};
+    ///
+    /// let bytes = CrcBytes::new(0xcbf43926, 32, ByteOrder::LittleEndian);
+    ///
+    /// assert_eq!(bytes.to_hex(), "2639f4cb");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> String {
+        use core::fmt::Write;
+
+        let mut hex = String::with_capacity(self.len * 2);
+        write!(hex, "{self:x}").unwrap();
+
+        hex
+    }
+
+    /// Formats the bytes as standard, padded base64, as used by the `x-amz-checksum-*` headers
+    /// for big-endian CRCs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum, ByteOrder, CrcBytes, CrcAlgorithm::Crc32Iscsi};
+    ///
+    /// // x-amz-checksum-crc32c
+    /// let checksum = checksum(Crc32Iscsi, b"123456789");
+    /// let bytes = CrcBytes::new(checksum, 32, ByteOrder::BigEndian);
+    ///
+    /// assert_eq!(bytes.to_base64(), "4waSgw==");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_base64(&self) -> String {
+        let mut encoded = String::with_capacity(self.len.div_ceil(3) * 4);
+
+        for chunk in self.chunks(3) {
+            let mut block = [0u8; 3];
+            block[..chunk.len()].copy_from_slice(chunk);
+            let bits = u32::from_be_bytes([0, block[0], block[1], block[2]]);
+
+            // n input bytes produce n + 1 characters, padded to 4
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    let index = (bits >> (18 - 6 * i)) & 0x3f;
+                    encoded.push(BASE64_ALPHABET[index as usize] as char);
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+
+        encoded
+    }
+
+    /// Parses standard, padded base64 holding 4 or 8 bytes, as produced by `to_base64()`.
+    ///
+    /// Returns `None` if the input isn't valid base64, or doesn't decode to 4 or 8 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{ByteOrder, CrcBytes};
+    ///
+    /// let bytes = CrcBytes::from_base64("4waSgw==").unwrap();
+    ///
+    /// assert_eq!(bytes.to_checksum(ByteOrder::BigEndian), 0xe3069283);
+    /// ```
+    pub fn from_base64(encoded: &str) -> Option<Self> {
+        let encoded = encoded.as_bytes();
+        if encoded.len() % 4 != 0 {
+            return None;
+        }
+
+        let mut bytes = [0u8; 8];
+        let mut len = 0;
+
+        for (i, chunk) in encoded.chunks(4).enumerate() {
+            let last = i == encoded.len() / 4 - 1;
+            let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+
+            // Padding is only allowed at the end, and a block always holds at least one byte
+            if padding > 2 || (padding > 0 && !last) {
+                return None;
+            }
+
+            let mut bits = 0u32;
+            for &c in &chunk[..4 - padding] {
+                let value = BASE64_ALPHABET.iter().position(|&a| a == c)? as u32;
+                bits = (bits << 6) | value;
+            }
+            bits <<= 6 * padding as u32;
+
+            let decoded = 3 - padding;
+            if len + decoded > bytes.len() {
+                return None;
+            }
+            bytes[len..len + decoded].copy_from_slice(&bits.to_be_bytes()[1..1 + decoded]);
+            len += decoded;
+        }
+
+        match len {
+            4 | 8 => Some(Self { bytes, len }),
+            _ => None,
+        }
+    }
+
+    /// Decodes the bytes back into a CRC checksum, given their byte order.
+    pub fn to_checksum(&self, order: ByteOrder) -> u64 {
+        let mut bytes = [0u8; 8];
+
+        match order {
+            ByteOrder::BigEndian => {
+                bytes[8 - self.len..].copy_from_slice(self);
+                u64::from_be_bytes(bytes)
+            }
+            ByteOrder::LittleEndian => {
+                bytes[..self.len].copy_from_slice(self);
+                u64::from_le_bytes(bytes)
+            }
+        }
+    }
+}
+
+impl Deref for CrcBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl AsRef<[u8]> for CrcBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl Debug for CrcBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("CrcBytes").field(&self.deref()).finish()
+    }
+}
+
+impl LowerHex for CrcBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl UpperHex for CrcBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.iter().try_for_each(|byte| write!(f, "{byte:02X}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use crate::{checksum, Digest};
+
+    #[test]
+    fn test_byte_orders() {
+        for config in TEST_ALL_CONFIGS {
+            let check = config.get_check();
+            let width = config.get_width();
+            let len = width as usize / 8;
+
+            let big = CrcBytes::new(check, width, ByteOrder::BigEndian);
+            let little = CrcBytes::new(check, width, ByteOrder::LittleEndian);
+
+            assert_eq!(*big, check.to_be_bytes()[8 - len..]);
+            assert_eq!(*little, check.to_le_bytes()[..len]);
+            assert_eq!(big.to_hex(), format!("{check:0len$x}", len = len * 2));
+            assert_eq!(format!("{big:X}"), format!("{check:0len$X}", len = len * 2));
+
+            // finalize_bytes() agrees with DynDigest's big-endian finalize_into()
+            let mut digest = Digest::new(config.get_algorithm());
+            digest.update(crate::test::consts::TEST_CHECK_STRING);
+            assert_eq!(digest.finalize_bytes(ByteOrder::BigEndian), big);
+            assert_eq!(digest.finalize_bytes(ByteOrder::LittleEndian), little);
+
+            let mut out = vec![0u8; len];
+            digest::DynDigest::finalize_into(digest, &mut out).unwrap();
+            assert_eq!(out, *big);
+        }
+    }
+
+    #[test]
+    fn test_trailers() {
+        for config in TEST_ALL_CONFIGS {
+            let params = config.get_params();
+            let algorithm = config.get_algorithm();
+            let len = config.get_width() as usize / 8;
+
+            let mut digest = Digest::new(algorithm);
+            digest.update(b"123456789");
+
+            let mut frame = b"123456789".to_vec();
+            digest.append_to(&mut frame);
+            assert_eq!(frame.len(), 9 + len);
+
+            let mut buf = [0xaa; 12];
+            assert_eq!(digest.write_to(&mut buf), len);
+            assert_eq!(buf[..len], frame[9..]);
+            assert!(buf[len..].iter().all(|&byte| byte == 0xaa));
+
+            // in the conventional order, every message and its trailer have the same CRC (the
+            // residue)
+            let empty = CrcBytes::new(checksum(algorithm, &[]), params.width, params.byte_order());
+            assert_eq!(checksum(algorithm, &frame), checksum(algorithm, &empty));
+        }
+    }
+
+    #[test]
+    fn test_conventional_byte_orders() {
+        use crate::CrcAlgorithm::*;
+
+        let order = |algorithm| crate::get_calculator_params(algorithm).1.byte_order();
+
+        // Ethernet FCS and gzip
+        assert_eq!(order(Crc32IsoHdlc), ByteOrder::LittleEndian);
+        // MPEG-2 transport streams
+        assert_eq!(order(Crc32Mpeg2), ByteOrder::BigEndian);
+        assert_eq!(order(Crc64Nvme), ByteOrder::LittleEndian);
+        assert_eq!(order(Crc64Ecma182), ByteOrder::BigEndian);
+    }
+
+    #[test]
+    fn test_base64() {
+        // RFC 4648 test vectors, via 32-bit and 64-bit values
+        let bytes = CrcBytes::new(
+            u32::from_be_bytes(*b"foob") as u64,
+            32,
+            ByteOrder::BigEndian,
+        );
+        assert_eq!(bytes.to_base64(), "Zm9vYg==");
+
+        let bytes = CrcBytes::new(u64::from_be_bytes(*b"foobarfo"), 64, ByteOrder::BigEndian);
+        assert_eq!(bytes.to_base64(), "Zm9vYmFyZm8=");
+
+        let bytes = CrcBytes::new(0, 32, ByteOrder::BigEndian);
+        assert_eq!(bytes.to_base64(), "AAAAAA==");
+
+        // x-amz-checksum-crc64nvme
+        let bytes = CrcBytes::new(0xae8b14860a799888, 64, ByteOrder::BigEndian);
+        assert_eq!(bytes.to_base64(), "rosUhgp5mIg=");
+    }
+
+    #[test]
+    fn test_from_base64() {
+        for config in TEST_ALL_CONFIGS {
+            let check = config.get_check();
+            let width = config.get_width();
+
+            for order in [ByteOrder::BigEndian, ByteOrder::LittleEndian] {
+                let bytes = CrcBytes::new(check, width, order);
+                let decoded = CrcBytes::from_base64(&bytes.to_base64()).unwrap();
+
+                assert_eq!(decoded, bytes);
+                assert_eq!(decoded.to_checksum(order), check);
+            }
+        }
+
+        assert_eq!(
+            CrcBytes::from_base64("rosUhgp5mIg=")
+                .map(|bytes| bytes.to_checksum(ByteOrder::BigEndian)),
+            Some(0xae8b14860a799888)
+        );
+
+        // invalid characters, lengths and padding
+        for invalid in [
+            "",
+            "4waSgw=",
+            "4waS gw=",
+            "4w=Sgw==",
+            "4waSgw===",
+            "4waS",
+            "4waSgwAA",
+            "4waSgw==4waSgw==",
+        ] {
+            assert_eq!(CrcBytes::from_base64(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported width: 16")]
+    fn test_unsupported_width() {
+        CrcBytes::new(0, 16, ByteOrder::BigEndian);
+    }
+}
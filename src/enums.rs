@@ -5,6 +5,29 @@ use crate::CrcAlgorithm;
 use core::fmt::{Display, Formatter};
 use core::str::FromStr;
 
+/// Every predefined algorithm, excluding the custom placeholders.
+pub(crate) const CATALOGUE: [CrcAlgorithm; 19] = [
+    CrcAlgorithm::Crc32Aixm,
+    CrcAlgorithm::Crc32Autosar,
+    CrcAlgorithm::Crc32Base91D,
+    CrcAlgorithm::Crc32Bzip2,
+    CrcAlgorithm::Crc32CdRomEdc,
+    CrcAlgorithm::Crc32Cksum,
+    CrcAlgorithm::Crc32Iscsi,
+    CrcAlgorithm::Crc32IsoHdlc,
+    CrcAlgorithm::Crc32Jamcrc,
+    CrcAlgorithm::Crc32Mef,
+    CrcAlgorithm::Crc32Mpeg2,
+    CrcAlgorithm::Crc32Xfer,
+    CrcAlgorithm::Crc64Ecma182,
+    CrcAlgorithm::Crc64GoIso,
+    CrcAlgorithm::Crc64Ms,
+    CrcAlgorithm::Crc64Nvme,
+    CrcAlgorithm::Crc64Redis,
+    CrcAlgorithm::Crc64We,
+    CrcAlgorithm::Crc64Xz,
+];
+
 impl FromStr for CrcAlgorithm {
     type Err = ();
 
@@ -54,6 +54,7 @@ pub enum PerformanceTier {
     // x86_64 tiers
     X86_64Avx512Vpclmulqdq,
     X86_64Avx512Pclmulqdq,
+    X86_64Avx2Vpclmulqdq,
     X86_64SsePclmulqdq,
 
     // x86 tiers
@@ -76,8 +77,9 @@ pub struct ArchCapabilities {
     pub has_sse41: bool,
     pub has_sse42: bool, // provides native CRC32C instructions for fusion techniques
     pub has_pclmulqdq: bool,
+    pub has_avx2: bool, // required for VPCLMULQDQ on 256-bit registers when AVX-512 is unavailable
     pub has_avx512vl: bool, // implicitly enables avx512f, has XOR3 operations
-    pub has_vpclmulqdq: bool,
+    pub has_vpclmulqdq: bool, // requires 'avx512vl' or 'avx2'
 
     // Rust version gates
     pub rust_version_supports_avx512: bool,
@@ -99,6 +101,7 @@ fn tier_to_target_string(tier: PerformanceTier) -> String {
         PerformanceTier::AArch64Aes => "aarch64-neon-pmull".to_string(),
         PerformanceTier::X86_64Avx512Vpclmulqdq => "x86_64-avx512-vpclmulqdq".to_string(),
         PerformanceTier::X86_64Avx512Pclmulqdq => "x86_64-avx512-pclmulqdq".to_string(),
+        PerformanceTier::X86_64Avx2Vpclmulqdq => "x86_64-avx2-vpclmulqdq".to_string(),
         PerformanceTier::X86_64SsePclmulqdq => "x86_64-sse-pclmulqdq".to_string(),
         PerformanceTier::X86SsePclmulqdq => "x86-sse-pclmulqdq".to_string(),
         PerformanceTier::SoftwareTable => "software-fallback-tables".to_string(),
@@ -131,6 +134,7 @@ unsafe fn detect_arch_capabilities() -> ArchCapabilities {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -164,6 +168,7 @@ unsafe fn detect_aarch64_features() -> ArchCapabilities {
         has_sse41: false,
         has_sse42: false,
         has_pclmulqdq: false,
+        has_avx2: false,
         has_avx512vl: false,
         has_vpclmulqdq: false,
         rust_version_supports_avx512: false,
@@ -180,6 +185,7 @@ unsafe fn detect_aarch64_features() -> ArchCapabilities {
         has_sse41: false,
         has_sse42: false,
         has_pclmulqdq: false,
+        has_avx2: false,
         has_avx512vl: false,
         has_vpclmulqdq: false,
         rust_version_supports_avx512: false,
@@ -202,11 +208,15 @@ unsafe fn detect_x86_features() -> ArchCapabilities {
     // SSE 4.2 provides native CRC32C instructions for fusion techniques
     let has_sse42 = is_x86_feature_detected!("sse4.2");
 
+    // AVX2 is only useful in combination with VPCLMULQDQ, on CPUs without AVX-512
+    let has_avx2 = has_pclmulqdq && is_x86_feature_detected!("avx2");
+
     // After Rust 1.89, AVX-512VL and VPCLMULQDQ can be used if available
     let has_avx512vl =
         has_pclmulqdq && rust_version_supports_avx512 && is_x86_feature_detected!("avx512vl");
-    let has_vpclmulqdq =
-        has_avx512vl && rust_version_supports_avx512 && is_x86_feature_detected!("vpclmulqdq");
+    let has_vpclmulqdq = (has_avx512vl || has_avx2)
+        && rust_version_supports_avx512
+        && is_x86_feature_detected!("vpclmulqdq");
 
     ArchCapabilities {
         has_aes: false,
@@ -215,6 +225,7 @@ unsafe fn detect_x86_features() -> ArchCapabilities {
         has_sse41,
         has_sse42,
         has_pclmulqdq,
+        has_avx2,
         has_avx512vl,
         has_vpclmulqdq,
         rust_version_supports_avx512,
@@ -229,10 +240,12 @@ unsafe fn detect_x86_features() -> ArchCapabilities {
     let has_sse41 = cfg!(target_feature = "sse4.1");
     let has_sse42 = cfg!(target_feature = "sse4.2");
     let has_pclmulqdq = has_sse41 && cfg!(target_feature = "pclmulqdq");
+    let has_avx2 = has_pclmulqdq && cfg!(target_feature = "avx2");
     let has_avx512vl =
         has_pclmulqdq && rust_version_supports_avx512 && cfg!(target_feature = "avx512vl");
-    let has_vpclmulqdq =
-        has_avx512vl && rust_version_supports_avx512 && cfg!(target_feature = "vpclmulqdq");
+    let has_vpclmulqdq = (has_avx512vl || has_avx2)
+        && rust_version_supports_avx512
+        && cfg!(target_feature = "vpclmulqdq");
 
     ArchCapabilities {
         has_aes: false,
@@ -241,6 +254,7 @@ unsafe fn detect_x86_features() -> ArchCapabilities {
         has_sse41,
         has_sse42,
         has_pclmulqdq,
+        has_avx2,
         has_avx512vl,
         has_vpclmulqdq,
         rust_version_supports_avx512,
@@ -282,12 +296,15 @@ pub(crate) fn select_performance_tier(capabilities: &ArchCapabilities) -> Perfor
 
     #[cfg(target_arch = "x86_64")]
     {
-        if capabilities.has_vpclmulqdq {
+        if capabilities.has_vpclmulqdq && capabilities.has_avx512vl {
             return PerformanceTier::X86_64Avx512Vpclmulqdq;
         }
         if capabilities.has_avx512vl {
             return PerformanceTier::X86_64Avx512Pclmulqdq;
         }
+        if capabilities.has_vpclmulqdq && capabilities.has_avx2 {
+            return PerformanceTier::X86_64Avx2Vpclmulqdq;
+        }
         if capabilities.has_pclmulqdq {
             return PerformanceTier::X86_64SsePclmulqdq;
         }
@@ -320,6 +337,8 @@ pub enum ArchOpsInstance {
     X86_64Avx512Pclmulqdq(crate::arch::x86_64::avx512::X86_64Avx512PclmulqdqOps),
     #[cfg(target_arch = "x86_64")]
     X86_64Avx512Vpclmulqdq(crate::arch::x86_64::avx512_vpclmulqdq::X86_64Avx512VpclmulqdqOps),
+    #[cfg(target_arch = "x86_64")]
+    X86_64Avx2Vpclmulqdq(crate::arch::x86_64::avx2_vpclmulqdq::X86_64Avx2VpclmulqdqOps),
     /// Software fallback - no ArchOps struct needed
     SoftwareFallback,
 }
@@ -354,6 +373,8 @@ impl ArchOpsInstance {
             ArchOpsInstance::X86_64Avx512Pclmulqdq(_) => PerformanceTier::X86_64Avx512Pclmulqdq,
             #[cfg(target_arch = "x86_64")]
             ArchOpsInstance::X86_64Avx512Vpclmulqdq(_) => PerformanceTier::X86_64Avx512Vpclmulqdq,
+            #[cfg(target_arch = "x86_64")]
+            ArchOpsInstance::X86_64Avx2Vpclmulqdq(_) => PerformanceTier::X86_64Avx2Vpclmulqdq,
             ArchOpsInstance::SoftwareFallback => PerformanceTier::SoftwareTable,
         }
     }
@@ -389,7 +410,12 @@ impl ArchOpsInstance {
     feature = "std",
     any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
 ))]
+#[inline(always)]
 pub fn get_arch_ops() -> &'static ArchOpsInstance {
+    if let Some(ops) = &STATIC_ARCH_OPS {
+        return ops;
+    }
+
     ARCH_OPS_INSTANCE.get_or_init(create_arch_ops)
 }
 
@@ -397,7 +423,12 @@ pub fn get_arch_ops() -> &'static ArchOpsInstance {
     not(feature = "std"),
     any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
 ))]
+#[inline(always)]
 pub fn get_arch_ops() -> &'static ArchOpsInstance {
+    if let Some(ops) = &STATIC_ARCH_OPS {
+        return ops;
+    }
+
     ARCH_OPS_INSTANCE.call_once(create_arch_ops)
 }
 
@@ -440,6 +471,11 @@ fn create_arch_ops_from_tier(tier: PerformanceTier) -> ArchOpsInstance {
             use crate::arch::x86_64::avx512::X86_64Avx512PclmulqdqOps;
             ArchOpsInstance::X86_64Avx512Pclmulqdq(X86_64Avx512PclmulqdqOps::new())
         }
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64Avx2Vpclmulqdq => {
+            use crate::arch::x86_64::avx2_vpclmulqdq::X86_64Avx2VpclmulqdqOps;
+            ArchOpsInstance::X86_64Avx2Vpclmulqdq(X86_64Avx2VpclmulqdqOps::new())
+        }
         #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         PerformanceTier::X86_64SsePclmulqdq | PerformanceTier::X86SsePclmulqdq => {
             create_x86_sse_pclmulqdq_ops()
@@ -483,6 +519,11 @@ fn create_arch_ops_from_tier(tier: PerformanceTier) -> ArchOpsInstance {
             // AVX512 not available in older Rust versions, fall back to SSE
             create_x86_sse_pclmulqdq_ops()
         }
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64Avx2Vpclmulqdq => {
+            // VPCLMULQDQ not available in older Rust versions, fall back to SSE
+            create_x86_sse_pclmulqdq_ops()
+        }
         #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         PerformanceTier::X86_64SsePclmulqdq | PerformanceTier::X86SsePclmulqdq => {
             create_x86_sse_pclmulqdq_ops()
@@ -505,6 +546,71 @@ fn create_x86_sse_pclmulqdq_ops() -> ArchOpsInstance {
     use crate::arch::x86::sse::X86SsePclmulqdqOps;
     ArchOpsInstance::X86SsePclmulqdq(X86SsePclmulqdqOps)
 }
+/// ArchOps instance selected at compile time, when the enabled target features already guarantee
+/// the highest tier available on this architecture (e.g. `-C target-cpu=native` on a capable CPU)
+///
+/// This is a `const` rather than a `static` so its value is known wherever it's used: when it's
+/// `Some`, `get_arch_ops()` returns it without touching the runtime-detected instance, and the
+/// `ArchOpsInstance` matches in the hot paths fold away entirely, leaving direct calls into the
+/// selected implementation. Lower tiers still use runtime detection, because a binary built for
+/// them may run on a more capable CPU.
+#[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+const STATIC_ARCH_OPS: Option<ArchOpsInstance> = static_arch_ops();
+
+#[cfg(target_arch = "aarch64")]
+const fn static_arch_ops() -> Option<ArchOpsInstance> {
+    use crate::arch::aarch64::aes_sha3::Aarch64AesSha3Ops;
+
+    if cfg!(all(target_feature = "aes", target_feature = "sha3")) {
+        return Some(ArchOpsInstance::Aarch64AesSha3(Aarch64AesSha3Ops::new()));
+    }
+
+    None
+}
+
+#[cfg(target_arch = "x86_64")]
+#[rustversion::since(1.89)]
+const fn static_arch_ops() -> Option<ArchOpsInstance> {
+    use crate::arch::x86_64::avx512_vpclmulqdq::X86_64Avx512VpclmulqdqOps;
+
+    if cfg!(all(
+        target_feature = "sse4.1",
+        target_feature = "pclmulqdq",
+        target_feature = "avx512vl",
+        target_feature = "vpclmulqdq"
+    )) {
+        return Some(ArchOpsInstance::X86_64Avx512Vpclmulqdq(
+            X86_64Avx512VpclmulqdqOps::new(),
+        ));
+    }
+
+    None
+}
+
+/// Before Rust 1.89, SSE and PCLMULQDQ is the highest tier available on x86_64
+#[cfg(target_arch = "x86_64")]
+#[rustversion::before(1.89)]
+const fn static_arch_ops() -> Option<ArchOpsInstance> {
+    use crate::arch::x86::sse::X86SsePclmulqdqOps;
+
+    if cfg!(all(target_feature = "sse4.1", target_feature = "pclmulqdq")) {
+        return Some(ArchOpsInstance::X86SsePclmulqdq(X86SsePclmulqdqOps));
+    }
+
+    None
+}
+
+#[cfg(target_arch = "x86")]
+const fn static_arch_ops() -> Option<ArchOpsInstance> {
+    use crate::arch::x86::sse::X86SsePclmulqdqOps;
+
+    if cfg!(all(target_feature = "sse4.1", target_feature = "pclmulqdq")) {
+        return Some(ArchOpsInstance::X86SsePclmulqdq(X86SsePclmulqdqOps));
+    }
+
+    None
+}
+
 /// Test-specific tier selection that works across all architectures for comprehensive testing
 #[cfg(test)]
 pub fn select_performance_tier_for_test(capabilities: &ArchCapabilities) -> PerformanceTier {
@@ -533,6 +639,15 @@ pub fn select_performance_tier_for_test(capabilities: &ArchCapabilities) -> Perf
         return PerformanceTier::X86_64Avx512Pclmulqdq;
     }
 
+    // Without AVX512VL, VPCLMULQDQ requires AVX2 for 256-bit registers
+    if capabilities.has_vpclmulqdq
+        && capabilities.has_avx2
+        && capabilities.has_pclmulqdq
+        && capabilities.rust_version_supports_avx512
+    {
+        return PerformanceTier::X86_64Avx2Vpclmulqdq;
+    }
+
     // PCLMULQDQ requires SSE4.1
     if capabilities.has_pclmulqdq && capabilities.has_sse41 {
         return PerformanceTier::X86_64SsePclmulqdq;
@@ -567,6 +682,7 @@ mod tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -584,6 +700,7 @@ mod tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -601,6 +718,7 @@ mod tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -625,6 +743,7 @@ mod tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -641,6 +760,7 @@ mod tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -663,6 +783,7 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: true,
             has_avx512vl: true,
             has_vpclmulqdq: true,
             rust_version_supports_avx512: true,
@@ -680,6 +801,7 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: true,
             has_avx512vl: true,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: true,
@@ -697,6 +819,7 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: true,
             has_avx512vl: true,
             has_vpclmulqdq: true,
             rust_version_supports_avx512: false,
@@ -714,6 +837,7 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: true,
             has_avx512vl: true,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -723,6 +847,52 @@ mod tests {
             PerformanceTier::X86_64SsePclmulqdq
         );
 
+        // Test AVX2 + VPCLMULQDQ without AVX512 on Rust 1.89+
+        let capabilities_avx2_vpclmulqdq = ArchCapabilities {
+            has_aes: false,
+            has_crc: false,
+            has_sha3: false,
+            has_sse41: true,
+            has_sse42: false,
+            has_pclmulqdq: true,
+            has_avx2: true,
+            has_avx512vl: false,
+            has_vpclmulqdq: true,
+            rust_version_supports_avx512: true,
+        };
+        assert_eq!(
+            select_performance_tier_for_test(&capabilities_avx2_vpclmulqdq),
+            PerformanceTier::X86_64Avx2Vpclmulqdq
+        );
+
+        // Test AVX2 + VPCLMULQDQ without AVX512 on Rust < 1.89
+        let capabilities_avx2_vpclmulqdq = ArchCapabilities {
+            rust_version_supports_avx512: false,
+            ..capabilities_avx2_vpclmulqdq
+        };
+        assert_eq!(
+            select_performance_tier_for_test(&capabilities_avx2_vpclmulqdq),
+            PerformanceTier::X86_64SsePclmulqdq
+        );
+
+        // Test AVX2 without VPCLMULQDQ (AVX2 alone doesn't add a tier)
+        let capabilities_avx2_only = ArchCapabilities {
+            has_aes: false,
+            has_crc: false,
+            has_sha3: false,
+            has_sse41: true,
+            has_sse42: false,
+            has_pclmulqdq: true,
+            has_avx2: true,
+            has_avx512vl: false,
+            has_vpclmulqdq: false,
+            rust_version_supports_avx512: true,
+        };
+        assert_eq!(
+            select_performance_tier_for_test(&capabilities_avx2_only),
+            PerformanceTier::X86_64SsePclmulqdq
+        );
+
         // Test SSE + PCLMULQDQ (baseline tier)
         let capabilities_sse = ArchCapabilities {
             has_aes: false,
@@ -731,6 +901,7 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -748,6 +919,7 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -771,6 +943,7 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -789,6 +962,7 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: false,
             has_avx512vl: false, // No AVX512 on 32-bit x86
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -807,6 +981,7 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -821,8 +996,8 @@ mod tests {
     fn test_x86_feature_hierarchy() {
         // Test that x86 feature hierarchy is properly maintained
         // SSE4.1 is required for PCLMULQDQ
-        // AVX512VL requires PCLMULQDQ
-        // VPCLMULQDQ requires AVX512VL and Rust 1.89+
+        // AVX2 and AVX512VL require PCLMULQDQ
+        // VPCLMULQDQ requires AVX512VL or AVX2, and Rust 1.89+
 
         // Test feature dependencies are enforced
         let capabilities_full = ArchCapabilities {
@@ -832,6 +1007,7 @@ mod tests {
             has_sse41: true,
             has_sse42: false,
             has_pclmulqdq: true,
+            has_avx2: true,
             has_avx512vl: true,
             has_vpclmulqdq: true,
             rust_version_supports_avx512: true,
@@ -840,6 +1016,7 @@ mod tests {
         // All x86 features should be available when hierarchy is satisfied
         assert!(capabilities_full.has_sse41);
         assert!(capabilities_full.has_pclmulqdq);
+        assert!(capabilities_full.has_avx2);
         assert!(capabilities_full.has_avx512vl);
         assert!(capabilities_full.has_vpclmulqdq);
         assert!(capabilities_full.rust_version_supports_avx512);
@@ -847,6 +1024,7 @@ mod tests {
 
     #[test]
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[allow(clippy::bool_comparison)]
     fn test_rust_version_gating() {
         // Test that VPCLMULQDQ is properly gated by Rust version
         let rust_support = check_rust_version_supports_avx512();
@@ -856,6 +1034,18 @@ mod tests {
         assert!(rust_support == true || rust_support == false);
     }
 
+    #[test]
+    #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+    fn test_static_arch_ops_matches_runtime_detection() {
+        // When the target features guarantee the highest tier at compile time, the static
+        // instance must be the same one runtime detection would have selected
+        if let Some(ops) = &STATIC_ARCH_OPS {
+            assert_eq!(ops.get_tier(), create_arch_ops().get_tier());
+        }
+
+        assert_eq!(get_arch_ops().get_tier(), create_arch_ops().get_tier());
+    }
+
     // Mock tests for compile-time and runtime feature agreement scenarios
     mod mock_feature_agreement_tests {
         use super::*;
@@ -872,6 +1062,7 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: true,                // Hardware supports it
                 rust_version_supports_avx512: false, // But Rust version is too old
@@ -881,6 +1072,7 @@ mod tests {
             let tier = select_performance_tier_for_test(&capabilities_old_rust);
             assert_ne!(tier, PerformanceTier::X86_64Avx512Vpclmulqdq);
             assert_ne!(tier, PerformanceTier::X86_64Avx512Pclmulqdq);
+            assert_ne!(tier, PerformanceTier::X86_64Avx2Vpclmulqdq);
             assert_eq!(tier, PerformanceTier::X86_64SsePclmulqdq);
         }
 
@@ -896,6 +1088,7 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false,
@@ -907,7 +1100,7 @@ mod tests {
                 PerformanceTier::SoftwareTable
             );
 
-            // VPCLMULQDQ without AVX512VL should not be possible
+            // VPCLMULQDQ without AVX512VL or AVX2 should not be possible
             let invalid_vpclmul_caps = ArchCapabilities {
                 has_aes: false,
                 has_crc: false,
@@ -915,12 +1108,13 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: false,
                 has_avx512vl: false,  // Missing required dependency
                 has_vpclmulqdq: true, // This should be impossible in real detection
                 rust_version_supports_avx512: true,
             };
 
-            // Should fall back to SSE tier since AVX512VL is required for VPCLMULQDQ
+            // Should fall back to SSE tier since AVX512VL or AVX2 is required for VPCLMULQDQ
             assert_eq!(
                 select_performance_tier_for_test(&invalid_vpclmul_caps),
                 PerformanceTier::X86_64SsePclmulqdq
@@ -944,6 +1138,7 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false,
@@ -961,6 +1156,7 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false,
@@ -978,6 +1174,7 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false,
@@ -1000,6 +1197,7 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false,
@@ -1017,6 +1215,7 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false,
@@ -1034,6 +1233,7 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false,
@@ -1051,6 +1251,7 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false, // Old Rust version
@@ -1068,6 +1269,7 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: true, // New Rust version
@@ -1077,6 +1279,24 @@ mod tests {
                 PerformanceTier::X86_64Avx512Pclmulqdq
             );
 
+            // SSE4.1 + PCLMULQDQ + AVX2 + VPCLMULQDQ with new Rust - 256-bit VPCLMULQDQ tier
+            let avx2_vpclmul_new_rust = ArchCapabilities {
+                has_aes: false,
+                has_crc: false,
+                has_sha3: false,
+                has_sse41: true,
+                has_sse42: false,
+                has_pclmulqdq: true,
+                has_avx2: true,
+                has_avx512vl: false,
+                has_vpclmulqdq: true,
+                rust_version_supports_avx512: true, // New Rust version
+            };
+            assert_eq!(
+                select_performance_tier_for_test(&avx2_vpclmul_new_rust),
+                PerformanceTier::X86_64Avx2Vpclmulqdq
+            );
+
             // All features + old Rust - should fall back to SSE tier
             let all_features_old_rust = ArchCapabilities {
                 has_aes: false,
@@ -1085,6 +1305,7 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: true,
                 rust_version_supports_avx512: false, // Old Rust version
@@ -1102,6 +1323,7 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: true,
                 rust_version_supports_avx512: true, // New Rust version
@@ -1124,6 +1346,7 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false,
@@ -1142,6 +1365,7 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: false,
                 has_avx512vl: false, // AVX512 not available on 32-bit x86
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false,
@@ -1168,6 +1392,10 @@ mod tests {
                     PerformanceTier::X86_64Avx512Pclmulqdq,
                     "x86_64-avx512-pclmulqdq",
                 ),
+                (
+                    PerformanceTier::X86_64Avx2Vpclmulqdq,
+                    "x86_64-avx2-vpclmulqdq",
+                ),
                 (PerformanceTier::X86_64SsePclmulqdq, "x86_64-sse-pclmulqdq"),
                 (PerformanceTier::X86SsePclmulqdq, "x86-sse-pclmulqdq"),
                 (PerformanceTier::SoftwareTable, "software-fallback-tables"),
@@ -1195,6 +1423,7 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false,
@@ -1223,7 +1452,8 @@ mod tests {
 
         #[test]
         fn test_x86_64_degradation_path() {
-            // Test the degradation path for x86_64: VPCLMULQDQ -> AVX512 -> SSE -> Software
+            // Test the degradation path for x86_64:
+            // AVX512+VPCLMULQDQ -> AVX512 -> AVX2+VPCLMULQDQ -> SSE -> Software
 
             // Start with highest tier capabilities
             let mut capabilities = ArchCapabilities {
@@ -1233,6 +1463,7 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: true,
                 rust_version_supports_avx512: true,
@@ -1251,8 +1482,16 @@ mod tests {
                 PerformanceTier::X86_64Avx512Pclmulqdq
             );
 
-            // Remove AVX512VL - should degrade to SSE tier
+            // Remove AVX512VL but restore VPCLMULQDQ - should degrade to AVX2 tier
             capabilities.has_avx512vl = false;
+            capabilities.has_vpclmulqdq = true;
+            assert_eq!(
+                select_performance_tier_for_test(&capabilities),
+                PerformanceTier::X86_64Avx2Vpclmulqdq
+            );
+
+            // Remove AVX2 - should degrade to SSE tier
+            capabilities.has_avx2 = false;
             assert_eq!(
                 select_performance_tier_for_test(&capabilities),
                 PerformanceTier::X86_64SsePclmulqdq
@@ -1284,6 +1523,7 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: true,
                 rust_version_supports_avx512: false, // Old Rust version
@@ -1308,6 +1548,7 @@ mod tests {
                 has_sse41: false,
                 has_sse42: false,
                 has_pclmulqdq: false,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
                 rust_version_supports_avx512: false,
@@ -1318,7 +1559,7 @@ mod tests {
                 PerformanceTier::SoftwareTable
             );
 
-            // x86_64: VPCLMULQDQ available but AVX512VL not (impossible in real hardware)
+            // x86_64: VPCLMULQDQ available but AVX512VL and AVX2 not (impossible in real hardware)
             let x86_64_partial = ArchCapabilities {
                 has_aes: false,
                 has_crc: false,
@@ -1326,11 +1567,12 @@ mod tests {
                 has_sse41: true,
                 has_sse42: false,
                 has_pclmulqdq: true,
+                has_avx2: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: true, // This would be impossible in real detection
                 rust_version_supports_avx512: true,
             };
-            // Should fall back to SSE tier since AVX512VL is required for VPCLMULQDQ
+            // Should fall back to SSE tier since AVX512VL or AVX2 is required for VPCLMULQDQ
             assert_eq!(
                 select_performance_tier_for_test(&x86_64_partial),
                 PerformanceTier::X86_64SsePclmulqdq
@@ -1352,6 +1594,7 @@ mod software_fallback_tests {
             has_sse41: false,
             has_sse42: false,
             has_pclmulqdq: false,
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -1375,6 +1618,7 @@ mod software_fallback_tests {
             has_sse41: true, // SSE4.1 available
             has_sse42: false,
             has_pclmulqdq: false, // But PCLMULQDQ not available
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -1395,6 +1639,7 @@ mod software_fallback_tests {
             has_sse41: false, // No SSE4.1 support
             has_sse42: false,
             has_pclmulqdq: false, // PCLMULQDQ requires SSE4.1
+            has_avx2: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
             rust_version_supports_avx512: false,
@@ -0,0 +1,140 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides CRC forcing, which finds the patch bytes that make data hit a target CRC.
+//!
+//! A CRC is affine in its input, so the CRC with the patch in place is the CRC with the patch
+//! zeroed, XORed with the patch's own contribution. A `width`-bit patch `p(x)` followed by `n`
+//! more bytes contributes `p(x) * x^(width + 8n) mod P(x)`, which can be solved for `p(x)`
+//! directly by multiplying with the inverse of `x`, rather than solving the system bit by bit.
+
+use crate::gf2::{mul_mod, pow_mod, reflect, register_form};
+use crate::{CalculatorFn, CrcParams};
+
+/// Returns the `N` bytes which, written at `offset`, give `data` the CRC `target`. The bytes
+/// already at `offset` are ignored.
+pub(crate) fn patch<const N: usize>(
+    calculator: CalculatorFn,
+    params: CrcParams,
+    data: &[u8],
+    offset: usize,
+    target: u64,
+) -> [u8; N] {
+    let width = params.width;
+    assert_eq!(N * 8, width as usize, "Patch size must match the CRC width");
+    assert!(
+        offset.checked_add(N).is_some_and(|end| end <= data.len()),
+        "Patch must be within the data"
+    );
+    // Every catalogue polynomial has an x^0 term, without which x has no inverse
+    assert!(params.poly & 1 == 1, "Polynomial must have an x^0 term");
+
+    // The register with the patch zeroed
+    let state = calculator(params.init, &data[..offset], params);
+    let state = calculator(state, &[0u8; N], params);
+    let state = calculator(state, &data[offset + N..], params);
+
+    let mask = u64::MAX >> (64 - width);
+    let difference = (target ^ params.xorout ^ state) & mask;
+
+    // P(x) = x * Q(x) + 1, so x^-1 = Q(x) mod P(x)
+    let x_inverse = (params.poly >> 1) | (1 << (width - 1));
+    let trailing = (data.len() - offset - N) as u64;
+    let shift = pow_mod(x_inverse, width as u64 + 8 * trailing, params.poly, width);
+
+    let patch = mul_mod(register_form(difference, params), shift, params.poly, width);
+
+    // Reflected CRCs take each byte least significant bit first
+    let mut bytes = [0u8; N];
+    if params.refin {
+        bytes.copy_from_slice(&reflect(patch, width).to_le_bytes()[..N]);
+    } else {
+        bytes.copy_from_slice(&patch.to_be_bytes()[8 - N..]);
+    }
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use crate::test::enums::AnyCrcTestConfig;
+    use crate::{checksum_with_params, force_crc, force_crc_with_params, CrcAlgorithm};
+    use rand::{rng, Rng};
+
+    fn check<const N: usize>(config: &AnyCrcTestConfig, data: &mut [u8], offset: usize) {
+        let params = *config.get_params();
+        let mask = u64::MAX >> (64 - params.width);
+        let target = rng().random::<u64>() & mask;
+
+        let patch: [u8; N] = force_crc_with_params(params, data, offset, target);
+        data[offset..offset + N].copy_from_slice(&patch);
+
+        assert_eq!(
+            checksum_with_params(params, data),
+            target,
+            "{} offset {offset}",
+            config.get_name()
+        );
+    }
+
+    #[test]
+    fn test_force_crc_all_configs() {
+        let mut data = vec![0u8; 300];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            for offset in [0, 1, 15, 100, 292] {
+                match config.get_width() {
+                    32 => check::<4>(config, &mut data, offset),
+                    _ => check::<8>(config, &mut data, offset),
+                }
+            }
+
+            // the patch can be the whole message
+            match config.get_width() {
+                32 => check::<4>(config, &mut data[..4], 0),
+                _ => check::<8>(config, &mut data[..8], 0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_force_crc() {
+        let mut data = *b"123456789\0\0\0\0";
+
+        let patch: [u8; 4] = force_crc(CrcAlgorithm::Crc32IsoHdlc, &data, 9, 0xdeadbeef);
+        data[9..].copy_from_slice(&patch);
+        assert_eq!(
+            crate::checksum(CrcAlgorithm::Crc32IsoHdlc, &data),
+            0xdeadbeef
+        );
+
+        // the existing bytes make no difference
+        let patch_again: [u8; 4] = force_crc(CrcAlgorithm::Crc32IsoHdlc, &data, 9, 0xdeadbeef);
+        assert_eq!(patch_again, patch);
+
+        // appending the CRC itself as a trailer gives the catalogue's residue (before the final XOR)
+        let patch: [u8; 4] = force_crc(
+            CrcAlgorithm::Crc32IsoHdlc,
+            &data,
+            9,
+            0xdebb20e3 ^ 0xffffffff,
+        );
+        assert_eq!(
+            patch,
+            (crate::checksum(CrcAlgorithm::Crc32IsoHdlc, b"123456789") as u32).to_le_bytes()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Patch size must match the CRC width")]
+    fn test_wrong_patch_size() {
+        let _: [u8; 8] = force_crc(CrcAlgorithm::Crc32IsoHdlc, &[0u8; 16], 0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Patch must be within the data")]
+    fn test_patch_out_of_bounds() {
+        let _: [u8; 4] = force_crc(CrcAlgorithm::Crc32IsoHdlc, &[0u8; 16], 13, 0);
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! gzip header and trailer CRCs.
+//!
+//! A gzip member's trailer stores the CRC-32/ISO-HDLC and length (modulo 2^32) of the
+//! decompressed data, which the caller supplies. An optional header CRC, the low 16 bits of the
+//! CRC-32 of the header, is checked too.
+
+use super::{bytes, crc32, u16_le, u32_le, FormatError, FormatErrorKind, Report};
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+const FHCRC: u8 = 0x02;
+const FEXTRA: u8 = 0x04;
+const FNAME: u8 = 0x08;
+const FCOMMENT: u8 = 0x10;
+
+/// Verifies a single gzip member against its decompressed data.
+///
+/// Each mismatch's item is the member's stored file name, or `"member"` if it has none. For a
+/// file with several concatenated members, verify each member against its own decompressed data.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::formats::gzip;
+///
+/// // "123456789", compressed with gzip
+/// let member = b"\x1f\x8b\x08\x00\x00\x00\x00\x00\x02\xff\x33\x34\x32\x36\x31\x35\x33\xb7\xb0\x04\x00\
+///     \x26\x39\xf4\xcb\x09\x00\x00\x00";
+///
+/// assert!(gzip::verify(member, b"123456789").unwrap().is_ok());
+/// assert!(!gzip::verify(member, b"12345678").unwrap().is_ok());
+/// ```
+pub fn verify(member: &[u8], decompressed: &[u8]) -> Result<Report, FormatError> {
+    if !member.starts_with(b"\x1f\x8b") {
+        return Err(FormatError::new(0, FormatErrorKind::InvalidSignature));
+    }
+
+    let flags = bytes(member, 3, 1)?[0];
+    let mut offset = 10;
+    let mut name = None;
+
+    if flags & FEXTRA != 0 {
+        offset += 2 + u16_le(member, offset)? as usize;
+    }
+    if flags & FNAME != 0 {
+        let length = zero_terminated(member, offset)?;
+        name = Some(String::from_utf8_lossy(&member[offset..offset + length]));
+        offset += length + 1;
+    }
+    if flags & FCOMMENT != 0 {
+        offset += zero_terminated(member, offset)? + 1;
+    }
+
+    let item = name.unwrap_or("member".into());
+    let mut report = Report::default();
+
+    if flags & FHCRC != 0 {
+        let header = bytes(member, 0, offset)?;
+        let stored = u16_le(member, offset)?;
+
+        report.check(
+            item.clone(),
+            "header CRC",
+            offset,
+            stored as u64,
+            crc32(header) & 0xffff,
+        );
+        offset += 2;
+    }
+
+    // the compressed data needs at least one byte, followed by the 8-byte trailer
+    let trailer = member
+        .len()
+        .checked_sub(8)
+        .filter(|&trailer| trailer > offset)
+        .ok_or(FormatError::new(member.len(), FormatErrorKind::Truncated))?;
+
+    report.check(
+        item.clone(),
+        "trailer CRC",
+        trailer,
+        u32_le(member, trailer)? as u64,
+        crc32(decompressed),
+    );
+    report.check(
+        item,
+        "trailer size",
+        trailer + 4,
+        u32_le(member, trailer + 4)? as u64,
+        decompressed.len() as u32 as u64,
+    );
+
+    Ok(report)
+}
+
+/// Returns the length of the zero-terminated field at `offset`, excluding the terminator.
+fn zero_terminated(data: &[u8], offset: usize) -> Result<usize, FormatError> {
+    data.get(offset..)
+        .and_then(|field| field.iter().position(|&byte| byte == 0))
+        .ok_or(FormatError::new(offset, FormatErrorKind::Truncated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// "123456789", compressed by Python's gzip module with the file name "check.txt".
+    const MEMBER: &[u8] = b"\x1f\x8b\x08\x08\x00\x00\x00\x00\x02\xffcheck.txt\x00\
+        \x33\x34\x32\x36\x31\x35\x33\xb7\xb0\x04\x00\x26\x39\xf4\xcb\x09\x00\x00\x00";
+
+    /// Builds a member with every optional header field, and the data in a stored deflate block.
+    fn member_with_header_crc(data: &[u8]) -> Vec<u8> {
+        let mut member = b"\x1f\x8b\x08".to_vec();
+        member.push(FHCRC | FEXTRA | FNAME | FCOMMENT);
+        member.extend_from_slice(&[0, 0, 0, 0, 0, 0xff]);
+        member.extend_from_slice(b"\x03\x00abc");
+        member.extend_from_slice(b"name\x00comment\x00");
+
+        let header_crc = crc32(&member) as u16;
+        member.extend_from_slice(&header_crc.to_le_bytes());
+
+        member.push(0x01);
+        member.extend_from_slice(&(data.len() as u16).to_le_bytes());
+        member.extend_from_slice(&(!(data.len() as u16)).to_le_bytes());
+        member.extend_from_slice(data);
+
+        member.extend_from_slice(&(crc32(data) as u32).to_le_bytes());
+        member.extend_from_slice(&(data.len() as u32).to_le_bytes());
+
+        member
+    }
+
+    #[test]
+    fn test_verify() {
+        let report = verify(MEMBER, b"123456789").unwrap();
+
+        assert!(report.is_ok());
+        assert_eq!(report.checked, 2);
+
+        let report = verify(&member_with_header_crc(b"hello"), b"hello").unwrap();
+
+        assert!(report.is_ok());
+        assert_eq!(report.checked, 3);
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let report = verify(MEMBER, b"123456780").unwrap();
+
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].item, "check.txt");
+        assert_eq!(report.mismatches[0].field, "trailer CRC");
+        assert_eq!(report.mismatches[0].offset, MEMBER.len() - 8);
+        assert_eq!(report.mismatches[0].expected, 0xcbf43926);
+
+        let report = verify(MEMBER, b"12345678").unwrap();
+        assert_eq!(report.mismatches.len(), 2);
+        assert_eq!(report.mismatches[1].field, "trailer size");
+
+        // corrupt the file name, which the header CRC covers
+        let mut member = member_with_header_crc(b"hello");
+        member[15] ^= 1;
+        let report = verify(&member, b"hello").unwrap();
+
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].item, "oame");
+        assert_eq!(report.mismatches[0].field, "header CRC");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            verify(b"PK\x03\x04", b"").unwrap_err().kind,
+            FormatErrorKind::InvalidSignature
+        );
+        assert_eq!(
+            verify(&MEMBER[..15], b"").unwrap_err(),
+            FormatError::new(10, FormatErrorKind::Truncated)
+        );
+        assert_eq!(
+            verify(&MEMBER[..28], b"").unwrap_err().kind,
+            FormatErrorKind::Truncated
+        );
+    }
+}
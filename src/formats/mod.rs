@@ -0,0 +1,179 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Integrity verifiers for container formats which store CRCs this crate calculates: CRC-32/ISO-HDLC
+//! in gzip, zip and PNG, and CRC-64/XZ (or CRC-32) in xz.
+//!
+//! Each verifier parses just enough structure to find the stored CRCs, and checks as many as it
+//! can without decompressing. Where a CRC covers decompressed data, the caller supplies it.
+//! Structural problems, such as a bad signature or truncated data, are returned as a
+//! [`FormatError`], while CRCs which don't match are collected in the [`Report`], so one bad
+//! chunk doesn't hide the rest.
+//!
+//! Requires the `formats` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::formats::png;
+//! use crc_fast::{checksum, CrcAlgorithm::Crc32IsoHdlc};
+//!
+//! let mut image = b"\x89PNG\r\n\x1a\n".to_vec();
+//! image.extend_from_slice(&[0, 0, 0, 0]);
+//! image.extend_from_slice(b"IEND");
+//! image.extend_from_slice(&(checksum(Crc32IsoHdlc, b"IEND") as u32).to_be_bytes());
+//!
+//! let report = png::verify(&image).unwrap();
+//!
+//! assert!(report.is_ok());
+//! assert_eq!(report.checked, 1);
+//! ```
+
+#![cfg(feature = "formats")]
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+use core::fmt::{Display, Formatter};
+
+pub mod gzip;
+pub mod png;
+pub mod xz;
+pub mod zip;
+
+/// The results of verifying a file's CRCs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    /// The number of CRCs (and sizes) checked.
+    pub checked: usize,
+    /// The CRCs which didn't match.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl Report {
+    /// Returns true if every CRC checked matched.
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Records a check, and a mismatch if `expected` and `actual` differ.
+    fn check(
+        &mut self,
+        item: impl Into<String>,
+        field: &'static str,
+        offset: usize,
+        expected: u64,
+        actual: u64,
+    ) {
+        self.checked += 1;
+
+        if expected != actual {
+            self.mismatches.push(Mismatch {
+                item: item.into(),
+                field,
+                offset,
+                expected,
+                actual,
+            });
+        }
+    }
+}
+
+/// A CRC which didn't match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// What the CRC covers, such as a PNG chunk type, a zip entry's name, or an xz block.
+    pub item: String,
+    /// Which CRC didn't match, such as `"chunk CRC"` or `"local header CRC"`.
+    pub field: &'static str,
+    /// The offset of the stored value in the file.
+    pub offset: usize,
+    /// The stored value.
+    pub expected: u64,
+    /// The calculated value, or for cross-checks, the value stored elsewhere.
+    pub actual: u64,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} at offset {}: {} mismatch, expected 0x{:x}, got 0x{:x}",
+            self.item, self.offset, self.field, self.expected, self.actual
+        )
+    }
+}
+
+/// A structural problem which stopped verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatError {
+    /// The offset in the file where the problem was found.
+    pub offset: usize,
+    pub kind: FormatErrorKind,
+}
+
+/// The kinds of [`FormatError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatErrorKind {
+    /// The file doesn't start with (or contain) the format's signature.
+    InvalidSignature,
+    /// The file ends partway through a structure.
+    Truncated,
+    /// A structure is internally inconsistent, such as a size pointing outside the file.
+    Malformed,
+    /// The file uses a feature the verifier doesn't support, such as zip64 or multiple xz
+    /// streams.
+    Unsupported,
+}
+
+impl FormatError {
+    fn new(offset: usize, kind: FormatErrorKind) -> Self {
+        Self { offset, kind }
+    }
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let kind = match self.kind {
+            FormatErrorKind::InvalidSignature => "invalid signature",
+            FormatErrorKind::Truncated => "truncated",
+            FormatErrorKind::Malformed => "malformed",
+            FormatErrorKind::Unsupported => "unsupported",
+        };
+
+        write!(f, "{kind} at offset {}", self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FormatError {}
+
+/// Returns `len` bytes at `offset`, or a `Truncated` error at `offset` if the data is too short.
+fn bytes(data: &[u8], offset: usize, len: usize) -> Result<&[u8], FormatError> {
+    offset
+        .checked_add(len)
+        .and_then(|end| data.get(offset..end))
+        .ok_or(FormatError::new(offset, FormatErrorKind::Truncated))
+}
+
+fn u16_le(data: &[u8], offset: usize) -> Result<u16, FormatError> {
+    Ok(u16::from_le_bytes(
+        bytes(data, offset, 2)?.try_into().unwrap(),
+    ))
+}
+
+fn u32_le(data: &[u8], offset: usize) -> Result<u32, FormatError> {
+    Ok(u32::from_le_bytes(
+        bytes(data, offset, 4)?.try_into().unwrap(),
+    ))
+}
+
+fn u32_be(data: &[u8], offset: usize) -> Result<u32, FormatError> {
+    Ok(u32::from_be_bytes(
+        bytes(data, offset, 4)?.try_into().unwrap(),
+    ))
+}
+
+/// The CRC-32/ISO-HDLC used by gzip, zip, PNG and xz.
+fn crc32(data: &[u8]) -> u64 {
+    crate::checksum(crate::CrcAlgorithm::Crc32IsoHdlc, data)
+}
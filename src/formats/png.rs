@@ -0,0 +1,111 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! PNG chunk CRCs.
+//!
+//! Every chunk stores the CRC-32/ISO-HDLC of its type and data, big-endian, so the whole file can
+//! be checked without decompressing the image.
+
+use super::{bytes, crc32, u32_be, FormatError, FormatErrorKind, Report};
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Verifies the CRC of every chunk up to and including `IEND`.
+///
+/// Each mismatch's item is the chunk type, such as `"IDAT"`.
+pub fn verify(data: &[u8]) -> Result<Report, FormatError> {
+    if !data.starts_with(SIGNATURE) {
+        return Err(FormatError::new(0, FormatErrorKind::InvalidSignature));
+    }
+
+    let mut report = Report::default();
+    let mut offset = SIGNATURE.len();
+
+    loop {
+        let length = u32_be(data, offset)?;
+        if length > i32::MAX as u32 {
+            return Err(FormatError::new(offset, FormatErrorKind::Malformed));
+        }
+
+        // the CRC covers the chunk type and data, but not the length
+        let covered = bytes(data, offset + 4, 4 + length as usize)?;
+        let stored_offset = offset + 8 + length as usize;
+        let stored = u32_be(data, stored_offset)?;
+        let chunk_type = &covered[..4];
+
+        report.check(
+            String::from_utf8_lossy(chunk_type),
+            "chunk CRC",
+            stored_offset,
+            stored as u64,
+            crc32(covered),
+        );
+
+        if chunk_type == b"IEND" {
+            return Ok(report);
+        }
+        offset += 12 + length as usize;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 1x1 grayscale image, generated with zlib.
+    const IMAGE: &[u8] = b"\x89PNG\r\n\x1a\n\
+        \x00\x00\x00\x0dIHDR\x00\x00\x00\x01\x00\x00\x00\x01\x08\x00\x00\x00\x00\x3a\x7e\x9b\x55\
+        \x00\x00\x00\x0aIDAT\x78\x9c\x63\x60\x00\x00\x00\x02\x00\x01\x48\xaf\xa4\x71\
+        \x00\x00\x00\x00IEND\xae\x42\x60\x82";
+
+    #[test]
+    fn test_verify() {
+        let report = verify(IMAGE).unwrap();
+
+        assert!(report.is_ok());
+        assert_eq!(report.checked, 3);
+
+        // anything after IEND is ignored
+        let mut trailing = IMAGE.to_vec();
+        trailing.extend_from_slice(b"junk");
+        assert_eq!(verify(&trailing).unwrap().checked, 3);
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let mut image = IMAGE.to_vec();
+        image[41 + 2] ^= 1; // IDAT data
+        let length = image.len();
+        image[length - 1] ^= 1; // IEND CRC
+
+        let report = verify(&image).unwrap();
+
+        assert_eq!(report.checked, 3);
+        assert_eq!(report.mismatches.len(), 2);
+        assert_eq!(report.mismatches[0].item, "IDAT");
+        assert_eq!(report.mismatches[0].offset, 51);
+        assert_eq!(report.mismatches[1].item, "IEND");
+        assert_eq!(report.mismatches[1].expected, 0xae426083);
+        assert_eq!(report.mismatches[1].actual, 0xae426082);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            verify(b"GIF89a").unwrap_err().kind,
+            FormatErrorKind::InvalidSignature
+        );
+
+        // missing IEND
+        assert_eq!(
+            verify(&IMAGE[..IMAGE.len() - 12]).unwrap_err(),
+            FormatError::new(IMAGE.len() - 12, FormatErrorKind::Truncated)
+        );
+        assert_eq!(
+            verify(&IMAGE[..IMAGE.len() - 1]).unwrap_err().kind,
+            FormatErrorKind::Truncated
+        );
+    }
+}
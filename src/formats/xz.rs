@@ -0,0 +1,395 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! xz stream, block and index CRCs.
+//!
+//! The stream header and footer, each block header, and the index are covered by
+//! CRC-32/ISO-HDLC, and are always checked. Each block also ends with a check of its
+//! uncompressed data, usually CRC-64/XZ or CRC-32, which is checked when the caller supplies the
+//! decompressed blocks.
+
+use super::{bytes, crc32, u32_le, FormatError, FormatErrorKind, Report};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+const HEADER_MAGIC: &[u8] = b"\xfd7zXZ\x00";
+const FOOTER_MAGIC: &[u8] = b"YZ";
+
+const CHECK_CRC32: u8 = 0x01;
+const CHECK_CRC64: u8 = 0x04;
+
+/// Verifies the stream header, stream footer, block header and index CRCs of a single xz stream.
+///
+/// Block header mismatches' items are `"block {n}"`, counting from zero. Concatenated streams are
+/// `Unsupported`.
+pub fn verify(data: &[u8]) -> Result<Report, FormatError> {
+    verify_stream(data, None)
+}
+
+/// As [`verify`], and also checks each block's decompressed data against its stored size and
+/// check (CRC-32 or CRC-64/XZ; other check types are skipped).
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::formats::xz;
+///
+/// // "123456789", compressed with xz
+/// let stream = b"\xfd7zXZ\x00\x00\x04\xe6\xd6\xb4\x46\x02\x00\x21\x01\x16\x00\x00\x00\x74\x2f\xe5\xa3\
+///     \x01\x00\x08123456789\x00\x00\x00\x00\xfa\x39\x19\xdf\xbb\xc9\x5d\x99\
+///     \x00\x01\x21\x09\x6c\x18\xc5\xd5\x1f\xb6\xf3\x7d\x01\x00\x00\x00\x00\x04YZ";
+///
+/// assert!(xz::verify_with_blocks(stream, &[b"123456789"]).unwrap().is_ok());
+/// assert!(!xz::verify_with_blocks(stream, &[b"12345678"]).unwrap().is_ok());
+/// ```
+pub fn verify_with_blocks(data: &[u8], blocks: &[&[u8]]) -> Result<Report, FormatError> {
+    verify_stream(data, Some(blocks))
+}
+
+/// A block's index record.
+struct Record {
+    unpadded: usize,
+    uncompressed: u64,
+    uncompressed_offset: usize,
+}
+
+fn verify_stream(data: &[u8], blocks: Option<&[&[u8]]>) -> Result<Report, FormatError> {
+    if !data.starts_with(HEADER_MAGIC) {
+        return Err(FormatError::new(0, FormatErrorKind::InvalidSignature));
+    }
+
+    let mut report = Report::default();
+
+    let flags = bytes(data, 6, 2)?;
+    report.check(
+        "stream header",
+        "stream header CRC",
+        8,
+        u32_le(data, 8)? as u64,
+        crc32(flags),
+    );
+    if flags[0] != 0 || flags[1] & 0xf0 != 0 {
+        return Err(FormatError::new(6, FormatErrorKind::Unsupported));
+    }
+    let check_type = flags[1];
+
+    // streams may be followed by padding in multiples of four zero bytes
+    let mut end = data.len();
+    while end >= 4 && data[end - 4..end] == [0; 4] {
+        end -= 4;
+    }
+
+    let footer = end
+        .checked_sub(12)
+        .filter(|&footer| footer >= 12)
+        .ok_or(FormatError::new(end, FormatErrorKind::Truncated))?;
+    if &data[footer + 10..footer + 12] != FOOTER_MAGIC {
+        return Err(FormatError::new(
+            footer + 10,
+            FormatErrorKind::InvalidSignature,
+        ));
+    }
+
+    if &data[footer + 8..footer + 10] != flags {
+        return Err(FormatError::new(footer + 8, FormatErrorKind::Malformed));
+    }
+
+    // the stored size is (real size / 4) - 1, which can overflow a 32-bit usize when restored
+    let index = (u32_le(data, footer + 4)? as usize)
+        .checked_add(1)
+        .and_then(|size| size.checked_mul(4))
+        .and_then(|backward_size| footer.checked_sub(backward_size))
+        .filter(|&index| index >= 12)
+        .ok_or(FormatError::new(footer + 4, FormatErrorKind::Malformed))?;
+
+    let (records, index_crc) = read_index(data, index, footer)?;
+
+    // the blocks must fill the stream between the header and the index
+    let start = records
+        .iter()
+        .try_fold(12usize, |size, record| {
+            size.checked_add(record.unpadded.checked_next_multiple_of(4)?)
+        })
+        .and_then(|size| index.checked_sub(size));
+    if start != Some(0) {
+        return Err(match start {
+            Some(start) if data[start..].starts_with(HEADER_MAGIC) => {
+                FormatError::new(start, FormatErrorKind::Unsupported)
+            }
+            _ => FormatError::new(12, FormatErrorKind::Malformed),
+        });
+    }
+
+    let check_size = match check_type {
+        0 => 0,
+        _ => 4 << ((check_type - 1) / 3),
+    };
+
+    let mut offset = 12;
+    for (i, record) in records.iter().enumerate() {
+        let header_size = (data[offset] as usize + 1) * 4;
+        if data[offset] == 0 || record.unpadded <= header_size + check_size {
+            return Err(FormatError::new(offset, FormatErrorKind::Malformed));
+        }
+
+        let crc_offset = offset + header_size - 4;
+        report.check(
+            format!("block {i}"),
+            "block header CRC",
+            crc_offset,
+            u32_le(data, crc_offset)? as u64,
+            crc32(&data[offset..crc_offset]),
+        );
+
+        // the unpadded size excludes the padding between the compressed data and the check
+        offset += record.unpadded.next_multiple_of(4);
+        let check_offset = offset - check_size;
+
+        let Some(block) = blocks.and_then(|blocks| blocks.get(i)) else {
+            continue;
+        };
+
+        report.check(
+            format!("block {i}"),
+            "uncompressed size",
+            record.uncompressed_offset,
+            record.uncompressed,
+            block.len() as u64,
+        );
+
+        let stored = &data[check_offset..check_offset + check_size];
+        match check_type {
+            CHECK_CRC32 => report.check(
+                format!("block {i}"),
+                "block check",
+                check_offset,
+                u32::from_le_bytes(stored.try_into().unwrap()) as u64,
+                crc32(block),
+            ),
+            CHECK_CRC64 => report.check(
+                format!("block {i}"),
+                "block check",
+                check_offset,
+                u64::from_le_bytes(stored.try_into().unwrap()),
+                crate::checksum(crate::CrcAlgorithm::Crc64Xz, block),
+            ),
+            _ => {}
+        }
+    }
+
+    report.check(
+        "index",
+        "index CRC",
+        index_crc,
+        u32_le(data, index_crc)? as u64,
+        crc32(&data[index..index_crc]),
+    );
+
+    if let Some(blocks) = blocks {
+        report.check(
+            "index",
+            "block count",
+            index + 1,
+            records.len() as u64,
+            blocks.len() as u64,
+        );
+    }
+
+    report.check(
+        "stream footer",
+        "stream footer CRC",
+        footer,
+        u32_le(data, footer)? as u64,
+        crc32(&data[footer + 4..footer + 10]),
+    );
+
+    Ok(report)
+}
+
+/// Reads the index records, returning them with the offset of the index CRC.
+fn read_index(
+    data: &[u8],
+    index: usize,
+    footer: usize,
+) -> Result<(Vec<Record>, usize), FormatError> {
+    if data[index] != 0 {
+        return Err(FormatError::new(index, FormatErrorKind::Malformed));
+    }
+
+    let mut offset = index + 1;
+    let count = varint(data, &mut offset, footer)?;
+
+    let mut records = Vec::new();
+    for _ in 0..count {
+        let unpadded = varint(data, &mut offset, footer)?;
+        let uncompressed_offset = offset;
+        let uncompressed = varint(data, &mut offset, footer)?;
+
+        records.push(Record {
+            unpadded: usize::try_from(unpadded)
+                .map_err(|_| FormatError::new(offset, FormatErrorKind::Unsupported))?,
+            uncompressed,
+            uncompressed_offset,
+        });
+    }
+
+    let crc_offset = offset.next_multiple_of(4);
+    if crc_offset + 4 != footer || data[offset..crc_offset].iter().any(|&byte| byte != 0) {
+        return Err(FormatError::new(offset, FormatErrorKind::Malformed));
+    }
+
+    Ok((records, crc_offset))
+}
+
+/// Reads a variable-length integer of up to 9 bytes, which must end before `end`.
+fn varint(data: &[u8], offset: &mut usize, end: usize) -> Result<u64, FormatError> {
+    let mut value = 0;
+
+    for i in 0..9 {
+        if *offset >= end {
+            return Err(FormatError::new(*offset, FormatErrorKind::Malformed));
+        }
+
+        let byte = data[*offset];
+        *offset += 1;
+        value |= ((byte & 0x7f) as u64) << (7 * i);
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err(FormatError::new(*offset, FormatErrorKind::Malformed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// "123456789", compressed by Python's lzma module with a CRC-64 check.
+    const STREAM: &[u8] = b"\xfd7zXZ\x00\x00\x04\xe6\xd6\xb4\x46\
+        \x02\x00\x21\x01\x16\x00\x00\x00\x74\x2f\xe5\xa3\
+        \x01\x00\x08123456789\x00\x00\x00\x00\xfa\x39\x19\xdf\xbb\xc9\x5d\x99\
+        \x00\x01\x21\x09\x6c\x18\xc5\xd5\
+        \x1f\xb6\xf3\x7d\x01\x00\x00\x00\x00\x04YZ";
+
+    /// The same, with a CRC-32 check.
+    const STREAM_CRC32: &[u8] = b"\xfd7zXZ\x00\x00\x01\x69\x22\xde\x36\
+        \x02\x00\x21\x01\x16\x00\x00\x00\x74\x2f\xe5\xa3\
+        \x01\x00\x08123456789\x00\x00\x00\x00\x26\x39\xf4\xcb\
+        \x00\x01\x1d\x09\x93\x61\x36\xa6\
+        \x90\x42\x99\x0d\x01\x00\x00\x00\x00\x01YZ";
+
+    #[test]
+    fn test_verify() {
+        for stream in [STREAM, STREAM_CRC32] {
+            let report = verify(stream).unwrap();
+
+            assert!(report.is_ok());
+            assert_eq!(report.checked, 4);
+
+            let report = verify_with_blocks(stream, &[b"123456789"]).unwrap();
+
+            assert!(report.is_ok());
+            assert_eq!(report.checked, 7);
+        }
+
+        // stream padding is ignored
+        let mut padded = STREAM.to_vec();
+        padded.extend_from_slice(&[0; 8]);
+        assert!(verify(&padded).unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_block_mismatches() {
+        let report = verify_with_blocks(STREAM, &[b"123456780"]).unwrap();
+
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].item, "block 0");
+        assert_eq!(report.mismatches[0].field, "block check");
+        assert_eq!(report.mismatches[0].offset, 40);
+        assert_eq!(report.mismatches[0].expected, 0x995dc9bbdf1939fa);
+
+        let report = verify_with_blocks(STREAM_CRC32, &[b"12345678"]).unwrap();
+
+        assert_eq!(report.mismatches.len(), 2);
+        assert_eq!(report.mismatches[0].field, "uncompressed size");
+        assert_eq!(report.mismatches[0].offset, 47);
+        assert_eq!(report.mismatches[0].expected, 9);
+        assert_eq!(report.mismatches[0].actual, 8);
+        assert_eq!(report.mismatches[1].field, "block check");
+        assert_eq!(report.mismatches[1].expected, 0xcbf43926);
+
+        let report = verify_with_blocks(STREAM, &[b"123456789", b""]).unwrap();
+
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].item, "index");
+        assert_eq!(report.mismatches[0].field, "block count");
+        assert_eq!(report.mismatches[0].expected, 1);
+        assert_eq!(report.mismatches[0].actual, 2);
+    }
+
+    #[test]
+    fn test_header_mismatches() {
+        let mut stream = STREAM.to_vec();
+        stream[9] ^= 1; // stream header CRC
+        stream[14] ^= 1; // block header
+        stream[52] ^= 1; // index CRC
+
+        let report = verify(&stream).unwrap();
+
+        assert_eq!(report.checked, 4);
+        assert_eq!(report.mismatches.len(), 3);
+        assert_eq!(report.mismatches[0].item, "stream header");
+        assert_eq!(report.mismatches[0].offset, 8);
+        assert_eq!(report.mismatches[1].item, "block 0");
+        assert_eq!(report.mismatches[1].field, "block header CRC");
+        assert_eq!(report.mismatches[1].offset, 20);
+        assert_eq!(report.mismatches[2].item, "index");
+        assert_eq!(report.mismatches[2].field, "index CRC");
+        assert_eq!(report.mismatches[2].offset, 52);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            verify(b"\x1f\x8b").unwrap_err().kind,
+            FormatErrorKind::InvalidSignature
+        );
+        assert_eq!(
+            verify(&STREAM[..STREAM.len() - 1]).unwrap_err().kind,
+            FormatErrorKind::InvalidSignature
+        );
+        assert_eq!(
+            verify(&STREAM[..20]).unwrap_err().kind,
+            FormatErrorKind::Truncated
+        );
+
+        // footer flags which don't match the header
+        let mut stream = STREAM.to_vec();
+        let footer = stream.len() - 12;
+        stream[footer + 9] = 1;
+        assert_eq!(
+            verify(&stream).unwrap_err(),
+            FormatError::new(footer + 8, FormatErrorKind::Malformed)
+        );
+
+        // a backward size past the start of the stream, including one whose real size overflows
+        for backward_size in [0x100, u32::MAX] {
+            let mut stream = STREAM.to_vec();
+            stream[footer + 4..footer + 8].copy_from_slice(&backward_size.to_le_bytes());
+            assert_eq!(
+                verify(&stream).unwrap_err(),
+                FormatError::new(footer + 4, FormatErrorKind::Malformed)
+            );
+        }
+
+        // concatenated streams
+        let mut streams = STREAM.to_vec();
+        streams.extend_from_slice(STREAM);
+        assert_eq!(
+            verify(&streams).unwrap_err(),
+            FormatError::new(STREAM.len(), FormatErrorKind::Unsupported)
+        );
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! zip entry CRCs.
+//!
+//! Each entry's CRC-32/ISO-HDLC of its uncompressed data is stored twice: in the central
+//! directory, and in the entry's local header (or the data descriptor following its data). The
+//! two are cross-checked for every entry, and for stored (uncompressed, unencrypted) entries, the
+//! CRC is also checked against the data itself.
+
+use super::{bytes, crc32, u16_le, u32_le, FormatError, FormatErrorKind, Report};
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+const LOCAL_HEADER: u32 = 0x04034b50;
+const CENTRAL_HEADER: u32 = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x06054b50;
+const DATA_DESCRIPTOR: u32 = 0x08074b50;
+
+const ENCRYPTED: u16 = 0x0001;
+const HAS_DATA_DESCRIPTOR: u16 = 0x0008;
+const STORED: u16 = 0;
+
+/// Verifies the CRCs of every entry in a zip archive.
+///
+/// Each mismatch's item is the entry's name. Zip64 and multi-disk archives are `Unsupported`.
+pub fn verify(data: &[u8]) -> Result<Report, FormatError> {
+    let end = find_end_of_central_directory(data)?;
+
+    if u16_le(data, end + 4)? != 0 || u16_le(data, end + 6)? != 0 {
+        return Err(FormatError::new(end, FormatErrorKind::Unsupported));
+    }
+
+    let entries = u16_le(data, end + 10)?;
+    let directory = u32_le(data, end + 16)?;
+    if entries == u16::MAX || directory == u32::MAX {
+        return Err(FormatError::new(end, FormatErrorKind::Unsupported));
+    }
+
+    let mut report = Report::default();
+    let mut offset = directory as usize;
+
+    for _ in 0..entries {
+        if u32_le(data, offset)? != CENTRAL_HEADER {
+            return Err(FormatError::new(offset, FormatErrorKind::Malformed));
+        }
+
+        let flags = u16_le(data, offset + 8)?;
+        let method = u16_le(data, offset + 10)?;
+        let crc = u32_le(data, offset + 16)?;
+        let compressed = u32_le(data, offset + 20)?;
+        let name_length = u16_le(data, offset + 28)? as usize;
+        let extra_length = u16_le(data, offset + 30)? as usize;
+        let comment_length = u16_le(data, offset + 32)? as usize;
+        let local = u32_le(data, offset + 42)?;
+
+        if compressed == u32::MAX || local == u32::MAX {
+            return Err(FormatError::new(offset, FormatErrorKind::Unsupported));
+        }
+
+        let name = String::from_utf8_lossy(bytes(data, offset + 46, name_length)?);
+        let local = local as usize;
+
+        if u32_le(data, local)? != LOCAL_HEADER {
+            return Err(FormatError::new(local, FormatErrorKind::Malformed));
+        }
+
+        let start =
+            local + 30 + u16_le(data, local + 26)? as usize + u16_le(data, local + 28)? as usize;
+        let contents = bytes(data, start, compressed as usize)?;
+
+        // with a data descriptor, the local header's CRC is zero and the real one follows the data
+        let (local_crc, local_crc_offset) = if flags & HAS_DATA_DESCRIPTOR != 0 {
+            let descriptor = start + contents.len();
+            if u32_le(data, descriptor)? == DATA_DESCRIPTOR {
+                (u32_le(data, descriptor + 4)?, descriptor + 4)
+            } else {
+                (u32_le(data, descriptor)?, descriptor)
+            }
+        } else {
+            (u32_le(data, local + 14)?, local + 14)
+        };
+
+        report.check(
+            name.clone(),
+            "local header CRC",
+            local_crc_offset,
+            crc as u64,
+            local_crc as u64,
+        );
+
+        if method == STORED && flags & ENCRYPTED == 0 {
+            report.check(name, "data CRC", offset + 16, crc as u64, crc32(contents));
+        }
+
+        offset += 46 + name_length + extra_length + comment_length;
+    }
+
+    Ok(report)
+}
+
+/// Finds the end of central directory record, which is followed by a comment of up to 64 KiB.
+fn find_end_of_central_directory(data: &[u8]) -> Result<usize, FormatError> {
+    let last = data
+        .len()
+        .checked_sub(22)
+        .ok_or(FormatError::new(0, FormatErrorKind::InvalidSignature))?;
+
+    (last.saturating_sub(u16::MAX as usize)..=last)
+        .rev()
+        .find(|&offset| {
+            data[offset..offset + 4] == END_OF_CENTRAL_DIRECTORY.to_le_bytes()
+                && offset + 22 + u16_le(data, offset + 20).unwrap() as usize == data.len()
+        })
+        .ok_or(FormatError::new(last, FormatErrorKind::InvalidSignature))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// "123456789" stored as "check.txt", and "hello hello hello hello" deflated as "hello.txt",
+    /// written by Python's zipfile module.
+    const ARCHIVE: &[u8] = b"PK\x03\x04\x14\x00\x00\x00\x00\x00\x00\x00\x21\x00\x26\x39\xf4\xcb\
+        \x09\x00\x00\x00\x09\x00\x00\x00\x09\x00\x00\x00check.txt123456789\
+        PK\x03\x04\x14\x00\x00\x00\x08\x00\x00\x00\x21\x00\xe3\x51\x3d\x8d\
+        \x0a\x00\x00\x00\x17\x00\x00\x00\x09\x00\x00\x00hello.txt\
+        \xcb\x48\xcd\xc9\xc9\x57\xc8\x40\x27\x01\
+        PK\x01\x02\x14\x03\x14\x00\x00\x00\x00\x00\x00\x00\x21\x00\x26\x39\xf4\xcb\
+        \x09\x00\x00\x00\x09\x00\x00\x00\x09\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+        \x00\x00\x80\x01\x00\x00\x00\x00check.txt\
+        PK\x01\x02\x14\x03\x14\x00\x00\x00\x08\x00\x00\x00\x21\x00\xe3\x51\x3d\x8d\
+        \x0a\x00\x00\x00\x17\x00\x00\x00\x09\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+        \x00\x00\x80\x01\x30\x00\x00\x00hello.txt\
+        PK\x05\x06\x00\x00\x00\x00\x02\x00\x02\x00\x6e\x00\x00\x00\x61\x00\x00\x00\x00\x00";
+
+    /// Builds an archive of one stored entry whose CRC is in a data descriptor.
+    fn archive_with_data_descriptor(name: &[u8], contents: &[u8], signature: bool) -> Vec<u8> {
+        let crc = (crc32(contents) as u32).to_le_bytes();
+        let size = (contents.len() as u32).to_le_bytes();
+        let name_length = (name.len() as u16).to_le_bytes();
+
+        let mut archive = LOCAL_HEADER.to_le_bytes().to_vec();
+        archive.extend_from_slice(&[20, 0, 8, 0, 0, 0, 0, 0, 0, 0]);
+        archive.extend_from_slice(&[0; 12]);
+        archive.extend_from_slice(&name_length);
+        archive.extend_from_slice(&[0, 0]);
+        archive.extend_from_slice(name);
+        archive.extend_from_slice(contents);
+        if signature {
+            archive.extend_from_slice(&DATA_DESCRIPTOR.to_le_bytes());
+        }
+        archive.extend_from_slice(&crc);
+        archive.extend_from_slice(&size);
+        archive.extend_from_slice(&size);
+
+        let directory = archive.len();
+        archive.extend_from_slice(&CENTRAL_HEADER.to_le_bytes());
+        archive.extend_from_slice(&[20, 0, 20, 0, 8, 0, 0, 0, 0, 0, 0, 0]);
+        archive.extend_from_slice(&crc);
+        archive.extend_from_slice(&size);
+        archive.extend_from_slice(&size);
+        archive.extend_from_slice(&name_length);
+        archive.extend_from_slice(&[0; 16]);
+        archive.extend_from_slice(name);
+
+        let directory_size = archive.len() - directory;
+        archive.extend_from_slice(&END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+        archive.extend_from_slice(&[0, 0, 0, 0, 1, 0, 1, 0]);
+        archive.extend_from_slice(&(directory_size as u32).to_le_bytes());
+        archive.extend_from_slice(&(directory as u32).to_le_bytes());
+        archive.extend_from_slice(&[0, 0]);
+
+        archive
+    }
+
+    #[test]
+    fn test_verify() {
+        let report = verify(ARCHIVE).unwrap();
+
+        // both CRCs of the stored entry, and only the cross-check of the deflated one
+        assert!(report.is_ok());
+        assert_eq!(report.checked, 3);
+
+        for signature in [true, false] {
+            let archive = archive_with_data_descriptor(b"check.txt", b"123456789", signature);
+            let report = verify(&archive).unwrap();
+
+            assert!(report.is_ok());
+            assert_eq!(report.checked, 2);
+        }
+    }
+
+    #[test]
+    fn test_archive_comment() {
+        let mut archive = ARCHIVE.to_vec();
+        let length = archive.len();
+        archive[length - 2] = 5;
+        archive.extend_from_slice(b"PK\x05\x06!");
+
+        assert!(verify(&archive).unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let mut archive = ARCHIVE.to_vec();
+        archive[41] ^= 1; // check.txt data
+        archive[168] ^= 1; // hello.txt central directory CRC
+
+        let report = verify(&archive).unwrap();
+
+        assert_eq!(report.checked, 3);
+        assert_eq!(report.mismatches.len(), 2);
+        assert_eq!(report.mismatches[0].item, "check.txt");
+        assert_eq!(report.mismatches[0].field, "data CRC");
+        assert_eq!(report.mismatches[0].offset, 97 + 16);
+        assert_eq!(report.mismatches[0].expected, 0xcbf43926);
+        assert_eq!(report.mismatches[1].item, "hello.txt");
+        assert_eq!(report.mismatches[1].field, "local header CRC");
+        assert_eq!(report.mismatches[1].offset, 48 + 14);
+        assert_eq!(report.mismatches[1].expected, 0x8d3d51e2);
+        assert_eq!(report.mismatches[1].actual, 0x8d3d51e3);
+
+        let mut archive = archive_with_data_descriptor(b"check.txt", b"123456789", true);
+        archive[52] ^= 1; // the descriptor's CRC
+        let report = verify(&archive).unwrap();
+
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].field, "local header CRC");
+        assert_eq!(report.mismatches[0].offset, 52);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            verify(b"\x1f\x8b").unwrap_err().kind,
+            FormatErrorKind::InvalidSignature
+        );
+        assert_eq!(
+            verify(&ARCHIVE[..ARCHIVE.len() - 1]).unwrap_err().kind,
+            FormatErrorKind::InvalidSignature
+        );
+
+        // the central directory pointing at the wrong local header
+        let mut archive = ARCHIVE.to_vec();
+        archive[97 + 42] = 1;
+        assert_eq!(
+            verify(&archive).unwrap_err(),
+            FormatError::new(1, FormatErrorKind::Malformed)
+        );
+
+        // a second disk
+        let mut archive = ARCHIVE.to_vec();
+        archive[207 + 4] = 1;
+        assert_eq!(
+            verify(&archive).unwrap_err(),
+            FormatError::new(207, FormatErrorKind::Unsupported)
+        );
+    }
+}
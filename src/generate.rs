@@ -44,7 +44,9 @@
 //! - This key folds together data chunks that are 12 bytes apart
 //!
 //! The larger distances (32*63, 32*65) handle 256-byte chunks for very high throughput, such as
-//! using AVX-512 VPCLMULQDQ.
+//! using AVX-512 VPCLMULQDQ. The wide keys from `wide_keys()` extend this to 512 and 1024-byte
+//! chunks for kernels with many wide accumulators. There are no 2048-byte keys, since a kernel
+//! folding that far apart needs more accumulators than there are registers.
 
 //! # Why CRC-32 and CRC-64 Implementations Differ So Greatly
 //!
@@ -60,12 +62,12 @@
 //!
 //! ## 2. Register Representation
 //!
-//! - **CRC-32**: Uses u64 with values in upper bits (e.g., 0x080000000 = bit 35)
-//!   - Has "headroom" above 32 bits for intermediate calculations
-//!   - Results often need alignment shifts (>> 31)
+//! - **CRC-32**: 32-bit remainders sit in a u64 key
+//!   - Results need alignment shifts (<< 32, or << 1 when reflected)
+//!   - μ is 33 bits, so its leading 1 is kept
 //!
-//! - **CRC-64**: Uses u64 directly (e.g., 0x8000000000000000 = bit 63)
-//!   - No headroom - must use two u64s for μ calculation
+//! - **CRC-64**: Uses u64 directly
+//!   - μ is 65 bits, so one end of it is implicit
 //!   - Results are naturally aligned, no extra shifts needed
 //!
 //! ## 3. Distance Scaling
@@ -83,18 +85,15 @@
 //!
 //! ## 4. Algorithm Variations
 //!
-//! - **Key generation**: CRC-32 uses simple if-check; CRC-64 uses bit-mask trick
-//!   - Both compute x^n mod P(x), but CRC-64's approach is more efficient
+//! - **Key generation**: Both compute x^n mod P(x) with `gf2::x_pow_mod()`
+//!   - Reflected CRC-64 keys use x^(n-1), since the reflected product is shifted by one bit
 //!
-//! - **Mu calculation**: CRC-32 uses single u64; CRC-64 needs double-register
-//!   - CRC-64 can't fit x^128 ÷ P(x) in one u64
-//!
-//! - **Iteration counts**: Off-by-one differences everywhere
-//!   - Reflects different starting points and alignment requirements
+//! - **Mu calculation**: Both start from `gf2::barrett_mu()`
+//!   - CRC-32 keeps all 33 bits of μ; CRC-64 drops a bit to fit it in one u64
 //!
 //! ## 5. Reflection Handling
 //!
-//! - **CRC-32**: Reverses 32 bits, then shifts by 31
+//! - **CRC-32**: Reverses 32 bits (33 for μ), then shifts by 1
 //! - **CRC-64**: Reverses 64 bits, no additional shift
 //!
 //! Both create the reflected representation needed for LSB-first CRCs, but
@@ -109,7 +108,7 @@
 
 #![allow(dead_code)]
 
-use core::ops::{BitAnd, BitOr, Shl, Shr};
+use crate::gf2::{self, reflect};
 
 /// Exponents (bit distances) for CRC-32 key generation.
 ///
@@ -205,6 +204,24 @@ const CRC64_EXPONENTS: [u64; 23] = [
     64 * 33, // for 256 byte distances (2048 + 64)
 ];
 
+/// Exponents for the wide folding keys, covering 512 and 1024-byte distances with the same
+/// (distance - 32, distance + 32) pattern as indices 21-22 of CRC32_EXPONENTS.
+const CRC32_WIDE_EXPONENTS: [u64; 4] = [
+    32 * 127, // for 512 byte distances (4096 - 32)
+    32 * 129, // for 512 byte distances (4096 + 32)
+    32 * 255, // for 1024 byte distances (8192 - 32)
+    32 * 257, // for 1024 byte distances (8192 + 32)
+];
+
+/// Exponents for the wide folding keys, covering 512 and 1024-byte distances with the same
+/// (distance, distance + 64) pattern as indices 21-22 of CRC64_EXPONENTS.
+const CRC64_WIDE_EXPONENTS: [u64; 4] = [
+    64 * 64,  // for 512 byte distances (4096)
+    64 * 65,  // for 512 byte distances (4096 + 64)
+    64 * 128, // for 1024 byte distances (8192)
+    64 * 129, // for 1024 byte distances (8192 + 64)
+];
+
 /// Generates the 23 keys needed to calculate CRCs for a given polynomial using PCLMULQDQ when
 /// folding by 8.
 pub fn keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
@@ -234,6 +251,20 @@ pub fn keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
     keys
 }
 
+/// Generates the 4 keys used to fold across 512 and 1024-byte distances on the AVX-512
+/// VPCLMULQDQ tier.
+///
+/// These aren't part of `CrcKeysStorage`, so they're looked up separately for each `CrcParams`.
+pub(crate) fn wide_keys(width: u8, poly: u64, reflected: bool) -> [u64; 4] {
+    let (exponents, poly) = match width {
+        32 => (CRC32_WIDE_EXPONENTS, poly | (1u64 << 32)),
+        64 => (CRC64_WIDE_EXPONENTS, poly),
+        _ => panic!("Unsupported width: {width}",),
+    };
+
+    exponents.map(|exponent| key(width, poly, reflected, exponent))
+}
+
 fn key(width: u8, poly: u64, reflected: bool, exponent: u64) -> u64 {
     if width == 32 {
         crc32_key(exponent, reflected, poly)
@@ -248,45 +279,27 @@ fn key(width: u8, poly: u64, reflected: bool, exponent: u64) -> u64 {
 ///
 /// # Algorithm
 ///
-/// This calculates x^exponent mod P(x) where P(x) is the CRC-32 polynomial.
-///
-/// 1. Start with x^32 (represented as 0x080000000, bit 35 set)
-/// 2. Multiply by x repeatedly (left shift), reducing modulo P(x) each time
-/// 3. After (exponent - 31) iterations, we have x^exponent mod P(x)
-///
-/// # Why Start at x^32?
+/// This is x^exponent mod P(x) where P(x) is the CRC-32 polynomial, calculated with
+/// `gf2::x_pow_mod()`.
 ///
-/// For CRC-32, we're working with 33-bit polynomials (32 data bits + leading 1).
-/// Starting at x^32 (bit 35 in a 36-bit representation) gives us the right alignment
-/// for the first reduction step.
+/// # Alignment
 ///
-/// # Why exponent - 31?
-///
-/// We start at x^32 and want to reach x^exponent, so we need (exponent - 32) more
-/// multiplications. But the first shift happens before the first check, so it's
-/// actually (exponent - 31) iterations.
+/// For non-reflected CRCs, the 32-bit remainder is shifted into the upper half of the 64-bit key
+/// so it lines up with the data lanes for PCLMULQDQ.
 ///
 /// # Reflection
 ///
-/// If the CRC is reflected (LSB-first), we bit-reverse the result and shift right
-/// by 31 bits to align it properly for PCLMULQDQ operations.
+/// If the CRC is reflected (LSB-first), we bit-reverse the remainder and shift left by 1 bit to
+/// align it properly for PCLMULQDQ operations.
 fn crc32_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
     if exponent < 32 {
         return 0;
     }
 
-    let mut n: u64 = 0x080000000;
-    let e = exponent - 31;
-
-    for _ in 0..e {
-        n <<= 1;
-        if (n & 0x100000000) != 0 {
-            n ^= polynomial;
-        }
-    }
+    let n = gf2::x_pow_mod(exponent, polynomial & 0xffffffff, 32);
 
     if reflected {
-        bit_reverse(n) >> 31
+        reflect(n, 32) << 1
     } else {
         n << 32
     }
@@ -296,50 +309,27 @@ fn crc32_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
 ///
 /// # Algorithm
 ///
-/// Similar to CRC-32 but adapted for 64-bit polynomials:
-///
-/// 1. Start with x^63 (represented as 0x8000000000000000, bit 63 set)
-/// 2. Multiply by x repeatedly, reducing modulo P(x) each time
-/// 3. After (exponent - 63 or - 64) iterations, we have x^exponent mod P(x)
+/// Similar to CRC-32, this is x^exponent mod P(x) calculated with `gf2::x_pow_mod()`, adapted
+/// for 64-bit polynomials.
 ///
 /// # Key Differences From CRC-32
 ///
-/// 1. **Starting point**: 0x8000000000000000 vs 0x080000000
-///    - CRC-64 uses bit 63 (the MSB of a 64-bit value)
-///    - CRC-32 uses bit 35 (extended beyond 32 bits)
-///
-/// 2. **Iteration count**: Depends on reflection
-///    - Reflected: exponent - 64 (because we iterate from x^64)
-///    - Non-reflected: exponent - 63 (because we start at x^63)
-///
-/// 3. **Reduction step**: Uses a more efficient formulation
-///    - `(n << 1) ^ ((0_u64.wrapping_sub(n >> 63)) & polynomial)`
-///    - This is equivalent to: if MSB is set, shift and XOR with poly; else just shift
-///    - The wrapping_sub creates a mask of all 1s or all 0s based on the MSB
+/// 1. **Exponent**: Depends on reflection
+///    - Reflected: x^(exponent - 1), since the reflected product is already shifted by one bit
+///    - Non-reflected: x^exponent
 ///
-/// 4. **No additional shift in result**: The result is already properly aligned
-///    - CRC-32 needs `>> 31` adjustment for reflected case
+/// 2. **No additional shift in result**: The result is already properly aligned
+///    - CRC-32 needs a shift to position its 32-bit remainder
 ///    - CRC-64 doesn't need this because it operates on full 64-bit values
 fn crc64_key(exponent: u64, reflected: bool, polynomial: u64) -> u64 {
     if exponent <= 64 {
         return 0;
     }
 
-    let mut n: u64 = 0x8000000000000000;
-    let e = if reflected {
-        exponent - 64
-    } else {
-        exponent - 63
-    };
-
-    for _ in 0..e {
-        n = (n << 1) ^ ((0_u64.wrapping_sub(n >> 63)) & polynomial);
-    }
-
     if reflected {
-        bit_reverse(n)
+        reflect(gf2::x_pow_mod(exponent - 1, polynomial, 64), 64)
     } else {
-        n
+        gf2::x_pow_mod(exponent, polynomial, 64)
     }
 }
 
@@ -386,9 +376,9 @@ fn crc32_polynomial(polynomial: u64, reflected: bool) -> u64 {
     };
 
     // For 32-bit polynomials, operate on full 33 bits including leading 1
-    let reversed = bit_reverse((polynomial & 0xFFFFFFFF) as u32);
+    let reversed = reflect(polynomial, 32);
     // Need to set bit 32 (33rd bit) to get the 1 in the right position after reflection
-    ((reversed as u64) << 1) | 1
+    (reversed << 1) | 1
 }
 
 /// Formats a CRC-64 polynomial for use in PCLMULQDQ operations.
@@ -413,7 +403,7 @@ fn crc64_polynomial(polynomial: u64, reflected: bool) -> u64 {
     };
 
     // For 64-bit polynomials, operate on all 64 bits
-    (bit_reverse(polynomial) << 1) | 1
+    (reflect(polynomial, 64) << 1) | 1
 }
 
 fn mu(width: u8, polynomial: u64, reflected: bool) -> u64 {
@@ -435,36 +425,17 @@ fn mu(width: u8, polynomial: u64, reflected: bool) -> u64 {
 ///
 /// # Algorithm
 ///
-/// This performs polynomial long division:
-/// 1. Start with x^64 (represented as 0x100000000, bit 32 set in a 65-bit view)
-/// 2. For each bit position from MSB down:
-///    - If the dividend has a bit at position 32, record a 1 in quotient
-///    - XOR the dividend with the polynomial (subtract in GF(2))
-///    - Shift dividend left
-/// 3. After 33 iterations, q contains μ
-///
-/// # Why 33 Iterations?
-///
-/// We're dividing a 65-bit value (x^64) by a 33-bit polynomial, giving a 33-bit quotient.
+/// `gf2::barrett_mu()` returns the lower 32 bits of the 33-bit quotient, so the implicit leading
+/// x^32 term is added back.
 ///
 /// # Reflection
 ///
-/// If reflected, the result is bit-reversed and shifted right by 31 to align properly.
+/// If reflected, all 33 bits of the result are bit-reversed.
 fn crc32_mu(polynomial: u64, reflected: bool) -> u64 {
-    let mut n: u64 = 0x100000000;
-    let mut q: u64 = 0;
-
-    for _ in 0..33 {
-        q <<= 1;
-        if n & 0x100000000 != 0 {
-            q |= 1;
-            n ^= polynomial;
-        }
-        n <<= 1;
-    }
+    let q = gf2::barrett_mu(polynomial & 0xffffffff, 32) | (1u64 << 32);
 
     if reflected {
-        bit_reverse(q) >> 31
+        reflect(q, 33)
     } else {
         q
     }
@@ -474,109 +445,24 @@ fn crc32_mu(polynomial: u64, reflected: bool) -> u64 {
 ///
 /// # Key Differences From CRC-32
 ///
-/// CRC-64 requires dividing a 129-bit value (x^128) by a 65-bit polynomial, which
-/// doesn't fit in a single u64. This implementation uses two u64 variables to represent
-/// a 128-bit dividend.
-///
-/// # Algorithm
+/// CRC-64's quotient floor(x^128 / P(x)) is 65 bits, which doesn't fit in a single u64.
 ///
-/// 1. Start with x^128 represented as (n_hi=1, n_lo=0)
-/// 2. For each bit position:
-///    - If n_hi is non-zero, record 1 in quotient and XOR n_lo with polynomial
-///    - Shift the double-register left: n_hi = n_lo >> 63; n_lo <<= 1
-/// 3. After 64 or 65 iterations, q contains μ
+/// - Non-reflected: the implicit leading x^64 term is dropped, leaving the 64 bits from
+///   `gf2::barrett_mu()`
+/// - Reflected: the lowest bit is dropped instead, and the remaining 64 bits are bit-reversed
 ///
-/// # Why Two Registers?
-///
-/// - n_hi:n_lo forms a 128-bit value
-/// - We can only XOR with the polynomial when "bit 64" is set (n_hi != 0)
-/// - The shift operation propagates the MSB of n_lo into n_hi
-///
-/// # Iteration Count Difference
-///
-/// - Reflected: 64 iterations (matches the polynomial degree)
-/// - Non-reflected: 65 iterations (one extra for proper alignment)
-///
-/// This asymmetry exists because reflected CRCs process data LSB-first,
-/// changing the division mechanics slightly.
-///
-/// # No Additional Shift
-///
-/// Unlike CRC-32's `>> 31`, CRC-64 doesn't need an extra shift in the reflected
-/// case because the 64-bit result is already properly aligned.
+/// This asymmetry exists because reflected CRCs process data LSB-first, changing which end of
+/// the quotient is implicit.
 fn crc64_mu(polynomial: u64, reflected: bool) -> u64 {
-    let mut n_hi: u64 = 0x0000000000000001;
-    let mut n_lo: u64 = 0x0000000000000000;
-    let mut q: u64 = 0;
-
-    let max = if reflected { 64 } else { 65 };
-
-    for _ in 0..max {
-        q <<= 1;
-        if n_hi != 0 {
-            q |= 1;
-            n_lo ^= polynomial;
-        }
-        n_hi = n_lo >> 63;
-        n_lo <<= 1;
-    }
+    let mu = gf2::barrett_mu(polynomial, 64);
 
     if reflected {
-        bit_reverse(q)
+        reflect((1u64 << 63) | (mu >> 1), 64)
     } else {
-        q
+        mu
     }
 }
 
-/// Reverses the bits of a value (MSB becomes LSB and vice versa).
-///
-/// # Purpose in CRC Calculations
-///
-/// Reflected (LSB-first) CRCs process data in reverse bit order. This function
-/// converts between normal and reflected representations.
-///
-/// # Why Generic?
-///
-/// This works with any unsigned integer type (u32, u64) through trait bounds,
-/// allowing the same code to handle both CRC-32 (which reverses 32 bits) and
-/// CRC-64 (which reverses 64 bits).
-///
-/// # Example
-///
-/// For u32: 0x12345678 → 0x1E6A2C48
-/// - Binary: 0001 0010 0011 0100 0101 0110 0111 1000
-/// - Reversed: 0001 1110 0110 1010 0010 1100 0100 1000
-fn bit_reverse<T>(mut value: T) -> T
-where
-    T: Copy
-        + Default
-        + PartialEq
-        + BitAnd<Output = T>
-        + BitOr<Output = T>
-        + Shl<usize, Output = T>
-        + Shr<usize, Output = T>
-        + From<u8>,
-{
-    let one = T::from(1u8);
-    let mut result = T::default(); // Zero value
-
-    // Get the bit size of type T
-    let bit_size = core::mem::size_of::<T>() * 8;
-
-    for _ in 0..bit_size {
-        // Shift result left by 1
-        result = result << 1;
-
-        // OR with least significant bit of value
-        result = result | (value & one);
-
-        // Shift value right by 1
-        value = value >> 1;
-    }
-
-    result
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -601,4 +487,23 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn test_wide_keys() {
+        for config in TEST_ALL_CONFIGS {
+            let (width, poly, reflected) =
+                (config.get_width(), config.get_poly(), config.get_refin());
+
+            let expected = match width {
+                32 => crate::crc32::consts::wide_keys(poly, reflected),
+                _ => crate::crc64::consts::wide_keys(poly, reflected),
+            };
+
+            assert_eq!(
+                Some(wide_keys(width, poly, reflected)),
+                expected,
+                "Mismatch in wide keys for {}",
+                config.get_name()
+            );
+        }
+    }
 }
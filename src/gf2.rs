@@ -0,0 +1,881 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Polynomial arithmetic over GF(2), the field CRCs are built on.
+//!
+//! These are the same routines the crate uses to generate its folding keys and combine CRCs,
+//! exposed for protocol design work such as choosing polynomials or precomputing constants.
+//!
+//! # Representation
+//!
+//! A polynomial is an integer whose bit `i` is the coefficient of `x^i`, so `0b1011` is
+//! `x^3 + x + 1`. Addition (and subtraction) is XOR.
+//!
+//! A modulus `P(x)` is given the same way [`CrcParams`](crate::CrcParams) gives a CRC polynomial:
+//! its `width` (degree, 1 to 64) and `poly`, the lower `width` bits without the implicit leading
+//! `x^width` term. CRC-32's `(32, 0x04c11db7)` is `x^32 + x^26 + ... + x + 1`. Results reduced
+//! modulo `P(x)` are `width`-bit values.
+//!
+//! Polynomials are in normal (MSB-first) form throughout, so the polynomials of reflected CRCs
+//! need no conversion. [`reflect`] converts to and from the bit-reversed form reflected CRC
+//! implementations use internally.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::gf2;
+//!
+//! // (x + 1)^2 = x^2 + 1
+//! assert_eq!(gf2::clmul(0b11, 0b11), 0b101);
+//!
+//! // x^32 wraps around to the rest of CRC-32's polynomial
+//! assert_eq!(gf2::x_pow_mod(32, 0x04c11db7, 32), 0x04c11db7);
+//!
+//! // CRC-32's polynomial is primitive, while CRC-32C's is divisible by (x + 1)
+//! assert!(gf2::is_primitive(0x04c11db7, 32));
+//! assert!(!gf2::is_irreducible(0x1edc6f41, 32));
+//!
+//! // the reflected form used by LSB-first implementations
+//! assert_eq!(gf2::reflect(0x04c11db7, 32), 0xedb88320);
+//! ```
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+use crate::traits::ArchOps;
+use crate::CrcParams;
+
+/// Carry-less multiplication of two 64-bit polynomials, returning the full 127-bit product.
+///
+/// Uses PCLMULQDQ on x86/x86_64 and PMULL on AArch64 where available.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::gf2::clmul;
+///
+/// // (x^2 + x) * (x + 1) = x^3 + x
+/// assert_eq!(clmul(0b110, 0b11), 0b1010);
+///
+/// assert_eq!(clmul(1 << 63, 1 << 63), 1 << 126);
+/// ```
+#[inline]
+pub fn clmul(a: u64, b: u64) -> u128 {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+    {
+        use crate::feature_detection::{get_arch_ops, ArchOpsInstance};
+
+        // Every SIMD tier requires carry-less multiplication
+        if !matches!(get_arch_ops(), ArchOpsInstance::SoftwareFallback) {
+            return unsafe { clmul_simd(a, b) };
+        }
+    }
+
+    clmul_software(a, b)
+}
+
+/// Reduces a polynomial modulo `P(x)`, returning the `width`-bit remainder.
+///
+/// # Panics
+///
+/// Panics if `width` isn't between 1 and 64.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::gf2::reduce;
+///
+/// // x^4 mod (x^3 + x + 1) = x^2 + x
+/// assert_eq!(reduce(0b10000, 0b011, 3), 0b110);
+///
+/// // values below the modulus are unchanged
+/// assert_eq!(reduce(0xff, 0x04c11db7, 32), 0xff);
+/// ```
+pub fn reduce(value: u128, poly: u64, width: u8) -> u64 {
+    Modulus::new(poly, width).reduce(value)
+}
+
+/// Multiplies two polynomials modulo `P(x)`, returning the `width`-bit result.
+///
+/// # Panics
+///
+/// Panics if `width` isn't between 1 and 64.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::gf2::{mul_mod, x_pow_mod};
+///
+/// let poly = 0x42f0e1eba9ea3693; // CRC-64/XZ
+///
+/// assert_eq!(
+///     mul_mod(x_pow_mod(100, poly, 64), x_pow_mod(200, poly, 64), poly, 64),
+///     x_pow_mod(300, poly, 64)
+/// );
+/// ```
+pub fn mul_mod(a: u64, b: u64, poly: u64, width: u8) -> u64 {
+    Modulus::new(poly, width).mul(a, b)
+}
+
+/// Raises a polynomial to a power modulo `P(x)`, returning the `width`-bit result.
+///
+/// # Panics
+///
+/// Panics if `width` isn't between 1 and 64.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::gf2::pow_mod;
+///
+/// // (x + 1)^3 = x^3 + x^2 + x + 1, which is x^2 mod (x^3 + x + 1)
+/// assert_eq!(pow_mod(0b11, 3, 0b011, 3), 0b100);
+///
+/// // x^(2^3 - 1) = 1, since x^3 + x + 1 is primitive
+/// assert_eq!(pow_mod(0b10, 7, 0b011, 3), 1);
+/// ```
+pub fn pow_mod(base: u64, exponent: u64, poly: u64, width: u8) -> u64 {
+    Modulus::new(poly, width).pow(base, exponent)
+}
+
+/// Computes `x^exponent mod P(x)`, returning the `width`-bit result.
+///
+/// This is the operator for shifting a CRC over `exponent` zero bits, which the folding keys and
+/// CRC combination are built from.
+///
+/// # Panics
+///
+/// Panics if `width` isn't between 1 and 64.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::gf2::x_pow_mod;
+///
+/// // the constant for folding CRC-32 (BZIP2) lanes across 128 bits
+/// assert_eq!(x_pow_mod(128 + 32, 0x04c11db7, 32), 0x17d3315d);
+/// ```
+pub fn x_pow_mod(exponent: u64, poly: u64, width: u8) -> u64 {
+    let modulus = Modulus::new(poly, width);
+
+    modulus.pow(modulus.x(), exponent)
+}
+
+/// Computes the Barrett reduction constant `μ = floor(x^(2 * width) / P(x))`, returning the
+/// lower `width` bits (the leading `x^width` term is implicit).
+///
+/// # Panics
+///
+/// Panics if `width` isn't between 1 and 64.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::gf2::barrett_mu;
+///
+/// // the well-known CRC-32 constant is 0x104d101df
+/// assert_eq!(barrett_mu(0x04c11db7, 32), 0x04d101df);
+/// ```
+pub fn barrett_mu(poly: u64, width: u8) -> u64 {
+    Modulus::new(poly, width).mu
+}
+
+/// Reverses the lower `width` bits of `value`, discarding any higher bits.
+///
+/// Converts between the normal (MSB-first) and reflected (LSB-first) forms of a polynomial or
+/// CRC value.
+///
+/// # Panics
+///
+/// Panics if `width` isn't between 1 and 64.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::gf2::reflect;
+///
+/// assert_eq!(reflect(0x04c11db7, 32), 0xedb88320);
+/// assert_eq!(reflect(0b0011, 4), 0b1100);
+/// ```
+#[inline]
+pub fn reflect(value: u64, width: u8) -> u64 {
+    check_width(width);
+
+    value.reverse_bits() >> (64 - width as u32)
+}
+
+/// Converts a CRC register value to or from the forward (unreflected) form used for polynomial
+/// arithmetic. The two only differ for reflected CRCs, so this is its own inverse.
+#[inline]
+pub(crate) fn register_form(value: u64, params: CrcParams) -> u64 {
+    if params.refin {
+        reflect(value, params.width)
+    } else {
+        value
+    }
+}
+
+/// Computes the reciprocal polynomial `x^width * P(1/x)`, which has the same error detection
+/// properties as `P(x)` when used as a CRC.
+///
+/// The reciprocal reverses all `width + 1` coefficients, so it only has degree `width` when
+/// `P(x)` has a `+1` term.
+///
+/// # Panics
+///
+/// Panics if `width` isn't between 1 and 64.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::gf2::reciprocal;
+///
+/// // x^3 + x + 1 => x^3 + x^2 + 1
+/// assert_eq!(reciprocal(0b011, 3), 0b101);
+///
+/// // CRC-32 and its reciprocal (CRC-32/ISO-HDLC's reflected form shifted into place)
+/// assert_eq!(reciprocal(0x04c11db7, 32), 0xdb710641);
+/// ```
+pub fn reciprocal(poly: u64, width: u8) -> u64 {
+    check_width(width);
+
+    // Drop the +1 term and restore the leading term, so the coefficients can be reversed in place
+    reflect((poly >> 1) | (1 << (width - 1)), width)
+}
+
+/// Returns true if `P(x)` can't be factored into polynomials of lower degree.
+///
+/// Uses Rabin's test, which needs only repeated squaring and a few GCDs, so it's fast even for
+/// 64-bit polynomials.
+///
+/// # Panics
+///
+/// Panics if `width` isn't between 1 and 64.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::gf2::is_irreducible;
+///
+/// assert!(is_irreducible(0b011, 3)); // x^3 + x + 1
+/// assert!(!is_irreducible(0b101, 2)); // x^2 + 1 = (x + 1)^2
+/// ```
+pub fn is_irreducible(poly: u64, width: u8) -> bool {
+    let modulus = Modulus::new(poly, width);
+    let x = modulus.x();
+
+    // P(x) is irreducible iff it divides x^(2^n) - x, but not x^(2^(n/q)) - x for any prime
+    // factor q of n (otherwise it has a factor of degree n/q or less)
+    if modulus.frobenius(x, width as u32) != x {
+        return false;
+    }
+
+    prime_factors(width as u64).iter().all(|&q| {
+        let residue = modulus.frobenius(x, width as u32 / q as u32) ^ x;
+
+        poly_gcd(residue as u128, modulus.full()) == 1
+    })
+}
+
+/// Returns true if `P(x)` is primitive: irreducible, and `x` generates all `2^width - 1`
+/// non-zero remainders.
+///
+/// A primitive polynomial gives a CRC the longest possible period, so it detects all 2-bit
+/// errors in codewords up to `2^width - 1` bits long.
+///
+/// # Panics
+///
+/// Panics if `width` isn't between 1 and 64.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::gf2::is_primitive;
+///
+/// assert!(is_primitive(0b011, 3)); // x^3 + x + 1
+/// assert!(!is_primitive(0b1111, 4)); // x^4 + x^3 + x^2 + x + 1 is irreducible, but has order 5
+/// ```
+pub fn is_primitive(poly: u64, width: u8) -> bool {
+    // x itself is irreducible, but not invertible
+    if poly & 1 == 0 || !is_irreducible(poly, width) {
+        return false;
+    }
+
+    let modulus = Modulus::new(poly, width);
+    let order = u64::MAX >> (64 - width as u32);
+
+    // x has order 2^n - 1 iff no proper divisor of it is also a multiple of x's order
+    prime_factors(order)
+        .iter()
+        .all(|&r| modulus.pow(modulus.x(), order / r) != 1)
+}
+
+/// A modulus `P(x)`, with its Barrett constant.
+#[derive(Clone, Copy)]
+struct Modulus {
+    poly: u64,
+    width: u32,
+    mu: u64,
+}
+
+impl Modulus {
+    fn new(poly: u64, width: u8) -> Self {
+        check_width(width);
+
+        let width = width as u32;
+        let poly = poly & mask(width);
+
+        Self {
+            poly,
+            width,
+            mu: barrett_mu_long(poly, width),
+        }
+    }
+
+    /// The full polynomial, including the leading term.
+    fn full(&self) -> u128 {
+        (1u128 << self.width) | self.poly as u128
+    }
+
+    /// `x mod P(x)`, which is the polynomial itself for degree 1.
+    fn x(&self) -> u64 {
+        self.reduce(0b10)
+    }
+
+    fn reduce(&self, mut value: u128) -> u64 {
+        let width = self.width;
+
+        // Bring the value below x^(2 * width) so Barrett reduction applies
+        if width < 64 {
+            while value >> (2 * width) != 0 {
+                let degree = 127 - value.leading_zeros();
+                value ^= self.full() << (degree - width);
+            }
+        }
+
+        // q = floor(value / P) = floor(high * μ / x^width), where μ's leading term is implicit
+        let high = (value >> width) as u64;
+        let quotient = high ^ (clmul(high, self.mu) >> width) as u64;
+
+        // value - q * P, where q * x^width only affects the bits above the remainder
+        (value ^ clmul(quotient, self.poly)) as u64 & mask(width)
+    }
+
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(clmul(a, b))
+    }
+
+    fn pow(&self, base: u64, exponent: u64) -> u64 {
+        let base = self.reduce(base as u128);
+        let mut result = self.reduce(1);
+
+        for bit in (0..64 - exponent.leading_zeros()).rev() {
+            result = self.mul(result, result);
+
+            if (exponent >> bit) & 1 == 1 {
+                result = self.mul(result, base);
+            }
+        }
+
+        result
+    }
+
+    /// `value^(2^k) mod P(x)`, by repeated squaring.
+    fn frobenius(&self, mut value: u64, k: u32) -> u64 {
+        for _ in 0..k {
+            value = self.mul(value, value);
+        }
+
+        value
+    }
+}
+
+#[inline]
+fn check_width(width: u8) {
+    assert!((1..=64).contains(&width), "Unsupported width: {width}");
+}
+
+/// All-ones mask for a `width`-bit value.
+#[inline]
+fn mask(width: u32) -> u64 {
+    u64::MAX >> (64 - width)
+}
+
+/// `floor(x^(2 * width) / P(x))` by long division, without its leading term.
+fn barrett_mu_long(poly: u64, width: u32) -> u64 {
+    let full = (1u128 << width) | poly as u128;
+    let mut remainder: u128 = 0;
+    let mut quotient: u128 = 0;
+
+    // Shift in the dividend's bits MSB-first: a single 1 followed by 2 * width zeros
+    for i in 0..=2 * width {
+        remainder = (remainder << 1) | (i == 0) as u128;
+        quotient <<= 1;
+
+        if (remainder >> width) & 1 == 1 {
+            remainder ^= full;
+            quotient |= 1;
+        }
+    }
+
+    quotient as u64 & mask(width)
+}
+
+/// Greatest common divisor of two polynomials.
+fn poly_gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let b_degree = 127 - b.leading_zeros();
+
+        while a != 0 && 127 - a.leading_zeros() >= b_degree {
+            a ^= b << (127 - a.leading_zeros() - b_degree);
+        }
+
+        core::mem::swap(&mut a, &mut b);
+    }
+
+    a
+}
+
+#[inline]
+fn clmul_software(a: u64, b: u64) -> u128 {
+    let mut product = 0u128;
+
+    for i in 0..64 {
+        if (b >> i) & 1 == 1 {
+            product ^= (a as u128) << i;
+        }
+    }
+
+    product
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "sse4.1,pclmulqdq")]
+unsafe fn clmul_simd(a: u64, b: u64) -> u128 {
+    clmul_with_ops(a, b, &crate::arch::x86::sse::X86SsePclmulqdqOps)
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "aes")]
+unsafe fn clmul_simd(a: u64, b: u64) -> u128 {
+    clmul_with_ops(a, b, &crate::arch::aarch64::aes::Aarch64AesOps)
+}
+
+#[inline(always)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+unsafe fn clmul_with_ops<T: ArchOps>(a: u64, b: u64, ops: &T) -> u128
+where
+    T::Vector: Copy,
+{
+    let product = ops.carryless_mul_00(
+        ops.create_vector_from_u64(a, false),
+        ops.create_vector_from_u64(b, false),
+    );
+    let [low, high] = ops.extract_u64s(product);
+
+    ((high as u128) << 64) | low as u128
+}
+
+/// The distinct prime factors of a number, smallest first.
+///
+/// `2^n - 1` has at most 11 distinct prime factors for `n <= 64`.
+struct PrimeFactors {
+    primes: [u64; 16],
+    len: usize,
+}
+
+impl PrimeFactors {
+    fn iter(&self) -> impl Iterator<Item = &u64> {
+        self.primes[..self.len].iter()
+    }
+
+    fn push(&mut self, prime: u64) {
+        if !self.primes[..self.len].contains(&prime) {
+            self.primes[self.len] = prime;
+            self.len += 1;
+            self.primes[..self.len].sort_unstable();
+        }
+    }
+}
+
+/// Factors a number using trial division for small primes, then Pollard's rho.
+fn prime_factors(mut n: u64) -> PrimeFactors {
+    let mut factors = PrimeFactors {
+        primes: [0; 16],
+        len: 0,
+    };
+
+    for p in 2..1000 {
+        if n % p == 0 {
+            factors.push(p);
+
+            while n % p == 0 {
+                n /= p;
+            }
+        }
+    }
+
+    push_large_factors(n, &mut factors);
+
+    factors
+}
+
+fn push_large_factors(n: u64, factors: &mut PrimeFactors) {
+    if n == 1 {
+        return;
+    }
+
+    if is_prime(n) {
+        factors.push(n);
+
+        return;
+    }
+
+    let divisor = pollard_rho(n);
+    push_large_factors(divisor, factors);
+    push_large_factors(n / divisor, factors);
+}
+
+#[inline]
+fn mul_mod_u64(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+fn pow_mod_u64(mut base: u64, mut exponent: u64, m: u64) -> u64 {
+    let mut result = 1;
+    base %= m;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod_u64(result, base, m);
+        }
+        base = mul_mod_u64(base, base, m);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// Deterministic Miller-Rabin, using bases which are sufficient for every 64-bit number.
+fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+
+    for p in BASES {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+
+    let shift = (n - 1).trailing_zeros();
+    let d = (n - 1) >> shift;
+
+    BASES.iter().all(|&a| {
+        let mut x = pow_mod_u64(a, d, n);
+
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+
+        for _ in 1..shift {
+            x = mul_mod_u64(x, x, n);
+
+            if x == n - 1 {
+                return true;
+            }
+        }
+
+        false
+    })
+}
+
+/// Finds a non-trivial divisor of an odd composite number.
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1.. {
+        let f = |x: u64| ((mul_mod_u64(x, x, n) as u128 + c as u128) % n as u128) as u64;
+
+        let (mut x, mut y, mut divisor) = (2, 2, 1);
+        while divisor == 1 {
+            x = f(x);
+            y = f(f(y));
+            divisor = gcd(x.abs_diff(y), n);
+        }
+
+        if divisor != n {
+            return divisor;
+        }
+    }
+
+    unreachable!()
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use rand::{rng, Rng};
+
+    /// Polynomial remainder by schoolbook long division.
+    fn reduce_long(mut value: u128, poly: u64, width: u8) -> u64 {
+        let full = (1u128 << width) | poly as u128;
+
+        while value >> width != 0 {
+            let degree = 127 - value.leading_zeros();
+            value ^= full << (degree - width as u32);
+        }
+
+        value as u64
+    }
+
+    /// Order of x modulo P(x), by stepping through its powers.
+    fn order_of_x(poly: u64, width: u8) -> u64 {
+        let x = reduce_long(0b10, poly, width);
+        let mut value = x;
+        let mut order = 1;
+
+        while value != 1 {
+            value = reduce_long((value as u128) << 1, poly, width);
+            order += 1;
+
+            if value == x {
+                return 0; // never reaches 1
+            }
+        }
+
+        order
+    }
+
+    /// Irreducibility by trial division by every polynomial of at most half the degree.
+    fn is_irreducible_trial(poly: u64, width: u8) -> bool {
+        let full = (1u128 << width) | poly as u128;
+
+        (1..=width / 2).all(|degree| {
+            (0..1u64 << degree).all(|low| {
+                let divisor = (1u64 << degree) | low;
+                reduce_long(full, low, degree) != 0 || divisor == 0
+            })
+        })
+    }
+
+    #[test]
+    fn test_clmul() {
+        let mut rng = rng();
+
+        for _ in 0..10_000 {
+            let (a, b) = (rng.random::<u64>(), rng.random::<u64>());
+
+            assert_eq!(clmul(a, b), clmul_software(a, b), "{a:#x} * {b:#x}");
+        }
+
+        assert_eq!(
+            clmul(u64::MAX, u64::MAX),
+            0x5555_5555_5555_5555_5555_5555_5555_5555
+        );
+        assert_eq!(clmul(0, u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_reduce() {
+        let mut rng = rng();
+
+        for width in 1..=64u8 {
+            for _ in 0..200 {
+                let poly = rng.random::<u64>() & mask(width as u32);
+                let value = rng.random::<u128>() >> rng.random_range(0..128);
+
+                assert_eq!(
+                    reduce(value, poly, width),
+                    reduce_long(value, poly, width),
+                    "{value:#x} mod {poly:#x} (width {width})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_pow_mod() {
+        let mut rng = rng();
+
+        for width in [1, 7, 16, 31, 32, 33, 63, 64] {
+            let poly = rng.random::<u64>() & mask(width as u32);
+            let (a, b) = (rng.random::<u64>(), rng.random::<u64>());
+
+            assert_eq!(
+                mul_mod(a, b, poly, width),
+                reduce_long(clmul_software(a, b), poly, width)
+            );
+
+            // x^(a + b) = x^a * x^b
+            let (e1, e2) = (rng.random::<u32>() as u64, rng.random::<u32>() as u64);
+            assert_eq!(
+                x_pow_mod(e1 + e2, poly, width),
+                mul_mod(
+                    x_pow_mod(e1, poly, width),
+                    x_pow_mod(e2, poly, width),
+                    poly,
+                    width
+                )
+            );
+
+            // against shifting one bit at a time
+            let mut value = reduce_long(1, poly, width);
+            for exponent in 0..300 {
+                assert_eq!(x_pow_mod(exponent, poly, width), value);
+                value = reduce_long((value as u128) << 1, poly, width);
+            }
+
+            assert_eq!(pow_mod(a, 0, poly, width), reduce_long(1, poly, width));
+            assert_eq!(
+                pow_mod(a, 3, poly, width),
+                mul_mod(mul_mod(a, a, poly, width), a, poly, width)
+            );
+        }
+    }
+
+    #[test]
+    fn test_keys_match_generated() {
+        for config in TEST_ALL_CONFIGS {
+            let (width, poly, reflected) =
+                (config.get_width(), config.get_poly(), config.get_refin());
+            let keys = config.get_keys();
+
+            // keys 1 and 2 fold across 128 bits, and 21 and 22 across 2048 bits
+            let exponents = if width == 32 {
+                [(1, 32 * 3), (2, 32 * 5), (21, 32 * 63), (22, 32 * 65)]
+            } else {
+                [(1, 64 * 2), (2, 64 * 3), (21, 64 * 32), (22, 64 * 33)]
+            };
+
+            for (index, exponent) in exponents {
+                let expected = match (width, reflected) {
+                    (32, false) => x_pow_mod(exponent, poly, 32) << 32,
+                    (32, true) => reflect(x_pow_mod(exponent, poly, 32), 32) << 1,
+                    (_, false) => x_pow_mod(exponent, poly, 64),
+                    (_, true) => reflect(x_pow_mod(exponent - 1, poly, 64), 64),
+                };
+
+                assert_eq!(keys[index], expected, "{} key {}", config.get_name(), index);
+            }
+
+            if !reflected {
+                let mu = barrett_mu(poly, width);
+                let expected = if width == 32 { mu | 1 << 32 } else { mu };
+
+                assert_eq!(keys[7], expected, "{} mu", config.get_name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_reflect() {
+        assert_eq!(reflect(0x42f0e1eba9ea3693, 64), 0xc96c5795d7870f42);
+        assert_eq!(reflect(0x1edc6f41, 32), 0x82f63b78);
+        assert_eq!(reflect(1, 1), 1);
+
+        // bits above the width are discarded
+        assert_eq!(reflect(0xf01, 8), 0x80);
+
+        for width in 1..=64 {
+            let value = rng().random::<u64>() & mask(width as u32);
+
+            assert_eq!(reflect(reflect(value, width), width), value);
+            assert_eq!(reciprocal(reciprocal(value | 1, width), width), value | 1);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported width: 65")]
+    fn test_unsupported_width() {
+        reflect(0, 65);
+    }
+
+    /// Skipping for Miri runs due to time constraints, underlying code already covered by other
+    /// tests.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_irreducible_and_primitive_counts() {
+        // OEIS A001037 and A011260
+        const IRREDUCIBLE: [usize; 10] = [2, 1, 2, 3, 6, 9, 18, 30, 56, 99];
+        const PRIMITIVE: [usize; 10] = [1, 1, 2, 2, 6, 6, 18, 16, 48, 60];
+
+        for width in 1..=10u8 {
+            let mut irreducible = 0;
+            let mut primitive = 0;
+
+            for poly in 0..1u64 << width {
+                let expected_irreducible = is_irreducible_trial(poly, width);
+                let expected_primitive = expected_irreducible
+                    && poly & 1 == 1
+                    && order_of_x(poly, width) == (1 << width) - 1;
+
+                assert_eq!(
+                    is_irreducible(poly, width),
+                    expected_irreducible,
+                    "{poly:#x} (width {width})"
+                );
+                assert_eq!(
+                    is_primitive(poly, width),
+                    expected_primitive,
+                    "{poly:#x} (width {width})"
+                );
+
+                irreducible += expected_irreducible as usize;
+                primitive += expected_primitive as usize;
+            }
+
+            assert_eq!(
+                irreducible,
+                IRREDUCIBLE[width as usize - 1],
+                "width {width}"
+            );
+            assert_eq!(primitive, PRIMITIVE[width as usize - 1], "width {width}");
+        }
+    }
+
+    #[test]
+    fn test_large_primitive_polynomials() {
+        // maximal-length LFSR taps, from Xilinx XAPP052
+        assert!(is_primitive(0x400007, 32)); // x^32 + x^22 + x^2 + x + 1
+        assert!(is_primitive(0xb000000000000001, 64)); // x^64 + x^63 + x^61 + x^60 + 1
+        assert!(is_primitive(reciprocal(0xb000000000000001, 64), 64));
+
+        // x^64 + x^32 + 1 = (x^32 + x^16 + 1)^2
+        assert!(!is_irreducible(0x100000001, 64));
+
+        // CRC-32C is divisible by (x + 1), as is every polynomial with an even number of terms
+        assert!(!is_irreducible(0x1edc6f41, 32));
+        assert!(!is_primitive(0x1edc6f41, 32));
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        for n in 1..=64u32 {
+            let value = u64::MAX >> (64 - n);
+            let factors = prime_factors(value);
+
+            let mut remaining = value;
+            for &p in factors.iter() {
+                assert!(is_prime(p), "{p} isn't prime");
+
+                while remaining % p == 0 {
+                    remaining /= p;
+                }
+            }
+
+            assert_eq!(remaining, 1, "2^{n} - 1 isn't fully factored");
+        }
+
+        assert_eq!(
+            prime_factors(u64::MAX).iter().copied().collect::<Vec<_>>(),
+            [3, 5, 17, 257, 641, 65537, 6700417]
+        );
+    }
+}
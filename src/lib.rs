@@ -82,6 +82,48 @@
 //! assert_eq!(checksum, 0xcbf43926);
 //! ```
 //!
+//! ## update_raw
+//!
+//! Updates a raw CRC state, without the initial value or final XOR, matching the Linux kernel's
+//! `crc32c(seed, buf, len)` semantics.
+//!
+//!```rust
+//! use crc_fast::{raw_to_finalized, update_raw, CrcAlgorithm::Crc32Iscsi};
+//!
+//! let state = update_raw(Crc32Iscsi, 0xffffffff, b"123456789");
+//!
+//! assert_eq!(state, 0x1cf96d7c);
+//! assert_eq!(raw_to_finalized(Crc32Iscsi, state), 0xe3069283);
+//! ```
+//!
+//! ## checksum_multi
+//!
+//! Computes several CRCs over the same data in a single pass.
+//!
+//!```rust
+//! use crc_fast::{checksum_multi, CrcAlgorithm::{Crc32Iscsi, Crc64Nvme}};
+//!
+//! let [crc32c, crc64nvme] = checksum_multi(&[Crc32Iscsi, Crc64Nvme], b"123456789");
+//!
+//! assert_eq!(crc32c, 0xe3069283);
+//! assert_eq!(crc64nvme, 0xae8b14860a799888);
+//! ```
+//!
+//! ## copy_and_checksum
+//!
+//! Copies a buffer and computes its CRC in a single pass, storing each block to the destination as
+//! it's folded.
+//!
+//!```rust
+//! use crc_fast::{copy_and_checksum, CrcAlgorithm::Crc32IsoHdlc};
+//!
+//! let mut dst = [0u8; 9];
+//! let checksum = copy_and_checksum(Crc32IsoHdlc, b"123456789", &mut dst);
+//!
+//! assert_eq!(&dst, b"123456789");
+//! assert_eq!(checksum, 0xcbf43926);
+//! ```
+//!
 //! ## checksum_combine
 //!```rust
 //! use crc_fast::{checksum, checksum_combine, CrcAlgorithm::Crc32IsoHdlc};
@@ -93,6 +135,19 @@
 //! assert_eq!(checksum, 0xcbf43926);
 //! ```
 //!
+//! ## force_crc
+//!```rust
+//! use crc_fast::{checksum, force_crc, CrcAlgorithm::Crc32IsoHdlc};
+//!
+//! let mut data = *b"1234\0\0\0\056789";
+//!
+//! // the 4 bytes at offset 4 which give the data a CRC of 0xdeadbeef
+//! let patch: [u8; 4] = force_crc(Crc32IsoHdlc, &data, 4, 0xdeadbeef);
+//! data[4..8].copy_from_slice(&patch);
+//!
+//! assert_eq!(checksum(Crc32IsoHdlc, &data), 0xdeadbeef);
+//! ```
+//!
 //! ## checksum_file
 //!```rust
 //! use std::env;
@@ -193,23 +248,22 @@ use crate::crc32::consts::{
     CRC32_ISCSI, CRC32_ISO_HDLC, CRC32_JAMCRC, CRC32_MEF, CRC32_MPEG_2, CRC32_XFER,
 };
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
-#[cfg(feature = "std")]
-use crate::crc32::fusion;
-
 use crate::crc64::consts::{
     CRC64_ECMA_182, CRC64_GO_ISO, CRC64_MS, CRC64_NVME, CRC64_REDIS, CRC64_WE, CRC64_XZ,
 };
-use crate::structs::Calculator;
-use crate::traits::CrcCalculator;
 #[cfg(feature = "alloc")]
 use digest::DynDigest;
 #[cfg(feature = "alloc")]
 use digest::InvalidBufferSize;
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+#[cfg(all(
+    feature = "alloc",
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
 use crate::feature_detection::get_arch_ops;
 #[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
 use std::fs::File;
 #[cfg(feature = "std")]
 use std::io::{Read, Write};
@@ -219,23 +273,51 @@ extern crate alloc;
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::boxed::Box;
 #[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::collections::BTreeMap;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::string::String;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
 
 mod algorithm;
+#[cfg(feature = "alloc")]
+pub mod analysis;
 mod arch;
 mod cache;
+pub mod chunking;
 mod combine;
+#[cfg(feature = "alloc")]
+mod combiner;
+#[cfg(any(
+    feature = "crc",
+    feature = "crc32c",
+    feature = "crc32fast",
+    feature = "crc64fast"
+))]
+pub mod compat;
 mod consts;
 mod crc32;
 mod crc64;
+mod encoding;
 mod enums;
 mod feature_detection;
 #[cfg(feature = "ffi")]
 mod ffi;
+mod force;
+pub mod formats;
 mod generate;
+pub mod gf2;
+#[cfg(feature = "alloc")]
+mod manifest;
+pub mod masked;
+pub mod nvme;
+mod rolling;
+#[cfg(feature = "alloc")]
+pub mod s3;
 mod structs;
 mod test;
 mod traits;
+mod zlib;
 
 /// Supported CRC-32 and CRC-64 variants
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -263,6 +345,25 @@ pub enum CrcAlgorithm {
     Crc64Xz,
 }
 
+/// Byte order for encoding a CRC result as bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Most significant byte first, as in MPEG-2, PNG and S3 checksum headers
+    BigEndian,
+    /// Least significant byte first, as in the Ethernet FCS, gzip and zip
+    LittleEndian,
+}
+
+/// A CRC result encoded as bytes in a specific order, which is 4 bytes for CRC-32 and 8 for
+/// CRC-64.
+///
+/// Dereferences to the encoded bytes, and formats them in order as hex with `{:x}` and `{:X}`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CrcBytes {
+    bytes: [u8; 8],
+    len: usize,
+}
+
 /// Internal storage for CRC folding keys that can accommodate different array sizes.
 /// This enum allows future expansion to support larger folding distances while maintaining
 /// backwards compatibility with existing const definitions.
@@ -389,10 +490,13 @@ pub struct Digest {
     /// The total amount of data processed so far.
     amount: u64,
 
+    /// The number of bits processed beyond `amount` whole bytes, by `update_bits()`.
+    bits: u8,
+
     /// The parameters for the CRC computation, such as polynomial, initial value, etc.
     params: CrcParams,
 
-    /// The function used to perform the CRC calculation.
+    /// The function used to perform the CRC calculation, resolved once for this CPU.
     calculator: CalculatorFn,
 }
 
@@ -472,6 +576,7 @@ impl Digest {
         Self {
             state: params.init,
             amount: 0,
+            bits: 0,
             params,
             calculator,
         }
@@ -507,6 +612,7 @@ impl Digest {
         Self {
             state: init_state,
             amount: 0,
+            bits: 0,
             params,
             calculator,
         }
@@ -538,11 +644,12 @@ impl Digest {
     /// ```
     #[inline(always)]
     pub fn new_with_params(params: CrcParams) -> Self {
-        let calculator = Calculator::calculate as CalculatorFn;
+        let calculator = get_calculator(&params);
 
         Self {
             state: params.init,
             amount: 0,
+            bits: 0,
             params,
             calculator,
         }
@@ -555,12 +662,180 @@ impl Digest {
         self.amount += data.len() as u64;
     }
 
+    /// Updates the CRC state with the first `bit_len` bits of the given data, for messages which
+    /// aren't a whole number of bytes.
+    ///
+    /// Bits are taken in the order the algorithm processes them: least significant first for
+    /// reflected algorithms, so a trailing partial byte contributes its low bits, and most
+    /// significant first for forward algorithms, so it contributes its high bits. Further updates
+    /// continue from the next bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is longer than `data`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crc_fast::{Digest, CrcAlgorithm::Crc32Bzip2};
+    ///
+    /// // an 11-bit message: 0xa5, then the high 3 bits (101) of 0xa0
+    /// let mut digest = Digest::new(Crc32Bzip2);
+    /// digest.update_bits(&[0xa5, 0xa0], 11);
+    ///
+    /// assert_eq!(digest.get_amount_bits(), 11);
+    /// ```
+    pub fn update_bits(&mut self, data: &[u8], bit_len: usize) {
+        assert!(bit_len <= data.len() * 8, "Bit length exceeds data length");
+
+        let (whole, tail) = (bit_len / 8, (bit_len % 8) as u8);
+        self.update(&data[..whole]);
+
+        if tail > 0 {
+            self.state = arch::software::update_bits(self.state, data[whole], tail, self.params);
+            self.add_bits(tail as u64);
+        }
+    }
+
+    /// Copies `src` to `dst` while updating the CRC state with `src`.
+    ///
+    /// Each block is stored to `dst` as it's loaded for folding, avoiding a second pass through
+    /// the cache compared to copying and then calling `update()`. On CPUs with VPCLMULQDQ, whose
+    /// wide kernels are faster than the single pass, it copies and then updates instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crc_fast::{Digest, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut dst = [0u8; 9];
+    ///
+    /// let mut digest = Digest::new(Crc32IsoHdlc);
+    /// digest.update_copy(b"1234", &mut dst[..4]);
+    /// digest.update_copy(b"56789", &mut dst[4..]);
+    ///
+    /// assert_eq!(&dst, b"123456789");
+    /// assert_eq!(digest.finalize(), 0xcbf43926);
+    /// ```
+    #[inline(always)]
+    pub fn update_copy(&mut self, src: &[u8], dst: &mut [u8]) {
+        self.update_copy_with(src, dst, false);
+    }
+
+    /// Copies `src` to `dst` while updating the CRC state with `src`, using non-temporal stores
+    /// where supported.
+    ///
+    /// Non-temporal stores bypass the cache, which helps for very large copies (many times the
+    /// size of the last level cache) whose destination won't be read again soon. For smaller
+    /// copies, `update_copy()` is usually faster.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline(always)]
+    pub fn update_copy_non_temporal(&mut self, src: &[u8], dst: &mut [u8]) {
+        self.update_copy_with(src, dst, true);
+    }
+
+    #[inline(always)]
+    fn update_copy_with(&mut self, src: &[u8], dst: &mut [u8], non_temporal: bool) {
+        assert_eq!(
+            src.len(),
+            dst.len(),
+            "source and destination slices have different lengths"
+        );
+
+        self.state = arch::update_copy(
+            self.state,
+            src,
+            dst,
+            self.params,
+            self.calculator,
+            non_temporal,
+        );
+        self.amount += src.len() as u64;
+    }
+
     /// Finalizes the CRC computation and returns the result.
     #[inline(always)]
     pub fn finalize(&self) -> u64 {
         self.state ^ self.params.xorout
     }
 
+    /// Finalizes the CRC computation and returns the result encoded as bytes in the given order.
+    ///
+    /// Unlike `DynDigest::finalize_into()`, which is always big-endian, this supports either
+    /// byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{ByteOrder, Digest, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut digest = Digest::new(Crc32IsoHdlc);
+    /// digest.update(b"123456789");
+    ///
+    /// assert_eq!(*digest.finalize_bytes(ByteOrder::LittleEndian), [0x26, 0x39, 0xf4, 0xcb]);
+    /// assert_eq!(*digest.finalize_bytes(ByteOrder::BigEndian), [0xcb, 0xf4, 0x39, 0x26]);
+    /// ```
+    #[inline(always)]
+    pub fn finalize_bytes(&self, order: ByteOrder) -> CrcBytes {
+        CrcBytes::new(self.finalize(), self.params.width, order)
+    }
+
+    /// Finalizes the CRC computation and appends the result to `out` as a trailer, in the
+    /// algorithm's conventional byte order (see `CrcParams::byte_order()`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{Digest, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut frame = b"123456789".to_vec();
+    ///
+    /// let mut digest = Digest::new(Crc32IsoHdlc);
+    /// digest.update(&frame);
+    /// digest.append_to(&mut frame);
+    ///
+    /// assert_eq!(frame[9..], [0x26, 0x39, 0xf4, 0xcb]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub fn append_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.finalize_bytes(self.params.byte_order()));
+    }
+
+    /// Finalizes the CRC computation and writes the result to the start of `buf`, in the
+    /// algorithm's conventional byte order (see `CrcParams::byte_order()`), returning the number
+    /// of bytes written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than the CRC (4 bytes for CRC-32, 8 bytes for CRC-64).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{Digest, CrcAlgorithm::Crc32Mpeg2};
+    ///
+    /// let mut digest = Digest::new(Crc32Mpeg2);
+    /// digest.update(b"123456789");
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let written = digest.write_to(&mut buf);
+    ///
+    /// assert_eq!(buf[..written], [0x03, 0x76, 0xe6, 0xe7]);
+    /// ```
+    #[inline(always)]
+    pub fn write_to(&self, buf: &mut [u8]) -> usize {
+        let bytes = self.finalize_bytes(self.params.byte_order());
+        buf[..bytes.len()].copy_from_slice(&bytes);
+
+        bytes.len()
+    }
+
     /// Finalizes the CRC computation, resets the state, and returns the result.
     #[inline(always)]
     pub fn finalize_reset(&mut self) -> u64 {
@@ -575,30 +850,48 @@ impl Digest {
     pub fn reset(&mut self) {
         self.state = self.params.init;
         self.amount = 0;
+        self.bits = 0;
     }
 
     /// Combines the CRC state with a second `Digest` instance.
     #[inline(always)]
     pub fn combine(&mut self, other: &Self) {
-        self.amount += other.amount;
         let other_crc = other.finalize();
 
         // note the xorout for the input, since it's already been applied so it has to be removed,
         // and then re-adding it on the final output
-        self.state = combine::checksums(
+        self.state = combine::checksums_bits(
             self.state ^ self.params.xorout,
             other_crc,
-            other.amount,
+            other.get_amount_bits(),
             self.params,
         ) ^ self.params.xorout;
+
+        self.amount += other.amount;
+        self.add_bits(other.bits as u64);
     }
 
-    /// Gets the amount of data processed so far
+    /// Gets the amount of data processed so far, in whole bytes
     #[inline(always)]
     pub fn get_amount(&self) -> u64 {
         self.amount
     }
 
+    /// Gets the amount of data processed so far, in bits
+    #[inline(always)]
+    pub fn get_amount_bits(&self) -> u64 {
+        self.amount * 8 + self.bits as u64
+    }
+
+    /// Adds a number of bits to the amount processed, carrying whole bytes.
+    #[inline(always)]
+    fn add_bits(&mut self, bits: u64) {
+        let bits = self.bits as u64 + bits;
+
+        self.amount += bits / 8;
+        self.bits = (bits % 8) as u8;
+    }
+
     /// Gets the current CRC state.
     ///
     /// # Examples
@@ -655,116 +948,635 @@ impl Write for Digest {
     }
 }
 
-/// Computes the CRC checksum for the given data using the specified algorithm.
+/// Represents several CRC Digests computed together over the same data in a single pass.
 ///
-///```rust
-/// use crc_fast::{checksum, CrcAlgorithm::Crc32IsoHdlc};
-/// let checksum = checksum(Crc32IsoHdlc, b"123456789");
-///
-/// assert_eq!(checksum, 0xcbf43926);
-/// ```
-#[inline(always)]
-pub fn checksum(algorithm: CrcAlgorithm, buf: &[u8]) -> u64 {
-    let (calculator, params) = get_calculator_params(algorithm);
+/// Each block of input is loaded from memory once and folded into every CRC, rather than making
+/// one pass over the data per CRC, which matters most for buffers too large to stay in cache.
+#[derive(Copy, Clone, Debug)]
+pub struct MultiDigest<const N: usize> {
+    /// The current state of each CRC computation.
+    states: [u64; N],
 
-    calculator(params.init, buf, params) ^ params.xorout
-}
+    /// The total amount of data processed so far.
+    amount: u64,
 
-/// Computes the CRC checksum for the given data using custom CRC parameters.
-///
-/// # Examples
-///
-/// ```rust
-/// use crc_fast::{checksum_with_params, CrcParams};
-///
-/// // Define custom CRC-32 parameters (equivalent to CRC-32/ISO-HDLC)
-/// let custom_params = CrcParams::new(
-///     "CRC-32/CUSTOM",
-///     32,
-///     0x04c11db7,
-///     0xffffffff,
-///     true,
-///     0xffffffff,
-///     0xcbf43926,
-/// );
-///
-/// let checksum = checksum_with_params(custom_params, b"123456789");
-///
-/// assert_eq!(checksum, 0xcbf43926);
-/// ```
-pub fn checksum_with_params(params: CrcParams, buf: &[u8]) -> u64 {
-    let calculator = Calculator::calculate as CalculatorFn;
+    /// The parameters for each CRC computation.
+    params: [CrcParams; N],
 
-    calculator(params.init, buf, params) ^ params.xorout
+    /// The function used for each CRC on short inputs and unaligned edges, resolved once for
+    /// this CPU.
+    calculators: [CalculatorFn; N],
 }
 
-/// Computes the CRC checksum for the given file using the specified algorithm.
-///
-/// Appears to be much faster (~2X) than using Writer and io::*, at least on Apple M2 Ultra
-///
-/// # Errors
-///
-/// This function will return an error if the file cannot be read.
-///
-/// # Examples
-/// ### checksum_file
-///```rust
-/// use std::env;
-/// use crc_fast::{checksum_file, CrcAlgorithm::Crc32IsoHdlc};
-///
-/// // for example/test purposes only, use your own file path
-/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
-/// let file_on_disk = file_path.to_str().unwrap();
-///
-/// let checksum = checksum_file(Crc32IsoHdlc, file_on_disk, None);
-///
-/// assert_eq!(checksum.unwrap(), 0xcbf43926);
-/// ```
-#[cfg(feature = "std")]
-#[inline(always)]
-pub fn checksum_file(
-    algorithm: CrcAlgorithm,
-    path: &str,
-    chunk_size: Option<usize>,
-) -> Result<u64, std::io::Error> {
-    checksum_file_with_digest(Digest::new(algorithm), path, chunk_size)
-}
+impl<const N: usize> MultiDigest<N> {
+    /// Creates a new `MultiDigest` instance for the specified CRC algorithms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{MultiDigest, CrcAlgorithm::{Crc32Iscsi, Crc64Nvme}};
+    ///
+    /// let mut digest = MultiDigest::new(&[Crc32Iscsi, Crc64Nvme]);
+    /// digest.update(b"1234");
+    /// digest.update(b"56789");
+    /// let [crc32c, crc64nvme] = digest.finalize();
+    ///
+    /// assert_eq!(crc32c, 0xe3069283);
+    /// assert_eq!(crc64nvme, 0xae8b14860a799888);
+    /// ```
+    #[inline(always)]
+    pub fn new(algorithms: &[CrcAlgorithm; N]) -> Self {
+        let resolved: [(CalculatorFn, CrcParams); N] =
+            core::array::from_fn(|i| get_calculator_params(algorithms[i]));
 
-/// Computes the CRC checksum for the given file using custom CRC parameters.
-///
-/// Appears to be much faster (~2X) than using Writer and io::*, at least on Apple M2 Ultra
-///
-/// # Errors
-///
-/// This function will return an error if the file cannot be read.
-///
-/// # Examples
-///
-/// ```rust
-/// use std::env;
-/// use crc_fast::{checksum_file_with_params, CrcParams};
-///
-/// // for example/test purposes only, use your own file path
-/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
-/// let file_on_disk = file_path.to_str().unwrap();
-///
-/// // Define custom CRC-32 parameters (equivalent to CRC-32/ISO-HDLC)
-/// let custom_params = CrcParams::new(
-///     "CRC-32/CUSTOM",
-///     32,
-///     0x04c11db7,
-///     0xffffffff,
-///     true,
-///     0xffffffff,
-///     0xcbf43926,
-/// );
-///
-/// let checksum = checksum_file_with_params(custom_params, file_on_disk, None);
-///
-/// assert_eq!(checksum.unwrap(), 0xcbf43926);
-/// ```
-#[cfg(feature = "std")]
-pub fn checksum_file_with_params(
+        Self::from_resolved(resolved)
+    }
+
+    /// Creates a new `MultiDigest` instance with custom CRC parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{MultiDigest, CrcParams};
+    ///
+    /// // Define custom CRC-32 parameters (equivalent to CRC-32/ISO-HDLC)
+    /// let custom_crc32 = CrcParams::new(
+    ///     "CRC-32/CUSTOM",
+    ///     32,
+    ///     0x04c11db7,
+    ///     0xffffffff,
+    ///     true,
+    ///     0xffffffff,
+    ///     0xcbf43926,
+    /// );
+    ///
+    /// // Define custom CRC-64 parameters (equivalent to CRC-64/NVME)
+    /// let custom_crc64 = CrcParams::new(
+    ///     "CRC-64/CUSTOM",
+    ///     64,
+    ///     0xad93d23594c93659,
+    ///     0xffffffffffffffff,
+    ///     true,
+    ///     0xffffffffffffffff,
+    ///     0xae8b14860a799888,
+    /// );
+    ///
+    /// let mut digest = MultiDigest::new_with_params([custom_crc32, custom_crc64]);
+    /// digest.update(b"123456789");
+    ///
+    /// assert_eq!(digest.finalize(), [0xcbf43926, 0xae8b14860a799888]);
+    /// ```
+    #[inline(always)]
+    pub fn new_with_params(params: [CrcParams; N]) -> Self {
+        Self::from_resolved(params.map(|params| (get_calculator(&params), params)))
+    }
+
+    #[inline(always)]
+    fn from_resolved(resolved: [(CalculatorFn, CrcParams); N]) -> Self {
+        let params = resolved.map(|(_, params)| params);
+
+        Self {
+            states: params.map(|params| params.init),
+            amount: 0,
+            params,
+            calculators: resolved.map(|(calculator, _)| calculator),
+        }
+    }
+
+    /// Updates each CRC state with the given data.
+    #[inline(always)]
+    pub fn update(&mut self, data: &[u8]) {
+        arch::update_multi(&mut self.states, data, &self.params, &self.calculators);
+        self.amount += data.len() as u64;
+    }
+
+    /// Finalizes the CRC computations and returns the results, in the order of the algorithms
+    /// (or parameters) this `MultiDigest` was created with.
+    #[inline(always)]
+    pub fn finalize(&self) -> [u64; N] {
+        core::array::from_fn(|i| self.states[i] ^ self.params[i].xorout)
+    }
+
+    /// Finalizes the CRC computations, resets the states, and returns the results.
+    #[inline(always)]
+    pub fn finalize_reset(&mut self) -> [u64; N] {
+        let result = self.finalize();
+        self.reset();
+
+        result
+    }
+
+    /// Resets the CRC states to their initial values.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.states = self.params.map(|params| params.init);
+        self.amount = 0;
+    }
+
+    /// Gets the amount of data processed so far
+    #[inline(always)]
+    pub fn get_amount(&self) -> u64 {
+        self.amount
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> Write for MultiDigest<N> {
+    #[inline(always)]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.update(buf);
+
+        Ok(())
+    }
+}
+
+/// Represents a rolling CRC over a fixed-size window, for rsync-style matching.
+///
+/// Sliding the window by one byte updates the CRC in constant time, using a table of each
+/// outgoing byte's contribution to the CRC, rather than recalculating it over the whole window.
+#[derive(Clone, Debug)]
+pub struct RollingCrc {
+    /// The CRC register for the current window.
+    state: u64,
+
+    /// The number of bytes in the window.
+    window: usize,
+
+    /// The parameters for the CRC computation.
+    params: CrcParams,
+
+    /// The function used to calculate the CRC of a whole window, resolved once for this CPU.
+    calculator: CalculatorFn,
+
+    /// The register update for each incoming byte.
+    incoming: [u64; 256],
+
+    /// Each outgoing byte's contribution to the register, including the initial value's.
+    outgoing: [u64; 256],
+}
+
+/// Combines the CRCs of an object's byte ranges, added in any order, into the CRC of the whole
+/// object, such as for a download which fetches ranges concurrently.
+///
+/// Adjacent ranges are merged as soon as both are added, so only the contiguous runs received so
+/// far are kept. The state can be saved with `snapshot()` and restored, to resume an interrupted
+/// download.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct CrcCombiner {
+    /// The parameters for the CRC computation.
+    params: CrcParams,
+
+    /// The length of the whole object.
+    len: u64,
+
+    /// The contiguous runs received so far, keyed by offset, with their lengths and CRCs.
+    runs: BTreeMap<u64, (u64, u64)>,
+}
+
+/// Errors from [`CrcCombiner`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombineError {
+    /// The range overlaps bytes already added, in the run at `offset` of `len` bytes.
+    Overlap { offset: u64, len: u64 },
+    /// The range extends past the end of the object.
+    OutOfBounds,
+    /// The ranges don't yet cover the object, the first missing range being at `offset` for
+    /// `len` bytes.
+    Gap { offset: u64, len: u64 },
+    /// The snapshot is malformed, or was taken with different CRC parameters.
+    InvalidSnapshot,
+}
+
+/// The CRC of each fixed-size block of a file or buffer, along with the CRC of the whole, for
+/// delta sync and scrubbing.
+///
+/// Two manifests taken with the same block size and CRC parameters can be compared with `diff()`
+/// to find the byte ranges which changed, and serialized with `to_bytes()`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct BlockManifest {
+    /// The parameters for the CRC computation.
+    params: CrcParams,
+
+    /// The number of bytes in each block, except possibly the last.
+    block_size: usize,
+
+    /// The length of the whole file.
+    len: u64,
+
+    /// The CRC of each block, in order.
+    blocks: Vec<u64>,
+
+    /// The CRC of the whole file, combined from the blocks' CRCs.
+    checksum: u64,
+}
+
+/// Errors from [`BlockManifest`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestError {
+    /// The manifests have different block sizes or CRC parameters, so can't be compared.
+    Incompatible,
+    /// The serialized manifest is malformed, or was taken with different CRC parameters.
+    Malformed,
+}
+
+/// Computes the CRC checksum for the given data using the specified algorithm.
+///
+///```rust
+/// use crc_fast::{checksum, CrcAlgorithm::Crc32IsoHdlc};
+/// let checksum = checksum(Crc32IsoHdlc, b"123456789");
+///
+/// assert_eq!(checksum, 0xcbf43926);
+/// ```
+#[inline(always)]
+pub fn checksum(algorithm: CrcAlgorithm, buf: &[u8]) -> u64 {
+    let (calculator, params) = get_calculator_params(algorithm);
+
+    calculator(params.init, buf, params) ^ params.xorout
+}
+
+/// Computes the CRC checksum for the given data using custom CRC parameters.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum_with_params, CrcParams};
+///
+/// // Define custom CRC-32 parameters (equivalent to CRC-32/ISO-HDLC)
+/// let custom_params = CrcParams::new(
+///     "CRC-32/CUSTOM",
+///     32,
+///     0x04c11db7,
+///     0xffffffff,
+///     true,
+///     0xffffffff,
+///     0xcbf43926,
+/// );
+///
+/// let checksum = checksum_with_params(custom_params, b"123456789");
+///
+/// assert_eq!(checksum, 0xcbf43926);
+/// ```
+pub fn checksum_with_params(params: CrcParams, buf: &[u8]) -> u64 {
+    let calculator = get_calculator(&params);
+
+    calculator(params.init, buf, params) ^ params.xorout
+}
+
+/// Computes the CRC checksum of the first `bit_len` bits of the given data, using the specified
+/// algorithm.
+///
+/// A trailing partial byte contributes its low bits for reflected algorithms, and its high bits
+/// for forward algorithms, matching the order each processes bits in.
+///
+/// # Panics
+///
+/// Panics if `bit_len` is longer than `data`.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, checksum_bits, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// assert_eq!(checksum_bits(Crc32IsoHdlc, b"123456789", 72), checksum(Crc32IsoHdlc, b"123456789"));
+///
+/// // a 3-bit message, 0b101, in the low bits since CRC-32/ISO-HDLC is reflected
+/// let crc = checksum_bits(Crc32IsoHdlc, &[0b101], 3);
+/// ```
+#[inline(always)]
+pub fn checksum_bits(algorithm: CrcAlgorithm, data: &[u8], bit_len: usize) -> u64 {
+    let mut digest = Digest::new(algorithm);
+    digest.update_bits(data, bit_len);
+
+    digest.finalize()
+}
+
+/// Computes the CRC checksum of the first `bit_len` bits of the given data, using custom CRC
+/// parameters.
+///
+/// See `checksum_bits()` for details.
+pub fn checksum_bits_with_params(params: CrcParams, data: &[u8], bit_len: usize) -> u64 {
+    let mut digest = Digest::new_with_params(params);
+    digest.update_bits(data, bit_len);
+
+    digest.finalize()
+}
+
+/// Updates a raw CRC state with the given data, without the algorithm's initial value or final
+/// XOR.
+///
+/// This matches the Linux kernel's `crc32c(seed, buf, len)`, `crc32_le()`, `crc32_be()` and
+/// `crc64_be()` semantics, where the caller supplies the seed and conditions the result. The
+/// state is the CRC register itself, so for reflected algorithms it's in reflected form, as it is
+/// in the kernel. Use `finalized_to_raw()` and `raw_to_finalized()` to convert to and from
+/// finalized checksums.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{raw_to_finalized, update_raw, CrcAlgorithm::Crc32Iscsi};
+///
+/// // crc32c(~0, buf, len), as used by ext4 and btrfs
+/// let state = update_raw(Crc32Iscsi, 0xffffffff, b"123456789");
+///
+/// assert_eq!(state, 0x1cf96d7c);
+/// assert_eq!(raw_to_finalized(Crc32Iscsi, state), 0xe3069283);
+/// ```
+#[inline(always)]
+pub fn update_raw(algorithm: CrcAlgorithm, state: u64, buf: &[u8]) -> u64 {
+    let (calculator, params) = get_calculator_params(algorithm);
+
+    calculator(state, buf, params)
+}
+
+/// Updates a raw CRC state with the given data using custom CRC parameters, without the initial
+/// value or final XOR.
+///
+/// See `update_raw()` for details.
+pub fn update_raw_with_params(params: CrcParams, state: u64, buf: &[u8]) -> u64 {
+    let calculator = get_calculator(&params);
+
+    calculator(state, buf, params)
+}
+
+/// Converts a finalized CRC checksum, such as one returned by `checksum()` or stored in a file
+/// format, into the raw state which `update_raw()` or `Digest::new_with_init_state()` continue
+/// from.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, finalized_to_raw, raw_to_finalized, update_raw, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let state = finalized_to_raw(Crc32IsoHdlc, checksum(Crc32IsoHdlc, b"1234"));
+/// let state = update_raw(Crc32IsoHdlc, state, b"56789");
+///
+/// assert_eq!(raw_to_finalized(Crc32IsoHdlc, state), 0xcbf43926);
+/// ```
+#[inline(always)]
+pub fn finalized_to_raw(algorithm: CrcAlgorithm, checksum: u64) -> u64 {
+    finalized_to_raw_with_params(get_calculator_params(algorithm).1, checksum)
+}
+
+/// Converts a raw CRC state, such as one returned by `update_raw()` or `Digest::get_state()`,
+/// into the finalized checksum.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{raw_to_finalized, update_raw, CrcAlgorithm::Crc64Nvme};
+///
+/// let state = update_raw(Crc64Nvme, 0xffffffffffffffff, b"123456789");
+///
+/// assert_eq!(raw_to_finalized(Crc64Nvme, state), 0xae8b14860a799888);
+/// ```
+#[inline(always)]
+pub fn raw_to_finalized(algorithm: CrcAlgorithm, state: u64) -> u64 {
+    raw_to_finalized_with_params(get_calculator_params(algorithm).1, state)
+}
+
+/// Converts a finalized CRC checksum into a raw state using custom CRC parameters.
+///
+/// See `finalized_to_raw()` for details.
+#[inline(always)]
+pub fn finalized_to_raw_with_params(params: CrcParams, checksum: u64) -> u64 {
+    checksum ^ params.xorout
+}
+
+/// Converts a raw CRC state into the finalized checksum using custom CRC parameters.
+///
+/// See `raw_to_finalized()` for details.
+#[inline(always)]
+pub fn raw_to_finalized_with_params(params: CrcParams, state: u64) -> u64 {
+    state ^ params.xorout
+}
+
+/// Returns the `N` bytes which, written at `patch_offset`, make the CRC checksum of `data` equal
+/// `target`, using the specified algorithm.
+///
+/// `N` must be the CRC width in bytes (4 or 8), and the bytes already at `patch_offset` are
+/// ignored. This is useful for test fixtures, firmware images with a fixed expected CRC, and
+/// formats with a reserved "CRC adjust" field.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, force_crc, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let mut data = *b"1234\0\0\0\056789";
+///
+/// let patch: [u8; 4] = force_crc(Crc32IsoHdlc, &data, 4, 0xdeadbeef);
+/// data[4..8].copy_from_slice(&patch);
+///
+/// assert_eq!(checksum(Crc32IsoHdlc, &data), 0xdeadbeef);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `N` doesn't match the CRC width, or the patch doesn't fit within `data`.
+pub fn force_crc<const N: usize>(
+    algorithm: CrcAlgorithm,
+    data: &[u8],
+    patch_offset: usize,
+    target: u64,
+) -> [u8; N] {
+    let (calculator, params) = get_calculator_params(algorithm);
+
+    force::patch(calculator, params, data, patch_offset, target)
+}
+
+/// Returns the `N` bytes which, written at `patch_offset`, make the CRC checksum of `data` equal
+/// `target`, using custom CRC parameters.
+///
+/// See `force_crc()` for details.
+///
+/// # Panics
+///
+/// Panics if `N` doesn't match the CRC width, the patch doesn't fit within `data`, or the
+/// polynomial has no x^0 term.
+pub fn force_crc_with_params<const N: usize>(
+    params: CrcParams,
+    data: &[u8],
+    patch_offset: usize,
+    target: u64,
+) -> [u8; N] {
+    force::patch(get_calculator(&params), params, data, patch_offset, target)
+}
+
+/// Copies `src` to `dst` and computes the CRC checksum of the data using the specified
+/// algorithm, in a single pass.
+///
+/// Each block is stored to `dst` as it's loaded for folding, avoiding a second pass through the
+/// cache compared to `copy_from_slice()` followed by `checksum()`. On CPUs with VPCLMULQDQ, whose
+/// wide kernels are faster than the single pass, it copies and then checksums instead.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+///```rust
+/// use crc_fast::{copy_and_checksum, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let mut dst = [0u8; 9];
+/// let checksum = copy_and_checksum(Crc32IsoHdlc, b"123456789", &mut dst);
+///
+/// assert_eq!(&dst, b"123456789");
+/// assert_eq!(checksum, 0xcbf43926);
+/// ```
+#[inline(always)]
+pub fn copy_and_checksum(algorithm: CrcAlgorithm, src: &[u8], dst: &mut [u8]) -> u64 {
+    let mut digest = Digest::new(algorithm);
+    digest.update_copy(src, dst);
+
+    digest.finalize()
+}
+
+/// Copies `src` to `dst` and computes the CRC checksum of the data using the specified
+/// algorithm, in a single pass, using non-temporal stores where supported.
+///
+/// Non-temporal stores bypass the cache, which helps for very large copies (many times the size
+/// of the last level cache) whose destination won't be read again soon. For smaller copies,
+/// `copy_and_checksum()` is usually faster.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+#[inline(always)]
+pub fn copy_and_checksum_non_temporal(algorithm: CrcAlgorithm, src: &[u8], dst: &mut [u8]) -> u64 {
+    let mut digest = Digest::new(algorithm);
+    digest.update_copy_non_temporal(src, dst);
+
+    digest.finalize()
+}
+
+/// Copies `src` to `dst` and computes the CRC checksum of the data using custom CRC parameters,
+/// in a single pass.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+pub fn copy_and_checksum_with_params(params: CrcParams, src: &[u8], dst: &mut [u8]) -> u64 {
+    let mut digest = Digest::new_with_params(params);
+    digest.update_copy(src, dst);
+
+    digest.finalize()
+}
+
+/// Computes several CRC checksums for the given data in a single pass, using the specified
+/// algorithms.
+///
+/// Returns the checksums in the same order as the algorithms.
+///
+///```rust
+/// use crc_fast::{checksum_multi, CrcAlgorithm::{Crc32Iscsi, Crc64Nvme}};
+///
+/// let [crc32c, crc64nvme] = checksum_multi(&[Crc32Iscsi, Crc64Nvme], b"123456789");
+///
+/// assert_eq!(crc32c, 0xe3069283);
+/// assert_eq!(crc64nvme, 0xae8b14860a799888);
+/// ```
+#[inline(always)]
+pub fn checksum_multi<const N: usize>(algorithms: &[CrcAlgorithm; N], buf: &[u8]) -> [u64; N] {
+    let mut digest = MultiDigest::new(algorithms);
+    digest.update(buf);
+
+    digest.finalize()
+}
+
+/// Computes several CRC checksums for the given data in a single pass, using custom CRC
+/// parameters.
+///
+/// Returns the checksums in the same order as the parameters.
+pub fn checksum_multi_with_params<const N: usize>(params: [CrcParams; N], buf: &[u8]) -> [u64; N] {
+    let mut digest = MultiDigest::new_with_params(params);
+    digest.update(buf);
+
+    digest.finalize()
+}
+
+/// Computes the CRC checksum for the given file using the specified algorithm.
+///
+/// Appears to be much faster (~2X) than using Writer and io::*, at least on Apple M2 Ultra
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be opened, or if any read fails
+/// partway through.
+///
+/// # Examples
+/// ### checksum_file
+///```rust
+/// use std::env;
+/// use crc_fast::{checksum_file, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// // for example/test purposes only, use your own file path
+/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
+/// let file_on_disk = file_path.to_str().unwrap();
+///
+/// let checksum = checksum_file(Crc32IsoHdlc, file_on_disk, None);
+///
+/// assert_eq!(checksum.unwrap(), 0xcbf43926);
+/// ```
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn checksum_file(
+    algorithm: CrcAlgorithm,
+    path: &str,
+    chunk_size: Option<usize>,
+) -> Result<u64, std::io::Error> {
+    checksum_file_with_digest(Digest::new(algorithm), path, chunk_size)
+}
+
+/// Computes the CRC checksum for the given file using custom CRC parameters.
+///
+/// Appears to be much faster (~2X) than using Writer and io::*, at least on Apple M2 Ultra
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be opened, or if any read fails
+/// partway through.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::env;
+/// use crc_fast::{checksum_file_with_params, CrcParams};
+///
+/// // for example/test purposes only, use your own file path
+/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
+/// let file_on_disk = file_path.to_str().unwrap();
+///
+/// // Define custom CRC-32 parameters (equivalent to CRC-32/ISO-HDLC)
+/// let custom_params = CrcParams::new(
+///     "CRC-32/CUSTOM",
+///     32,
+///     0x04c11db7,
+///     0xffffffff,
+///     true,
+///     0xffffffff,
+///     0xcbf43926,
+/// );
+///
+/// let checksum = checksum_file_with_params(custom_params, file_on_disk, None);
+///
+/// assert_eq!(checksum.unwrap(), 0xcbf43926);
+/// ```
+#[cfg(feature = "std")]
+pub fn checksum_file_with_params(
     params: CrcParams,
     path: &str,
     chunk_size: Option<usize>,
@@ -772,6 +1584,243 @@ pub fn checksum_file_with_params(
     checksum_file_with_digest(Digest::new_with_params(params), path, chunk_size)
 }
 
+/// Computes the CRC checksum of the `len` bytes at `offset` in the given file, using the specified
+/// algorithm.
+///
+/// Uses positioned reads, so several ranges of the same file can be checksummed concurrently from
+/// multiple threads, such as the parts of a multipart upload.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or an error of kind
+/// `UnexpectedEof` if the range extends past the end of the file.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::env;
+/// use crc_fast::{checksum, checksum_file_range, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// // for example/test purposes only, use your own file path
+/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
+/// let file_on_disk = file_path.to_str().unwrap();
+///
+/// let checksum_range = checksum_file_range(Crc32IsoHdlc, file_on_disk, 2, 5);
+///
+/// assert_eq!(checksum_range.unwrap(), checksum(Crc32IsoHdlc, b"34567"));
+/// ```
+#[cfg(feature = "std")]
+pub fn checksum_file_range(
+    algorithm: CrcAlgorithm,
+    path: &str,
+    offset: u64,
+    len: u64,
+) -> Result<u64, std::io::Error> {
+    checksum_file_range_from(algorithm, &File::open(path)?, offset, len)
+}
+
+/// Computes the CRC checksum of the `len` bytes at `offset` in the given file, using custom CRC
+/// parameters.
+///
+/// Uses positioned reads, so several ranges of the same file can be checksummed concurrently.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or an error of kind
+/// `UnexpectedEof` if the range extends past the end of the file.
+#[cfg(feature = "std")]
+pub fn checksum_file_range_with_params(
+    params: CrcParams,
+    path: &str,
+    offset: u64,
+    len: u64,
+) -> Result<u64, std::io::Error> {
+    checksum_file_range_from_with_params(params, &File::open(path)?, offset, len)
+}
+
+/// Computes the CRC checksum of the `len` bytes at `offset` in an already open file, using the
+/// specified algorithm.
+///
+/// Uses positioned reads, so threads can share one `File` to checksum several ranges of it
+/// concurrently. On Unix the file's cursor isn't used or moved. On Windows each read moves the
+/// cursor, so don't rely on its position afterwards, and elsewhere each read seeks, so ranges of the
+/// same `File` mustn't be checksummed concurrently.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or an error of kind
+/// `UnexpectedEof` if the range extends past the end of the file.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::env;
+/// use std::fs::File;
+/// use crc_fast::{checksum, checksum_file_range_from, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// // for example/test purposes only, use your own file path
+/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
+/// let file = File::open(file_path).unwrap();
+///
+/// let checksum_range = checksum_file_range_from(Crc32IsoHdlc, &file, 2, 5);
+///
+/// assert_eq!(checksum_range.unwrap(), checksum(Crc32IsoHdlc, b"34567"));
+/// ```
+#[cfg(feature = "std")]
+pub fn checksum_file_range_from(
+    algorithm: CrcAlgorithm,
+    file: &File,
+    offset: u64,
+    len: u64,
+) -> Result<u64, std::io::Error> {
+    checksum_file_range_with_digest(Digest::new(algorithm), file, offset, len)
+}
+
+/// Computes the CRC checksum of the `len` bytes at `offset` in an already open file, using custom
+/// CRC parameters.
+///
+/// Uses positioned reads, with the same cursor caveats as `checksum_file_range_from()`.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or an error of kind
+/// `UnexpectedEof` if the range extends past the end of the file.
+#[cfg(feature = "std")]
+pub fn checksum_file_range_from_with_params(
+    params: CrcParams,
+    file: &File,
+    offset: u64,
+    len: u64,
+) -> Result<u64, std::io::Error> {
+    checksum_file_range_with_digest(Digest::new_with_params(params), file, offset, len)
+}
+
+/// Computes the CRC checksum of a range of the given file using the specified Digest.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or the range extends past the
+/// end of the file.
+#[cfg(feature = "std")]
+fn checksum_file_range_with_digest(
+    mut digest: Digest,
+    file: &File,
+    offset: u64,
+    len: u64,
+) -> Result<u64, std::io::Error> {
+    let end = offset.checked_add(len).ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, "Range overflows u64")
+    })?;
+
+    // same chunk size as checksum_file(), but no larger than the range
+    let mut buf = vec![0; len.min(FILE_CHUNK_SIZE as u64) as usize];
+    let mut position = offset;
+
+    while position < end {
+        let wanted = (end - position).min(buf.len() as u64) as usize;
+
+        match read_at(file, &mut buf[..wanted], position) {
+            Ok(0) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "Range extends past the end of the file",
+                ))
+            }
+            Ok(n) => {
+                digest.update(&buf[..n]);
+                position += n as u64;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(digest.finalize())
+}
+
+/// Reads from the file at `offset`.
+///
+/// On Unix this neither uses nor moves the file's cursor. Windows' `seek_read` leaves the cursor
+/// after the bytes read, though concurrent reads are still safe since each has its own offset.
+/// Other platforms seek then read, which isn't safe to do concurrently on the same file.
+#[cfg(feature = "std")]
+fn read_at(file: &File, buf: &mut [u8], offset: u64) -> std::io::Result<usize> {
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::FileExt::read_at(file, buf, offset)
+    }
+
+    #[cfg(windows)]
+    {
+        std::os::windows::fs::FileExt::seek_read(file, buf, offset)
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        use std::io::{Seek, SeekFrom};
+
+        let mut file = file;
+        file.seek(SeekFrom::Start(offset))?;
+        file.read(buf)
+    }
+}
+
+/// Computes the CRC of each `block_size` block of the given file, and of the whole file, in a
+/// single read pass, using the specified algorithm.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read.
+///
+/// # Panics
+///
+/// Panics if `block_size` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::env;
+/// use crc_fast::{checksum_file_blocks, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// // for example/test purposes only, use your own file path
+/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
+/// let file_on_disk = file_path.to_str().unwrap();
+///
+/// let manifest = checksum_file_blocks(Crc32IsoHdlc, file_on_disk, 4).unwrap();
+///
+/// assert_eq!(manifest.blocks().len(), 3);
+/// assert_eq!(manifest.checksum(), 0xcbf43926);
+/// ```
+#[cfg(feature = "std")]
+pub fn checksum_file_blocks(
+    algorithm: CrcAlgorithm,
+    path: &str,
+    block_size: usize,
+) -> Result<BlockManifest, std::io::Error> {
+    let (_, params) = get_calculator_params(algorithm);
+
+    BlockManifest::from_file(params, path, block_size)
+}
+
+/// Computes the CRC of each `block_size` block of the given file, and of the whole file, in a
+/// single read pass, using custom CRC parameters.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read.
+///
+/// # Panics
+///
+/// Panics if `block_size` is zero.
+#[cfg(feature = "std")]
+pub fn checksum_file_blocks_with_params(
+    params: CrcParams,
+    path: &str,
+    block_size: usize,
+) -> Result<BlockManifest, std::io::Error> {
+    BlockManifest::from_file(params, path, block_size)
+}
+
 /// Computes the CRC checksum for the given file using the specified Digest.
 ///
 /// # Errors
@@ -785,23 +1834,49 @@ fn checksum_file_with_digest(
 ) -> Result<u64, std::io::Error> {
     let mut file = File::open(path)?;
 
-    // 512KiB KiB was fastest in my benchmarks on an Apple M2 Ultra
-    //
-    // 4KiB ~7GiB/s
-    // 64KiB ~22 GiB/s
-    // 512KiB ~24 GiB/s
-    let chunk_size = chunk_size.unwrap_or(524288);
+    read_chunks(&mut file, chunk_size.unwrap_or(FILE_CHUNK_SIZE), |chunk| {
+        digest.update(chunk)
+    })?;
 
-    let mut buf = vec![0; chunk_size];
+    Ok(digest.finalize())
+}
+
+/// The default number of bytes to read from a file at a time.
+///
+/// 512KiB was fastest in my benchmarks on an Apple M2 Ultra
+///
+/// 4KiB ~7GiB/s
+/// 64KiB ~22 GiB/s
+/// 512KiB ~24 GiB/s
+#[cfg(feature = "std")]
+pub(crate) const FILE_CHUNK_SIZE: usize = 524288;
 
-    while let Ok(n) = file.read(&mut buf) {
-        if n == 0 {
-            break;
+/// Reads `file` to the end, `chunk_size` bytes at a time, passing each chunk to `f`, and returns
+/// the number of bytes read.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read.
+#[cfg(feature = "std")]
+pub(crate) fn read_chunks(
+    file: &mut File,
+    chunk_size: usize,
+    mut f: impl FnMut(&[u8]),
+) -> Result<u64, std::io::Error> {
+    let mut buf = vec![0; chunk_size];
+    let mut len = 0u64;
+
+    loop {
+        match file.read(&mut buf) {
+            Ok(0) => return Ok(len),
+            Ok(n) => {
+                f(&buf[..n]);
+                len += n as u64;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
         }
-        digest.update(&buf[..n]);
     }
-
-    Ok(digest.finalize())
 }
 
 /// Combines two CRC checksums using the specified algorithm.
@@ -861,6 +1936,46 @@ pub fn checksum_combine_with_params(
     combine::checksums(checksum1, checksum2, checksum2_len, params)
 }
 
+/// Combines two CRC checksums using the specified algorithm, where the second was calculated over
+/// `checksum2_bit_len` bits, which needn't be a whole number of bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum_bits, checksum_combine_bits, CrcAlgorithm::Crc32Bzip2};
+///
+/// // 0xa5 followed by the high 3 bits of 0xa0, as two messages of 8 and 3 bits
+/// let checksum_1 = checksum_bits(Crc32Bzip2, &[0xa5], 8);
+/// let checksum_2 = checksum_bits(Crc32Bzip2, &[0xa0], 3);
+/// let checksum = checksum_combine_bits(Crc32Bzip2, checksum_1, checksum_2, 3);
+///
+/// assert_eq!(checksum, checksum_bits(Crc32Bzip2, &[0xa5, 0xa0], 11));
+/// ```
+#[inline(always)]
+pub fn checksum_combine_bits(
+    algorithm: CrcAlgorithm,
+    checksum1: u64,
+    checksum2: u64,
+    checksum2_bit_len: u64,
+) -> u64 {
+    let params = get_calculator_params(algorithm).1;
+
+    combine::checksums_bits(checksum1, checksum2, checksum2_bit_len, params)
+}
+
+/// Combines two CRC checksums using custom CRC parameters, where the second was calculated over
+/// `checksum2_bit_len` bits.
+///
+/// See `checksum_combine_bits()` for details.
+pub fn checksum_combine_bits_with_params(
+    params: CrcParams,
+    checksum1: u64,
+    checksum2: u64,
+    checksum2_bit_len: u64,
+) -> u64 {
+    combine::checksums_bits(checksum1, checksum2, checksum2_bit_len, params)
+}
+
 /// Returns the target used to calculate the CRC checksum for the specified algorithm.
 ///
 /// This function provides visibility into the active performance tier being used for CRC calculations.
@@ -869,7 +1984,7 @@ pub fn checksum_combine_with_params(
 ///
 /// The performance tier system provides graceful degradation across different hardware capabilities:
 /// - **AArch64**: `aarch64-aes-sha3` (highest) → `aarch64-aes-pmull` (baseline)
-/// - **x86_64**: `x86_64-avx512-vpclmulqdq` (highest) → `x86_64-avx512-pclmulqdq` (mid) → `x86_64-sse-pclmulqdq` (baseline)
+/// - **x86_64**: `x86_64-avx512-vpclmulqdq` (highest) → `x86_64-avx512-pclmulqdq` → `x86_64-avx2-vpclmulqdq` → `x86_64-sse-pclmulqdq` (baseline)
 /// - **x86**: `x86-sse-pclmulqdq` (baseline) → `software-fallback-tables` (fallback)
 /// - **Other architectures**: `software-fallback-tables`
 ///
@@ -913,94 +2028,84 @@ pub fn get_calculator_target(_algorithm: CrcAlgorithm) -> String {
 /// Returns the calculator function and parameters for the specified CRC algorithm.
 #[inline(always)]
 fn get_calculator_params(algorithm: CrcAlgorithm) -> (CalculatorFn, CrcParams) {
+    let params = get_params(algorithm);
+
+    (get_calculator(&params), params)
+}
+
+/// Returns the parameters for the specified predefined CRC algorithm.
+const fn get_params(algorithm: CrcAlgorithm) -> CrcParams {
     match algorithm {
-        CrcAlgorithm::Crc32Aixm => (Calculator::calculate as CalculatorFn, CRC32_AIXM),
-        CrcAlgorithm::Crc32Autosar => (Calculator::calculate as CalculatorFn, CRC32_AUTOSAR),
-        CrcAlgorithm::Crc32Base91D => (Calculator::calculate as CalculatorFn, CRC32_BASE91_D),
-        CrcAlgorithm::Crc32Bzip2 => (Calculator::calculate as CalculatorFn, CRC32_BZIP2),
-        CrcAlgorithm::Crc32CdRomEdc => (Calculator::calculate as CalculatorFn, CRC32_CD_ROM_EDC),
-        CrcAlgorithm::Crc32Cksum => (Calculator::calculate as CalculatorFn, CRC32_CKSUM),
+        CrcAlgorithm::Crc32Aixm => CRC32_AIXM,
+        CrcAlgorithm::Crc32Autosar => CRC32_AUTOSAR,
+        CrcAlgorithm::Crc32Base91D => CRC32_BASE91_D,
+        CrcAlgorithm::Crc32Bzip2 => CRC32_BZIP2,
+        CrcAlgorithm::Crc32CdRomEdc => CRC32_CD_ROM_EDC,
+        CrcAlgorithm::Crc32Cksum => CRC32_CKSUM,
         CrcAlgorithm::Crc32Custom => {
             panic!("Custom CRC-32 requires parameters via CrcParams::new()")
         }
-        CrcAlgorithm::Crc32Iscsi => (crc32_iscsi_calculator as CalculatorFn, CRC32_ISCSI),
-        CrcAlgorithm::Crc32IsoHdlc => (crc32_iso_hdlc_calculator as CalculatorFn, CRC32_ISO_HDLC),
-        CrcAlgorithm::Crc32Jamcrc => (Calculator::calculate as CalculatorFn, CRC32_JAMCRC),
-        CrcAlgorithm::Crc32Mef => (Calculator::calculate as CalculatorFn, CRC32_MEF),
-        CrcAlgorithm::Crc32Mpeg2 => (Calculator::calculate as CalculatorFn, CRC32_MPEG_2),
-        CrcAlgorithm::Crc32Xfer => (Calculator::calculate as CalculatorFn, CRC32_XFER),
+        CrcAlgorithm::Crc32Iscsi => CRC32_ISCSI,
+        CrcAlgorithm::Crc32IsoHdlc => CRC32_ISO_HDLC,
+        CrcAlgorithm::Crc32Jamcrc => CRC32_JAMCRC,
+        CrcAlgorithm::Crc32Mef => CRC32_MEF,
+        CrcAlgorithm::Crc32Mpeg2 => CRC32_MPEG_2,
+        CrcAlgorithm::Crc32Xfer => CRC32_XFER,
         CrcAlgorithm::Crc64Custom => {
             panic!("Custom CRC-64 requires parameters via CrcParams::new()")
         }
-        CrcAlgorithm::Crc64Ecma182 => (Calculator::calculate as CalculatorFn, CRC64_ECMA_182),
-        CrcAlgorithm::Crc64GoIso => (Calculator::calculate as CalculatorFn, CRC64_GO_ISO),
-        CrcAlgorithm::Crc64Ms => (Calculator::calculate as CalculatorFn, CRC64_MS),
-        CrcAlgorithm::Crc64Nvme => (Calculator::calculate as CalculatorFn, CRC64_NVME),
-        CrcAlgorithm::Crc64Redis => (Calculator::calculate as CalculatorFn, CRC64_REDIS),
-        CrcAlgorithm::Crc64We => (Calculator::calculate as CalculatorFn, CRC64_WE),
-        CrcAlgorithm::Crc64Xz => (Calculator::calculate as CalculatorFn, CRC64_XZ),
+        CrcAlgorithm::Crc64Ecma182 => CRC64_ECMA_182,
+        CrcAlgorithm::Crc64GoIso => CRC64_GO_ISO,
+        CrcAlgorithm::Crc64Ms => CRC64_MS,
+        CrcAlgorithm::Crc64Nvme => CRC64_NVME,
+        CrcAlgorithm::Crc64Redis => CRC64_REDIS,
+        CrcAlgorithm::Crc64We => CRC64_WE,
+        CrcAlgorithm::Crc64Xz => CRC64_XZ,
     }
 }
 
-/// Calculates the CRC-32/ISCSI ("crc32c" in many, but not all, implementations) checksum.
+/// Resolves the calculator function for the specified CRC parameters.
 ///
-/// Because both aarch64 and x86 have native hardware support for CRC-32/ISCSI, we can use
-/// fusion techniques to accelerate the calculation beyond what SIMD can do alone.
+/// This performs the feature detection and dispatch once, returning a concrete kernel which
+/// can be called directly for every subsequent update.
+///
+/// Because both aarch64 and x86 have native hardware support for CRC-32/ISCSI, and aarch64 also
+/// has native support for CRC-32/ISO-HDLC, we can use fusion techniques to accelerate those
+/// calculations beyond what SIMD can do alone. Without `std`, the native CRC instructions must be
+/// enabled at compile time.
 #[inline(always)]
-fn crc32_iscsi_calculator(state: u64, data: &[u8], _params: CrcParams) -> u64 {
-    #[cfg(all(target_arch = "aarch64", feature = "std"))]
+fn get_calculator(params: &CrcParams) -> CalculatorFn {
+    #[cfg(all(target_arch = "aarch64", any(feature = "std", target_feature = "crc")))]
     {
-        use crate::feature_detection::PerformanceTier;
-
-        let arch_ops = get_arch_ops();
-        match arch_ops.get_tier() {
-            PerformanceTier::AArch64AesSha3 | PerformanceTier::AArch64Aes => {
-                return fusion::crc32_iscsi(state as u32, data) as u64;
-            }
-            _ => {}
-        }
-    }
+        use crate::feature_detection::{get_arch_ops, PerformanceTier};
 
-    #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "std"))]
-    {
-        use crate::feature_detection::PerformanceTier;
-
-        let arch_ops = get_arch_ops();
-        match arch_ops.get_tier() {
-            PerformanceTier::X86_64Avx512Vpclmulqdq
-            | PerformanceTier::X86_64Avx512Pclmulqdq
-            | PerformanceTier::X86_64SsePclmulqdq
-            | PerformanceTier::X86SsePclmulqdq => {
-                return fusion::crc32_iscsi(state as u32, data) as u64;
+        let tier = get_arch_ops().get_tier();
+        if matches!(
+            tier,
+            PerformanceTier::AArch64AesSha3 | PerformanceTier::AArch64Aes
+        ) {
+            match params.algorithm {
+                CrcAlgorithm::Crc32Iscsi => return crc32::fusion::crc32_iscsi_kernel(tier),
+                CrcAlgorithm::Crc32IsoHdlc => return crc32::fusion::crc32_iso_hdlc_kernel(tier),
+                _ => {}
             }
-            _ => {}
         }
     }
 
-    Calculator::calculate(state, data, _params)
-}
-
-/// Calculates the CRC-32/ISO-HDLC ("crc32" in many, but not all, implementations) checksum.
-///
-/// Because aarch64 has native hardware support for CRC-32/ISO-HDLC, we can use fusion techniques
-/// to accelerate the calculation beyond what SIMD can do alone. x86 does not have native support,
-/// so we use the traditional calculation.
-#[inline(always)]
-fn crc32_iso_hdlc_calculator(state: u64, data: &[u8], _params: CrcParams) -> u64 {
-    #[cfg(all(target_arch = "aarch64", feature = "std"))]
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        any(feature = "std", target_feature = "sse4.2")
+    ))]
     {
         use crate::feature_detection::{get_arch_ops, PerformanceTier};
-        let arch_ops = get_arch_ops();
 
-        match arch_ops.get_tier() {
-            PerformanceTier::AArch64AesSha3 | PerformanceTier::AArch64Aes => {
-                return fusion::crc32_iso_hdlc(state as u32, data) as u64;
-            }
-            _ => {}
+        let tier = get_arch_ops().get_tier();
+        if params.algorithm == CrcAlgorithm::Crc32Iscsi && tier != PerformanceTier::SoftwareTable {
+            return crc32::fusion::crc32_iscsi_kernel(tier);
         }
     }
 
-    Calculator::calculate(state, data, _params)
+    arch::get_kernel(params)
 }
 
 #[cfg(test)]
@@ -1064,6 +2169,97 @@ mod lib {
         );
     }
 
+    #[test]
+    fn test_update_raw_kernel() {
+        // crc32c(~0, ...), crc32_le(~0, ...), crc32_be(~0, ...) and crc64_be(0, ...)
+        assert_eq!(
+            update_raw(CrcAlgorithm::Crc32Iscsi, 0xffffffff, TEST_CHECK_STRING),
+            0x1cf96d7c
+        );
+        assert_eq!(
+            update_raw(CrcAlgorithm::Crc32IsoHdlc, 0xffffffff, TEST_CHECK_STRING),
+            0x340bc6d9
+        );
+        assert_eq!(
+            update_raw(CrcAlgorithm::Crc32Bzip2, 0xffffffff, TEST_CHECK_STRING),
+            0x0376e6e7
+        );
+        assert_eq!(
+            update_raw(CrcAlgorithm::Crc64Ecma182, 0, TEST_CHECK_STRING),
+            0x6c40df5f0b497347
+        );
+
+        // iSCSI's data digest is the complement of crc32c(~0, ...)
+        assert_eq!(
+            !update_raw(CrcAlgorithm::Crc32Iscsi, 0xffffffff, TEST_CHECK_STRING) as u32,
+            0xe3069283
+        );
+
+        // seeds chain across buffers, as ext4 and btrfs rely on
+        let state = update_raw(CrcAlgorithm::Crc32Iscsi, 0xffffffff, b"1234");
+        assert_eq!(
+            update_raw(CrcAlgorithm::Crc32Iscsi, state, b"56789"),
+            0x1cf96d7c
+        );
+    }
+
+    #[test]
+    fn test_update_raw() {
+        let mut rng = rng();
+        let mut data = vec![0u8; 4096];
+        rng.fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+            let params = *config.get_params();
+            let check = config.get_check();
+
+            // a zero seed with no conditioning, against the reference implementation
+            let raw = match config {
+                AnyCrcTestConfig::CRC32(config) => {
+                    let reference = crc::Algorithm {
+                        init: 0,
+                        xorout: 0,
+                        ..*config.reference_impl.algorithm
+                    };
+                    crc::Crc::<u32>::new(Box::leak(Box::new(reference))).checksum(&data) as u64
+                }
+                AnyCrcTestConfig::CRC64(config) => {
+                    let reference = crc::Algorithm {
+                        init: 0,
+                        xorout: 0,
+                        ..*config.reference_impl.algorithm
+                    };
+                    crc::Crc::<u64>::new(Box::leak(Box::new(reference))).checksum(&data)
+                }
+            };
+            assert_eq!(
+                update_raw(algorithm, 0, &data),
+                raw,
+                "{}",
+                config.get_name()
+            );
+            assert_eq!(update_raw_with_params(params, 0, &data), raw);
+
+            // the algorithm's own init, finalized
+            let state = update_raw(algorithm, params.init, TEST_CHECK_STRING);
+            assert_eq!(raw_to_finalized(algorithm, state), check);
+            assert_eq!(raw_to_finalized_with_params(params, state), check);
+            assert_eq!(finalized_to_raw(algorithm, check), state);
+            assert_eq!(finalized_to_raw_with_params(params, check), state);
+
+            // resuming from a finalized checksum
+            let state = finalized_to_raw(algorithm, checksum(algorithm, b"1234"));
+            let state = update_raw(algorithm, state, b"56789");
+            assert_eq!(raw_to_finalized(algorithm, state), check);
+
+            // interoperating with Digest's raw state
+            let mut digest = Digest::new_with_init_state(algorithm, state);
+            digest.update(&data);
+            assert_eq!(digest.get_state(), update_raw(algorithm, state, &data));
+        }
+    }
+
     #[test]
     fn test_get_custom_params() {
         crate::cache::clear_cache();
@@ -1183,6 +2379,41 @@ mod lib {
         );
     }
 
+    #[test]
+    fn test_digest_small_updates() {
+        let mut rng = rng();
+
+        for config in TEST_ALL_CONFIGS {
+            let mut data = vec![0u8; 4096];
+            rng.fill(&mut data[..]);
+
+            let expected = config.checksum_with_reference(&data);
+
+            // per-record updates reuse the calculator resolved in Digest::new()
+            for size in [1, 16, 31, 64, 127, 256] {
+                let mut digest = Digest::new(config.get_algorithm());
+                for record in data.chunks(size) {
+                    digest.update(record);
+                }
+
+                assert_eq!(
+                    digest.finalize(),
+                    expected,
+                    "Mismatch CRC, {}, record size {}",
+                    config.get_name(),
+                    size
+                );
+            }
+
+            // predefined params should resolve to the same calculator as the algorithm
+            let mut digest = Digest::new_with_params(*config.get_params());
+            for record in data.chunks(16) {
+                digest.update(record);
+            }
+            assert_eq!(digest.finalize(), expected, "{}", config.get_name());
+        }
+    }
+
     fn check_digest(mut digest: Digest, check: u64) {
         digest.update(b"123");
         digest.update(b"456");
@@ -1256,6 +2487,243 @@ mod lib {
         );
     }
 
+    /// Skipping for Miri runs due to time constraints, underlying code already covered by other
+    /// tests.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_checksum_multi() {
+        let mut data = vec![0u8; 1024 * 1024 + 3];
+        rng().fill(&mut data[..]);
+
+        let lengths = [
+            0,
+            1,
+            16,
+            127,
+            128,
+            255,
+            256,
+            257,
+            511,
+            1024,
+            4099,
+            data.len(),
+        ];
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+
+            // mixed widths, reflected and forward
+            for other in [CrcAlgorithm::Crc64Nvme, CrcAlgorithm::Crc32Bzip2] {
+                for len in lengths {
+                    let buf = &data[..len];
+
+                    assert_eq!(
+                        checksum_multi(&[algorithm, other], buf),
+                        [checksum(algorithm, buf), checksum(other, buf)],
+                        "Mismatch for {algorithm:?} and {other:?} with length {len}",
+                    );
+                }
+            }
+        }
+
+        // unaligned starts, since the folded middle depends on alignment
+        let algorithms = [
+            CrcAlgorithm::Crc32IsoHdlc,
+            CrcAlgorithm::Crc32Iscsi,
+            CrcAlgorithm::Crc64Nvme,
+            CrcAlgorithm::Crc64Ecma182,
+        ];
+
+        for offset in 0..64 {
+            let buf = &data[offset..offset + 8192 + 129];
+
+            assert_eq!(
+                checksum_multi(&algorithms, buf),
+                algorithms.map(|algorithm| checksum(algorithm, buf)),
+                "Mismatch with offset {offset}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_copy_and_checksum() {
+        let mut data = vec![0u8; 1024 * 1024 + 3];
+        rng().fill(&mut data[..]);
+
+        let lengths = [0, 1, 16, 127, 128, 255, 256, 257, 1024, 4099, data.len()];
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+
+            for len in lengths {
+                let src = &data[..len];
+                let expected = checksum(algorithm, src);
+
+                let mut dst = vec![0u8; len];
+                assert_eq!(
+                    copy_and_checksum(algorithm, src, &mut dst),
+                    expected,
+                    "Mismatch for {algorithm:?} with length {len}",
+                );
+                assert_eq!(
+                    dst, src,
+                    "Copy mismatch for {algorithm:?} with length {len}"
+                );
+
+                let mut dst = vec![0u8; len];
+                assert_eq!(
+                    copy_and_checksum_non_temporal(algorithm, src, &mut dst),
+                    expected,
+                    "Non-temporal mismatch for {algorithm:?} with length {len}",
+                );
+                assert_eq!(
+                    dst, src,
+                    "Non-temporal copy mismatch for {algorithm:?} with length {len}"
+                );
+            }
+        }
+
+        // unaligned sources and destinations, since the stores depend on both alignments
+        let mut dst = vec![0u8; 8192 + 129 + 64];
+        for src_offset in [0, 1, 15, 16, 63] {
+            for dst_offset in [0, 1, 8, 16, 33] {
+                let src = &data[src_offset..src_offset + 8192 + 129];
+                let dst = &mut dst[dst_offset..dst_offset + src.len()];
+
+                for algorithm in [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme] {
+                    dst.fill(0);
+                    assert_eq!(
+                        copy_and_checksum_non_temporal(algorithm, src, dst),
+                        checksum(algorithm, src),
+                        "Mismatch with offsets {src_offset} and {dst_offset}",
+                    );
+                    assert_eq!(
+                        dst, src,
+                        "Copy mismatch with offsets {src_offset} and {dst_offset}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_copy_and_checksum_with_params() {
+        crate::cache::clear_cache();
+
+        for params in [
+            get_custom_crc32_reflected(),
+            get_custom_crc32_forward(),
+            get_custom_crc64_reflected(),
+            get_custom_crc64_forward(),
+        ] {
+            let mut dst = [0u8; 9];
+
+            assert_eq!(
+                copy_and_checksum_with_params(params, TEST_CHECK_STRING, &mut dst),
+                params.check
+            );
+            assert_eq!(&dst, TEST_CHECK_STRING);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "different lengths")]
+    fn test_copy_and_checksum_length_mismatch() {
+        let mut dst = [0u8; 8];
+
+        copy_and_checksum(CrcAlgorithm::Crc32IsoHdlc, TEST_CHECK_STRING, &mut dst);
+    }
+
+    #[test]
+    fn test_digest_update_copy() {
+        let mut data = vec![0u8; 64 * 1024];
+        rng().fill(&mut data[..]);
+
+        for algorithm in [CrcAlgorithm::Crc32Bzip2, CrcAlgorithm::Crc64Nvme] {
+            let mut dst = vec![0u8; data.len()];
+            let mut digest = Digest::new(algorithm);
+
+            // uneven updates, alternating store types, so both edge and folded paths see partial
+            // data
+            let mut start = 0;
+            let mut size = 1;
+            let mut non_temporal = false;
+            while start < data.len() {
+                let end = (start + size).min(data.len());
+                if non_temporal {
+                    digest.update_copy_non_temporal(&data[start..end], &mut dst[start..end]);
+                } else {
+                    digest.update_copy(&data[start..end], &mut dst[start..end]);
+                }
+                start = end;
+                size = size * 3 + 7;
+                non_temporal = !non_temporal;
+            }
+
+            assert_eq!(dst, data);
+            assert_eq!(digest.get_amount(), data.len() as u64);
+            assert_eq!(digest.finalize(), checksum(algorithm, &data));
+        }
+    }
+
+    #[test]
+    fn test_checksum_multi_with_params() {
+        crate::cache::clear_cache();
+
+        let params = [
+            get_custom_crc32_reflected(),
+            get_custom_crc32_forward(),
+            get_custom_crc64_reflected(),
+            get_custom_crc64_forward(),
+        ];
+
+        assert_eq!(
+            checksum_multi_with_params(params, TEST_CHECK_STRING),
+            params.map(|params| params.check)
+        );
+
+        let mut data = vec![0u8; 4096];
+        rng().fill(&mut data[..]);
+
+        assert_eq!(
+            checksum_multi_with_params(params, &data),
+            params.map(|params| checksum_with_params(params, &data))
+        );
+    }
+
+    #[test]
+    fn test_multi_digest() {
+        let mut data = vec![0u8; 64 * 1024];
+        rng().fill(&mut data[..]);
+
+        let algorithms = [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme];
+        let expected = algorithms.map(|algorithm| checksum(algorithm, &data));
+
+        let mut digest = MultiDigest::new(&algorithms);
+
+        // uneven updates, so both edge and folded paths see partial data
+        let mut rest = &data[..];
+        let mut size = 1;
+        while !rest.is_empty() {
+            let (chunk, remaining) = rest.split_at(size.min(rest.len()));
+            digest.update(chunk);
+            rest = remaining;
+            size = size * 3 + 7;
+        }
+
+        assert_eq!(digest.get_amount(), data.len() as u64);
+        assert_eq!(digest.finalize_reset(), expected);
+
+        // reset back to the initial states
+        assert_eq!(digest.get_amount(), 0);
+        digest.update(TEST_CHECK_STRING);
+        assert_eq!(
+            digest.finalize(),
+            algorithms.map(|algorithm| checksum(algorithm, TEST_CHECK_STRING))
+        );
+    }
+
     #[test]
     fn test_combine() {
         for config in TEST_ALL_CONFIGS {
@@ -1323,6 +2791,134 @@ mod lib {
         );
     }
 
+    /// Expands the first `bit_len` bits of `data` into the order the CRC processes them.
+    fn message_bits(data: &[u8], bit_len: usize, reflected: bool) -> Vec<u8> {
+        (0..bit_len)
+            .map(|i| {
+                let shift = if reflected { i % 8 } else { 7 - i % 8 };
+                (data[i / 8] >> shift) & 1
+            })
+            .collect()
+    }
+
+    /// Calculates the CRC of a message of up to 64 bits directly from its polynomial,
+    /// init(x) * x^n + m(x) * x^width mod P(x), independently of the bitwise implementation.
+    fn checksum_bits_reference(params: CrcParams, bits: &[u8]) -> u64 {
+        use crate::gf2::{clmul, mul_mod, reduce, reflect, x_pow_mod};
+
+        let width = params.width;
+        let convert = |value: u64| {
+            if params.refin {
+                reflect(value, width)
+            } else {
+                value
+            }
+        };
+        let message = bits.iter().fold(0u64, |m, &bit| (m << 1) | bit as u64);
+
+        let init = mul_mod(
+            convert(params.init),
+            x_pow_mod(bits.len() as u64, params.poly, width),
+            params.poly,
+            width,
+        );
+        let message = reduce(
+            clmul(message, x_pow_mod(width as u64, params.poly, width)),
+            params.poly,
+            width,
+        );
+
+        convert(init ^ message) ^ params.xorout
+    }
+
+    #[test]
+    fn test_checksum_bits() {
+        let mut data = [0u8; 8];
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            for bit_len in 0..=64 {
+                rng().fill(&mut data[..]);
+                let bits = message_bits(&data, bit_len, params.refin);
+                let expected = checksum_bits_reference(params, &bits);
+
+                assert_eq!(
+                    checksum_bits(config.get_algorithm(), &data, bit_len),
+                    expected,
+                    "{} bit_len {bit_len}",
+                    config.get_name()
+                );
+                assert_eq!(checksum_bits_with_params(params, &data, bit_len), expected);
+            }
+
+            // whole bytes match checksum()
+            assert_eq!(
+                checksum_bits(config.get_algorithm(), TEST_CHECK_STRING, 72),
+                config.get_check()
+            );
+        }
+    }
+
+    #[test]
+    fn test_digest_update_bits() {
+        let mut first = [0u8; 4];
+        let mut second = [0u8; 4];
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            for (len1, len2) in [(13, 20), (5, 3), (0, 7), (32, 32), (1, 31)] {
+                rng().fill(&mut first[..]);
+                rng().fill(&mut second[..]);
+
+                let mut bits = message_bits(&first, len1, params.refin);
+                bits.extend(message_bits(&second, len2, params.refin));
+                let expected = checksum_bits_reference(params, &bits);
+
+                // continuing from a partial byte
+                let mut digest = Digest::new_with_params(params);
+                digest.update_bits(&first, len1);
+                digest.update_bits(&second, len2);
+                assert_eq!(digest.finalize(), expected, "{}", config.get_name());
+                assert_eq!(digest.get_amount_bits(), (len1 + len2) as u64);
+                assert_eq!(digest.get_amount(), ((len1 + len2) / 8) as u64);
+
+                // combining
+                let mut digest1 = Digest::new(config.get_algorithm());
+                digest1.update_bits(&first, len1);
+                let mut digest2 = Digest::new(config.get_algorithm());
+                digest2.update_bits(&second, len2);
+
+                let checksum1 = digest1.finalize();
+                let checksum2 = digest2.finalize();
+                assert_eq!(
+                    checksum_combine_bits(
+                        config.get_algorithm(),
+                        checksum1,
+                        checksum2,
+                        len2 as u64
+                    ),
+                    expected
+                );
+                assert_eq!(
+                    checksum_combine_bits_with_params(params, checksum1, checksum2, len2 as u64),
+                    expected
+                );
+
+                digest1.combine(&digest2);
+                assert_eq!(digest1.finalize(), expected);
+                assert_eq!(digest1.get_amount_bits(), (len1 + len2) as u64);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Bit length exceeds data length")]
+    fn test_update_bits_too_long() {
+        Digest::new(CrcAlgorithm::Crc32IsoHdlc).update_bits(b"1", 9);
+    }
+
     /// Skipping for Miri runs due to isolation constraints, underlying code other than I/O already
     /// covered by other tests.
     #[test]
@@ -1344,6 +2940,18 @@ mod lib {
         std::fs::remove_file(test_file_path).unwrap();
     }
 
+    /// Skipping for Miri runs due to isolation constraints, underlying code other than I/O already
+    /// covered by other tests.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_checksum_file_read_error() {
+        // Directories open on Unix but fail to read, and fail to open on Windows, so either way the
+        // error must be returned rather than a checksum of whatever was read before it
+        for config in TEST_ALL_CONFIGS {
+            assert!(checksum_file(config.get_algorithm(), "test", None).is_err());
+        }
+    }
+
     /// Skipping for Miri runs due to isolation constraints, underlying code other than I/O already
     /// covered by other tests.
     #[test]
@@ -1390,6 +2998,101 @@ mod lib {
         std::fs::remove_file(test_file_path).unwrap();
     }
 
+    /// Skipping for Miri runs due to isolation constraints, underlying code other than I/O already
+    /// covered by other tests.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_checksum_file_range() {
+        let test_file_path = "test/test_crc32_hash_file_range.bin";
+        let mut data = vec![0u8; 1024 * 1024 + 123];
+        rng().fill(&mut data[..]);
+        if let Err(e) = write(test_file_path, &data) {
+            eprintln!("Skipping test due to write error: {}", e);
+            return;
+        }
+
+        let ranges = [(0, 0), (0, 1), (17, 1000), (1000, 600_000), (0, data.len())];
+
+        for config in TEST_ALL_CONFIGS {
+            for (offset, len) in ranges {
+                let result = checksum_file_range(
+                    config.get_algorithm(),
+                    test_file_path,
+                    offset as u64,
+                    len as u64,
+                )
+                .unwrap();
+
+                assert_eq!(
+                    result,
+                    config.checksum_with_reference(&data[offset..offset + len])
+                );
+            }
+        }
+
+        let params = get_custom_crc64_forward();
+        assert_eq!(
+            checksum_file_range_with_params(params, test_file_path, 5, 70_000).unwrap(),
+            checksum_with_params(params, &data[5..70_005])
+        );
+
+        let file = File::open(test_file_path).unwrap();
+        assert_eq!(
+            checksum_file_range_from_with_params(params, &file, 5, 70_000).unwrap(),
+            checksum_with_params(params, &data[5..70_005])
+        );
+
+        // ranges of the same open file from several threads at once
+        std::thread::scope(|scope| {
+            let file = &file;
+            let handles: Vec<_> = (0..4)
+                .map(|i| {
+                    scope.spawn(move || {
+                        checksum_file_range_from(
+                            CrcAlgorithm::Crc64Nvme,
+                            file,
+                            i * 250_000,
+                            250_000,
+                        )
+                        .unwrap()
+                    })
+                })
+                .collect();
+
+            for (i, handle) in handles.into_iter().enumerate() {
+                let range = &data[i * 250_000..(i + 1) * 250_000];
+                assert_eq!(
+                    handle.join().unwrap(),
+                    checksum(CrcAlgorithm::Crc64Nvme, range)
+                );
+            }
+        });
+
+        let len = data.len() as u64;
+        assert_eq!(
+            checksum_file_range(CrcAlgorithm::Crc32Iscsi, test_file_path, len - 1, 2)
+                .unwrap_err()
+                .kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+
+        // an empty range is fine anywhere
+        assert_eq!(
+            checksum_file_range(CrcAlgorithm::Crc32Iscsi, test_file_path, len + 1, 0).unwrap(),
+            checksum(CrcAlgorithm::Crc32Iscsi, b"")
+        );
+
+        assert_eq!(
+            checksum_file_range(CrcAlgorithm::Crc32Iscsi, test_file_path, u64::MAX, 2)
+                .unwrap_err()
+                .kind(),
+            std::io::ErrorKind::InvalidInput
+        );
+
+        drop(file);
+        std::fs::remove_file(test_file_path).unwrap();
+    }
+
     fn check_file(params: CrcParams, file_path: &str, check: u64) {
         let result = checksum_file_with_params(params, file_path, None).unwrap();
         assert_eq!(result, check);
@@ -1520,6 +3223,14 @@ mod lib {
             .exclude_item("ISO_HDLC_TARGET")
             .exclude_item("ISCSI_TARGET")
             .exclude_item("CrcParams")
+            // exclude zlib-compatible functions, which are declared by zlib.h
+            .exclude_item("crc32")
+            .exclude_item("crc32_z")
+            .exclude_item("crc32_combine")
+            .exclude_item("crc32_combine64")
+            .exclude_item("crc32_combine_gen")
+            .exclude_item("crc32_combine_gen64")
+            .exclude_item("crc32_combine_op")
             .rename_item("Digest", "CrcFastDigest")
             .with_style(Both)
             // generate C header
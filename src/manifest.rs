@@ -0,0 +1,508 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides per-block CRC manifests of files and buffers.
+//!
+//! Each block's CRC is calculated independently, and the whole-file CRC is combined from them
+//! rather than calculated in a second pass. Since every block but the last is the same length, the
+//! shift operator for combining them is built once, making each combine a single multiply modulo
+//! the polynomial.
+
+use crate::combine::{self, Shift};
+use crate::{
+    checksum_with_params, get_calculator_params, BlockManifest, CrcAlgorithm, CrcParams,
+    ManifestError,
+};
+use core::fmt::{Display, Formatter};
+
+#[cfg(feature = "std")]
+use crate::{read_chunks, FILE_CHUNK_SIZE};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Identifies a serialized `BlockManifest`, followed by the format version.
+const MANIFEST_MAGIC: &[u8] = b"CRCM";
+const MANIFEST_VERSION: u8 = 1;
+
+/// The size of the serialized header, up to and including the whole-file CRC.
+const MANIFEST_HEADER_SIZE: usize = 4 + 1 + 1 + 1 + 1 + 8 * 6;
+
+impl BlockManifest {
+    /// Creates a manifest of `data` in blocks of `block_size` bytes, using the specified CRC
+    /// algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum, BlockManifest, CrcAlgorithm::Crc32Iscsi};
+    ///
+    /// let manifest = BlockManifest::new(Crc32Iscsi, b"123456789", 4);
+    ///
+    /// assert_eq!(manifest.blocks().len(), 3);
+    /// assert_eq!(manifest.blocks()[2], checksum(Crc32Iscsi, b"9"));
+    /// assert_eq!(manifest.checksum(), 0xe3069283);
+    /// ```
+    pub fn new(algorithm: CrcAlgorithm, data: &[u8], block_size: usize) -> Self {
+        let (_, params) = get_calculator_params(algorithm);
+
+        Self::new_with_params(params, data, block_size)
+    }
+
+    /// Creates a manifest of `data` in blocks of `block_size` bytes, using custom CRC parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is zero.
+    pub fn new_with_params(params: CrcParams, data: &[u8], block_size: usize) -> Self {
+        assert!(block_size > 0, "Block size must be greater than zero");
+
+        let blocks = data
+            .chunks(block_size)
+            .map(|block| checksum_with_params(params, block))
+            .collect();
+
+        Self::from_blocks(params, block_size, data.len() as u64, blocks)
+    }
+
+    /// Creates a manifest of the file at `path` in a single read pass.
+    #[cfg(feature = "std")]
+    pub(crate) fn from_file(
+        params: CrcParams,
+        path: &str,
+        block_size: usize,
+    ) -> Result<Self, std::io::Error> {
+        assert!(block_size > 0, "Block size must be greater than zero");
+
+        let mut file = std::fs::File::open(path)?;
+
+        // read in large chunks regardless of the block size, same as checksum_file()
+        let mut digest = crate::Digest::new_with_params(params);
+        let mut blocks = Vec::new();
+
+        let len = read_chunks(&mut file, FILE_CHUNK_SIZE, |mut chunk| {
+            while !chunk.is_empty() {
+                let remaining = block_size - digest.get_amount() as usize;
+                let (head, tail) = chunk.split_at(remaining.min(chunk.len()));
+
+                digest.update(head);
+                if head.len() == remaining {
+                    blocks.push(digest.finalize_reset());
+                }
+
+                chunk = tail;
+            }
+        })?;
+
+        if digest.get_amount() > 0 {
+            blocks.push(digest.finalize());
+        }
+
+        Ok(Self::from_blocks(params, block_size, len, blocks))
+    }
+
+    /// Creates a manifest from its blocks' CRCs, combining them into the whole-file CRC.
+    fn from_blocks(params: CrcParams, block_size: usize, len: u64, blocks: Vec<u64>) -> Self {
+        let checksum = match blocks.split_first() {
+            None => checksum_with_params(params, &[]),
+            Some((&first, rest)) => {
+                let shift = Shift::new(block_size as u64, params);
+                let last = rest.len();
+
+                rest.iter().enumerate().fold(first, |crc, (i, &block)| {
+                    if i + 1 == last && len % block_size as u64 != 0 {
+                        combine::checksums(crc, block, len % block_size as u64, params)
+                    } else {
+                        shift.combine(crc, block)
+                    }
+                })
+            }
+        };
+
+        Self {
+            params,
+            block_size,
+            len,
+            blocks,
+            checksum,
+        }
+    }
+
+    /// Returns the number of bytes in each block, except possibly the last.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Returns the length of the whole file.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns true if the file is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the CRC of each block, in order.
+    pub fn blocks(&self) -> &[u64] {
+        &self.blocks
+    }
+
+    /// Returns the CRC of the whole file.
+    pub fn checksum(&self) -> u64 {
+        self.checksum
+    }
+
+    /// Returns the offset and length of each range which differs between this manifest and
+    /// `other`, in order, with adjacent changed blocks merged into a single range.
+    ///
+    /// If the files are different lengths, the ranges cover the longer one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ManifestError::Incompatible` if the manifests have different block sizes or CRC
+    /// parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{BlockManifest, CrcAlgorithm::Crc64Nvme};
+    ///
+    /// let old = BlockManifest::new(Crc64Nvme, b"aaaabbbbccccdddd", 4);
+    /// let new = BlockManifest::new(Crc64Nvme, b"aaaaBBBBCCCCddddee", 4);
+    ///
+    /// assert_eq!(old.diff(&new), Ok(vec![(4, 8), (16, 2)]));
+    /// ```
+    pub fn diff(&self, other: &Self) -> Result<Vec<(u64, u64)>, ManifestError> {
+        if self.block_size != other.block_size || !same_params(&self.params, &other.params) {
+            return Err(ManifestError::Incompatible);
+        }
+
+        let block_size = self.block_size as u64;
+        let len = self.len.max(other.len);
+        let mut ranges: Vec<(u64, u64)> = Vec::new();
+
+        for i in 0..self.blocks.len().max(other.blocks.len()) {
+            if self.blocks.get(i) == other.blocks.get(i) && self.block_len(i) == other.block_len(i)
+            {
+                continue;
+            }
+
+            let offset = i as u64 * block_size;
+            let end = (offset + block_size).min(len);
+
+            match ranges.last_mut() {
+                Some((start, range_len)) if *start + *range_len == offset => {
+                    *range_len = end - *start
+                }
+                _ => ranges.push((offset, end - offset)),
+            }
+        }
+
+        Ok(ranges)
+    }
+
+    /// Returns the length of the block at `index`, or zero past the end of the file.
+    fn block_len(&self, index: usize) -> u64 {
+        let block_size = self.block_size as u64;
+
+        self.len
+            .saturating_sub(index as u64 * block_size)
+            .min(block_size)
+    }
+
+    /// Serializes the manifest to a compact binary form, storing each block's CRC in just the
+    /// bytes its width needs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{BlockManifest, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let manifest = BlockManifest::new(Crc32IsoHdlc, &[0u8; 10_000], 4096);
+    /// let bytes = manifest.to_bytes();
+    ///
+    /// let restored = BlockManifest::from_bytes(Crc32IsoHdlc, &bytes).unwrap();
+    /// assert_eq!(restored.blocks(), manifest.blocks());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let crc_size = self.crc_size();
+        let mut bytes = Vec::with_capacity(MANIFEST_HEADER_SIZE + self.blocks.len() * crc_size);
+
+        bytes.extend_from_slice(MANIFEST_MAGIC);
+        bytes.push(MANIFEST_VERSION);
+        bytes.push(self.params.width);
+        bytes.push(self.params.refin as u8);
+        bytes.push(self.params.refout as u8);
+
+        for value in [
+            self.params.poly,
+            self.params.init,
+            self.params.xorout,
+            self.block_size as u64,
+            self.len,
+            self.checksum,
+        ] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        for crc in &self.blocks {
+            bytes.extend_from_slice(&crc.to_le_bytes()[..crc_size]);
+        }
+
+        bytes
+    }
+
+    /// Deserializes a manifest from `to_bytes()`, using the specified CRC algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ManifestError::Malformed` if the bytes are malformed, or the manifest was taken
+    /// with a different CRC algorithm.
+    pub fn from_bytes(algorithm: CrcAlgorithm, bytes: &[u8]) -> Result<Self, ManifestError> {
+        let (_, params) = get_calculator_params(algorithm);
+
+        Self::from_bytes_with_params(params, bytes)
+    }
+
+    /// Deserializes a manifest from `to_bytes()`, using custom CRC parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ManifestError::Malformed` if the bytes are malformed, or the manifest was taken
+    /// with different CRC parameters.
+    pub fn from_bytes_with_params(params: CrcParams, bytes: &[u8]) -> Result<Self, ManifestError> {
+        let (header, crcs) = bytes
+            .split_at_checked(MANIFEST_HEADER_SIZE)
+            .ok_or(ManifestError::Malformed)?;
+
+        let value =
+            |offset: usize| u64::from_le_bytes(header[offset..offset + 8].try_into().unwrap());
+        let [poly, init, xorout, block_size, len, checksum] = [8, 16, 24, 32, 40, 48].map(value);
+
+        if &header[..4] != MANIFEST_MAGIC
+            || header[4] != MANIFEST_VERSION
+            || header[5] != params.width
+            || header[6] != params.refin as u8
+            || header[7] != params.refout as u8
+            || (poly, init, xorout) != (params.poly, params.init, params.xorout)
+            || block_size == 0
+            || block_size > usize::MAX as u64
+        {
+            return Err(ManifestError::Malformed);
+        }
+
+        let crc_size = (params.width as usize).div_ceil(8);
+        if len.div_ceil(block_size).checked_mul(crc_size as u64) != Some(crcs.len() as u64) {
+            return Err(ManifestError::Malformed);
+        }
+
+        let blocks = crcs
+            .chunks_exact(crc_size)
+            .map(|crc| {
+                let mut value = [0; 8];
+                value[..crc_size].copy_from_slice(crc);
+
+                u64::from_le_bytes(value)
+            })
+            .collect();
+
+        let manifest = Self::from_blocks(params, block_size as usize, len, blocks);
+
+        // the stored whole-file CRC catches corruption of the block CRCs
+        if manifest.checksum != checksum {
+            return Err(ManifestError::Malformed);
+        }
+
+        Ok(manifest)
+    }
+
+    /// Returns the number of bytes each serialized block CRC takes.
+    fn crc_size(&self) -> usize {
+        (self.params.width as usize).div_ceil(8)
+    }
+}
+
+/// Returns true if both sets of parameters calculate the same CRC.
+fn same_params(a: &CrcParams, b: &CrcParams) -> bool {
+    (a.width, a.poly, a.init, a.refin, a.refout, a.xorout)
+        == (b.width, b.poly, b.init, b.refin, b.refout, b.xorout)
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ManifestError::Incompatible => {
+                write!(f, "Manifests have different block sizes or CRC parameters")
+            }
+            ManifestError::Malformed => write!(f, "Malformed manifest"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ManifestError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use crate::{checksum, checksum_file_blocks_with_params};
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_all_configs() {
+        let mut data = vec![0u8; 10_000];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            for (len, block_size) in [(0, 64), (1, 64), (64, 64), (10_000, 1), (10_000, 4096)] {
+                let data = &data[..len];
+                let manifest = BlockManifest::new_with_params(params, data, block_size);
+
+                assert_eq!(manifest.len(), len as u64);
+                assert_eq!(manifest.blocks().len(), len.div_ceil(block_size));
+                assert_eq!(
+                    manifest.checksum(),
+                    checksum_with_params(params, data),
+                    "{} {len} {block_size}",
+                    config.get_name()
+                );
+
+                for (crc, block) in manifest.blocks().iter().zip(data.chunks(block_size)) {
+                    assert_eq!(*crc, checksum_with_params(params, block));
+                }
+            }
+        }
+    }
+
+    /// Skipping for Miri runs due to isolation constraints, underlying code other than I/O already
+    /// covered by other tests.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_file() {
+        let mut data = vec![0u8; 1_500_000];
+        rng().fill(&mut data[..]);
+
+        let test_file_path = "test/test_manifest_file.bin";
+        if let Err(e) = std::fs::write(test_file_path, &data) {
+            eprintln!("Skipping test due to write error: {}", e);
+            return;
+        }
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            for block_size in [4096, 1_000_000, 3_000_000] {
+                let manifest =
+                    checksum_file_blocks_with_params(params, test_file_path, block_size).unwrap();
+
+                assert_eq!(
+                    manifest.blocks(),
+                    BlockManifest::new_with_params(params, &data, block_size).blocks()
+                );
+                assert_eq!(manifest.checksum(), checksum_with_params(params, &data));
+            }
+        }
+
+        std::fs::remove_file(test_file_path).unwrap();
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut old = vec![0u8; 10_000];
+        rng().fill(&mut old[..]);
+
+        let mut new = old.clone();
+        new[0] ^= 1;
+        new[4100] ^= 1;
+        new[8191] ^= 1;
+        new.extend_from_slice(b"appended");
+
+        let old = BlockManifest::new(CrcAlgorithm::Crc32Iscsi, &old, 1024);
+        let new = BlockManifest::new(CrcAlgorithm::Crc32Iscsi, &new, 1024);
+
+        // the appended bytes change the last, partial, block too
+        assert_eq!(
+            old.diff(&new),
+            Ok(vec![(0, 1024), (4096, 1024), (7168, 1024), (9216, 792)])
+        );
+        assert_eq!(new.diff(&old), old.diff(&new));
+        assert_eq!(old.diff(&old), Ok(vec![]));
+
+        let other = BlockManifest::new(CrcAlgorithm::Crc32Iscsi, b"", 512);
+        assert_eq!(old.diff(&other), Err(ManifestError::Incompatible));
+
+        let other = BlockManifest::new(CrcAlgorithm::Crc32IsoHdlc, b"", 1024);
+        assert_eq!(old.diff(&other), Err(ManifestError::Incompatible));
+
+        // compared with an empty file, everything changed
+        let empty = BlockManifest::new(CrcAlgorithm::Crc32Iscsi, b"", 1024);
+        assert_eq!(empty.diff(&old), Ok(vec![(0, 10_000)]));
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut data = vec![0u8; 10_000];
+        rng().fill(&mut data[..]);
+
+        for algorithm in [CrcAlgorithm::Crc32IsoHdlc, CrcAlgorithm::Crc64Nvme] {
+            let manifest = BlockManifest::new(algorithm, &data, 4096);
+            let bytes = manifest.to_bytes();
+
+            let crc_size = if algorithm == CrcAlgorithm::Crc64Nvme {
+                8
+            } else {
+                4
+            };
+            assert_eq!(bytes.len(), MANIFEST_HEADER_SIZE + 3 * crc_size);
+
+            let restored = BlockManifest::from_bytes(algorithm, &bytes).unwrap();
+            assert_eq!(restored.block_size(), 4096);
+            assert_eq!(restored.len(), 10_000);
+            assert_eq!(restored.blocks(), manifest.blocks());
+            assert_eq!(restored.checksum(), checksum(algorithm, &data));
+        }
+
+        let bytes = BlockManifest::new(CrcAlgorithm::Crc32IsoHdlc, &data, 4096).to_bytes();
+
+        for invalid in [
+            &bytes[..bytes.len() - 1],
+            &bytes[..MANIFEST_HEADER_SIZE],
+            &[],
+        ] {
+            assert_eq!(
+                BlockManifest::from_bytes(CrcAlgorithm::Crc32IsoHdlc, invalid).unwrap_err(),
+                ManifestError::Malformed
+            );
+        }
+
+        assert_eq!(
+            BlockManifest::from_bytes(CrcAlgorithm::Crc32Iscsi, &bytes).unwrap_err(),
+            ManifestError::Malformed
+        );
+
+        // the same parameters but refout
+        let (_, params) = get_calculator_params(CrcAlgorithm::Crc32IsoHdlc);
+        let params = CrcParams {
+            refout: false,
+            ..params
+        };
+        assert_eq!(
+            BlockManifest::from_bytes_with_params(params, &bytes).unwrap_err(),
+            ManifestError::Malformed
+        );
+
+        // a corrupted block CRC
+        let mut corrupted = bytes.clone();
+        corrupted[MANIFEST_HEADER_SIZE] ^= 1;
+        assert_eq!(
+            BlockManifest::from_bytes(CrcAlgorithm::Crc32IsoHdlc, &corrupted).unwrap_err(),
+            ManifestError::Malformed
+        );
+    }
+}
@@ -0,0 +1,540 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Masked CRC-32C, as stored by LevelDB, RocksDB, Snappy framing, TFRecord and Arrow IPC.
+//!
+//! These formats don't store the CRC-32C of their data directly, since computing the CRC of data
+//! which itself contains CRCs is problematic. Instead they store it rotated right by 15 bits,
+//! plus the constant `0xa282ead8`.
+//!
+//! This module also walks [Snappy framing format] streams and [TFRecord] files, verifying each
+//! chunk or record's masked CRC and reporting which one failed.
+//!
+//! [Snappy framing format]: https://github.com/google/snappy/blob/main/framing_format.txt
+//! [TFRecord]: https://www.tensorflow.org/tutorials/load_data/tfrecord
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::masked::{checksum, mask, unmask};
+//!
+//! let masked = checksum(b"123456789");
+//!
+//! assert_eq!(masked, mask(0xe3069283));
+//! assert_eq!(unmask(masked), 0xe3069283);
+//! ```
+
+use crate::CrcAlgorithm;
+use core::fmt::{Display, Formatter};
+
+const MASK_DELTA: u32 = 0xa282ead8;
+
+/// The Snappy framing format's stream identifier chunk data.
+const SNAPPY_IDENTIFIER: &[u8] = b"sNaPpY";
+
+/// The maximum uncompressed size of a Snappy framing data chunk.
+const SNAPPY_MAX_UNCOMPRESSED: usize = 65536;
+
+/// Masks a CRC-32C for storage.
+#[inline(always)]
+pub fn mask(crc: u32) -> u32 {
+    crc.rotate_right(15).wrapping_add(MASK_DELTA)
+}
+
+/// Recovers the CRC-32C from a masked value.
+#[inline(always)]
+pub fn unmask(masked: u32) -> u32 {
+    masked.wrapping_sub(MASK_DELTA).rotate_left(15)
+}
+
+/// Computes the masked CRC-32C of `data`.
+#[inline(always)]
+pub fn checksum(data: &[u8]) -> u32 {
+    mask(crate::checksum(CrcAlgorithm::Crc32Iscsi, data) as u32)
+}
+
+/// An error in a Snappy framing stream or TFRecord file, and where it occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordError {
+    /// The index of the chunk or record, counting from 0.
+    pub index: usize,
+    /// The byte offset of the chunk or record in the stream.
+    pub offset: usize,
+    pub kind: RecordErrorKind,
+}
+
+/// The kinds of [`RecordError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordErrorKind {
+    /// The stream ends partway through the chunk or record.
+    Truncated,
+    /// A Snappy framing stream doesn't start with a stream identifier.
+    MissingStreamIdentifier,
+    /// A Snappy framing stream identifier isn't `sNaPpY`.
+    InvalidStreamIdentifier,
+    /// A Snappy framing chunk has a reserved type (0x02-0x7f) which can't be skipped.
+    UnskippableChunk(u8),
+    /// A Snappy framing chunk is too short to hold a CRC, or too long for its type.
+    InvalidLength,
+    /// A TFRecord length doesn't match its masked CRC.
+    LengthChecksumMismatch,
+    /// The data doesn't match its CRC. Both values are unmasked.
+    ChecksumMismatch { expected: u32, actual: u32 },
+}
+
+impl Display for RecordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Record {} at offset {}: ", self.index, self.offset)?;
+
+        match self.kind {
+            RecordErrorKind::Truncated => write!(f, "truncated"),
+            RecordErrorKind::MissingStreamIdentifier => write!(f, "missing stream identifier"),
+            RecordErrorKind::InvalidStreamIdentifier => write!(f, "invalid stream identifier"),
+            RecordErrorKind::UnskippableChunk(chunk_type) => {
+                write!(f, "reserved unskippable chunk type 0x{chunk_type:02x}")
+            }
+            RecordErrorKind::InvalidLength => write!(f, "invalid chunk length"),
+            RecordErrorKind::LengthChecksumMismatch => write!(f, "length checksum mismatch"),
+            RecordErrorKind::ChecksumMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "checksum mismatch, expected 0x{expected:08x}, got 0x{actual:08x}"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RecordError {}
+
+/// A chunk from a Snappy framing stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnappyChunk<'a> {
+    /// The byte offset of the chunk header in the stream.
+    pub offset: usize,
+    /// The chunk type: 0x00 for compressed data, 0x01 for uncompressed data, 0xff for the stream
+    /// identifier, and otherwise padding or a skippable reserved type.
+    pub chunk_type: u8,
+    /// The unmasked CRC-32C of the uncompressed data, for data chunks.
+    pub checksum: Option<u32>,
+    /// The chunk's data, after the CRC for data chunks.
+    pub data: &'a [u8],
+}
+
+impl SnappyChunk<'_> {
+    /// Verifies the uncompressed data against the chunk's CRC. For compressed chunks, this is the
+    /// data after decompression. Chunks without a CRC always verify.
+    pub fn verify(&self, uncompressed: &[u8]) -> bool {
+        match self.checksum {
+            Some(expected) => {
+                expected == crate::checksum(CrcAlgorithm::Crc32Iscsi, uncompressed) as u32
+            }
+            None => true,
+        }
+    }
+}
+
+/// Returns an iterator over the chunks in a Snappy framing stream.
+///
+/// The stream's structure and the CRCs of uncompressed chunks are verified as it goes, and
+/// iteration stops after the first error. Compressed chunks' CRCs cover the data after
+/// decompression, so they're left to the caller, with [`SnappyChunk::verify`].
+pub fn snappy_chunks(stream: &[u8]) -> SnappyChunks<'_> {
+    SnappyChunks {
+        stream,
+        offset: 0,
+        index: 0,
+        done: false,
+    }
+}
+
+/// Verifies a Snappy framing stream, returning the number of chunks.
+///
+/// See [`snappy_chunks`] for what's verified.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::masked::{checksum, verify_snappy_framed};
+///
+/// let mut stream = b"\xff\x06\x00\x00sNaPpY".to_vec();
+///
+/// // an uncompressed chunk holding "hello"
+/// stream.extend_from_slice(&[0x01, 9, 0, 0]);
+/// stream.extend_from_slice(&checksum(b"hello").to_le_bytes());
+/// stream.extend_from_slice(b"hello");
+///
+/// assert_eq!(verify_snappy_framed(&stream), Ok(2));
+/// ```
+pub fn verify_snappy_framed(stream: &[u8]) -> Result<usize, RecordError> {
+    snappy_chunks(stream).try_fold(0, |count, chunk| chunk.map(|_| count + 1))
+}
+
+/// An iterator over the chunks in a Snappy framing stream, returned by [`snappy_chunks`].
+#[derive(Debug, Clone)]
+pub struct SnappyChunks<'a> {
+    stream: &'a [u8],
+    offset: usize,
+    index: usize,
+    done: bool,
+}
+
+impl<'a> SnappyChunks<'a> {
+    /// Returns the chunk and its total size, including the header.
+    fn chunk(&self) -> Result<(SnappyChunk<'a>, usize), RecordErrorKind> {
+        let rest = &self.stream[self.offset..];
+        let header = rest.get(..4).ok_or(RecordErrorKind::Truncated)?;
+        let chunk_type = header[0];
+        let length = u32::from_le_bytes([header[1], header[2], header[3], 0]) as usize;
+        let body = rest.get(4..4 + length).ok_or(RecordErrorKind::Truncated)?;
+
+        if self.index == 0 && chunk_type != 0xff {
+            return Err(RecordErrorKind::MissingStreamIdentifier);
+        }
+
+        let mut chunk = SnappyChunk {
+            offset: self.offset,
+            chunk_type,
+            checksum: None,
+            data: body,
+        };
+
+        match chunk_type {
+            0xff if body != SNAPPY_IDENTIFIER => Err(RecordErrorKind::InvalidStreamIdentifier),
+            0x00 | 0x01 => {
+                if length < 4 || (chunk_type == 0x01 && length - 4 > SNAPPY_MAX_UNCOMPRESSED) {
+                    return Err(RecordErrorKind::InvalidLength);
+                }
+
+                let expected = unmask(u32::from_le_bytes(body[..4].try_into().unwrap()));
+                chunk.checksum = Some(expected);
+                chunk.data = &body[4..];
+
+                if chunk_type == 0x01 {
+                    let actual = crate::checksum(CrcAlgorithm::Crc32Iscsi, chunk.data) as u32;
+                    if actual != expected {
+                        return Err(RecordErrorKind::ChecksumMismatch { expected, actual });
+                    }
+                }
+
+                Ok((chunk, 4 + length))
+            }
+            0x02..=0x7f => Err(RecordErrorKind::UnskippableChunk(chunk_type)),
+            _ => Ok((chunk, 4 + length)),
+        }
+    }
+}
+
+impl<'a> Iterator for SnappyChunks<'a> {
+    type Item = Result<SnappyChunk<'a>, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.offset == self.stream.len() {
+            return None;
+        }
+
+        match self.chunk() {
+            Ok((chunk, size)) => {
+                self.offset += size;
+                self.index += 1;
+
+                Some(Ok(chunk))
+            }
+            Err(kind) => {
+                self.done = true;
+
+                Some(Err(RecordError {
+                    index: self.index,
+                    offset: self.offset,
+                    kind,
+                }))
+            }
+        }
+    }
+}
+
+/// Returns an iterator over the records in a TFRecord file, verifying each record's length and
+/// data CRCs. Iteration stops after the first error.
+pub fn tfrecords(stream: &[u8]) -> TfRecords<'_> {
+    TfRecords {
+        stream,
+        offset: 0,
+        index: 0,
+        done: false,
+    }
+}
+
+/// Verifies a TFRecord file, returning the number of records.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::masked::{checksum, verify_tfrecords};
+///
+/// let mut file = Vec::new();
+/// for record in [b"hello".as_slice(), b"world"] {
+///     let length = (record.len() as u64).to_le_bytes();
+///
+///     file.extend_from_slice(&length);
+///     file.extend_from_slice(&checksum(&length).to_le_bytes());
+///     file.extend_from_slice(record);
+///     file.extend_from_slice(&checksum(record).to_le_bytes());
+/// }
+///
+/// assert_eq!(verify_tfrecords(&file), Ok(2));
+///
+/// file[14] ^= 1;
+/// let error = verify_tfrecords(&file).unwrap_err();
+///
+/// assert_eq!((error.index, error.offset), (0, 0));
+/// ```
+pub fn verify_tfrecords(stream: &[u8]) -> Result<usize, RecordError> {
+    tfrecords(stream).try_fold(0, |count, record| record.map(|_| count + 1))
+}
+
+/// An iterator over the records in a TFRecord file, returned by [`tfrecords`].
+#[derive(Debug, Clone)]
+pub struct TfRecords<'a> {
+    stream: &'a [u8],
+    offset: usize,
+    index: usize,
+    done: bool,
+}
+
+impl<'a> TfRecords<'a> {
+    /// Returns the record's data and its total size, including the length and CRCs.
+    fn record(&self) -> Result<(&'a [u8], usize), RecordErrorKind> {
+        let rest = &self.stream[self.offset..];
+        let header = rest.get(..12).ok_or(RecordErrorKind::Truncated)?;
+
+        let length = &header[..8];
+        if checksum(length) != u32::from_le_bytes(header[8..].try_into().unwrap()) {
+            return Err(RecordErrorKind::LengthChecksumMismatch);
+        }
+
+        let length = usize::try_from(u64::from_le_bytes(length.try_into().unwrap()))
+            .map_err(|_| RecordErrorKind::Truncated)?;
+        let size = length
+            .checked_add(16)
+            .filter(|&size| size <= rest.len())
+            .ok_or(RecordErrorKind::Truncated)?;
+
+        let data = &rest[12..12 + length];
+        let expected = unmask(u32::from_le_bytes(
+            rest[12 + length..size].try_into().unwrap(),
+        ));
+        let actual = crate::checksum(CrcAlgorithm::Crc32Iscsi, data) as u32;
+        if actual != expected {
+            return Err(RecordErrorKind::ChecksumMismatch { expected, actual });
+        }
+
+        Ok((data, size))
+    }
+}
+
+impl<'a> Iterator for TfRecords<'a> {
+    type Item = Result<&'a [u8], RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.offset == self.stream.len() {
+            return None;
+        }
+
+        match self.record() {
+            Ok((data, size)) => {
+                self.offset += size;
+                self.index += 1;
+
+                Some(Ok(data))
+            }
+            Err(kind) => {
+                self.done = true;
+
+                Some(Err(RecordError {
+                    index: self.index,
+                    offset: self.offset,
+                    kind,
+                }))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rng, Rng};
+
+    fn snappy_stream(chunks: &[(u8, &[u8])]) -> Vec<u8> {
+        let mut stream = b"\xff\x06\x00\x00sNaPpY".to_vec();
+
+        for &(chunk_type, data) in chunks {
+            let mut body = Vec::new();
+            if chunk_type <= 0x01 {
+                body.extend_from_slice(&checksum(data).to_le_bytes());
+            }
+            body.extend_from_slice(data);
+
+            stream.push(chunk_type);
+            stream.extend_from_slice(&(body.len() as u32).to_le_bytes()[..3]);
+            stream.extend_from_slice(&body);
+        }
+
+        stream
+    }
+
+    fn tfrecord_file(records: &[&[u8]]) -> Vec<u8> {
+        let mut file = Vec::new();
+
+        for record in records {
+            let length = (record.len() as u64).to_le_bytes();
+            file.extend_from_slice(&length);
+            file.extend_from_slice(&checksum(&length).to_le_bytes());
+            file.extend_from_slice(record);
+            file.extend_from_slice(&checksum(record).to_le_bytes());
+        }
+
+        file
+    }
+
+    #[test]
+    fn test_mask() {
+        // rotr(0xe3069283, 15) + 0xa282ead8, calculated independently
+        assert_eq!(mask(0xe3069283), 0xc78ab0e5);
+        assert_eq!(checksum(b"123456789"), 0xc78ab0e5);
+
+        for _ in 0..1000 {
+            let crc = rng().random::<u32>();
+
+            assert_eq!(unmask(mask(crc)), crc);
+            assert_ne!(mask(crc), crc);
+            assert_ne!(mask(mask(crc)), mask(crc));
+        }
+    }
+
+    #[test]
+    fn test_snappy_chunks() {
+        let mut data = vec![0u8; 1000];
+        rng().fill(&mut data[..]);
+
+        let stream = snappy_stream(&[
+            (0x01, &data[..500]),
+            (0xfe, &[0u8; 10]),
+            (0x00, b"compressed"),
+            (0x80, b"skippable"),
+            (0x01, &data[500..]),
+            (0x01, &[]),
+        ]);
+
+        assert_eq!(verify_snappy_framed(&stream), Ok(7));
+
+        let chunks: Vec<_> = snappy_chunks(&stream).map(Result::unwrap).collect();
+        assert_eq!(chunks[1].data, &data[..500]);
+        assert_eq!(chunks[2].chunk_type, 0xfe);
+        assert_eq!(chunks[2].checksum, None);
+        assert_eq!(chunks[5].offset, 10 + 508 + 14 + 18 + 13);
+
+        // compressed chunks are verified against their decompressed data by the caller
+        assert!(chunks[3].verify(b"compressed"));
+        assert!(!chunks[3].verify(b"decompressed"));
+
+        assert_eq!(verify_snappy_framed(&[]), Ok(0));
+    }
+
+    #[test]
+    fn test_snappy_errors() {
+        let stream = snappy_stream(&[(0x01, b"first"), (0x01, b"second"), (0x01, b"third")]);
+        let error = |stream: &[u8]| verify_snappy_framed(stream).unwrap_err();
+
+        // corrupt the second chunk's data
+        let mut corrupt = stream.clone();
+        corrupt[10 + 13 + 8] ^= 1;
+        let result = error(&corrupt);
+        assert_eq!((result.index, result.offset), (2, 23));
+        assert!(matches!(
+            result.kind,
+            RecordErrorKind::ChecksumMismatch { .. }
+        ));
+
+        // iteration stops at the error
+        assert_eq!(snappy_chunks(&corrupt).count(), 3);
+
+        assert_eq!(
+            error(&stream[..stream.len() - 1]).kind,
+            RecordErrorKind::Truncated
+        );
+        assert_eq!(error(&stream[..2]).kind, RecordErrorKind::Truncated);
+        assert_eq!(
+            error(&stream[10..]).kind,
+            RecordErrorKind::MissingStreamIdentifier
+        );
+
+        let mut invalid = stream.clone();
+        invalid[4] = b'S';
+        assert_eq!(
+            error(&invalid).kind,
+            RecordErrorKind::InvalidStreamIdentifier
+        );
+
+        let mut reserved = stream.clone();
+        reserved[10] = 0x02;
+        assert_eq!(
+            error(&reserved).kind,
+            RecordErrorKind::UnskippableChunk(0x02)
+        );
+
+        // a compressed chunk too short to hold its CRC
+        let mut short = snappy_stream(&[(0xfe, &[])]);
+        short[10] = 0x00;
+        assert_eq!(error(&short).kind, RecordErrorKind::InvalidLength);
+    }
+
+    #[test]
+    fn test_tfrecords() {
+        let mut data = vec![0u8; 1000];
+        rng().fill(&mut data[..]);
+
+        let file = tfrecord_file(&[&data[..100], &[], &data[100..]]);
+
+        assert_eq!(verify_tfrecords(&file), Ok(3));
+        assert_eq!(
+            tfrecords(&file).map(Result::unwrap).collect::<Vec<_>>(),
+            [&data[..100], &[], &data[100..]]
+        );
+        assert_eq!(verify_tfrecords(&[]), Ok(0));
+    }
+
+    #[test]
+    fn test_tfrecord_errors() {
+        let file = tfrecord_file(&[b"first", b"second", b"third"]);
+        let error = |file: &[u8]| verify_tfrecords(file).unwrap_err();
+
+        // corrupt the second record's data
+        let mut corrupt = file.clone();
+        corrupt[21 + 12] ^= 1;
+        let result = error(&corrupt);
+        assert_eq!((result.index, result.offset), (1, 21));
+        assert!(matches!(
+            result.kind,
+            RecordErrorKind::ChecksumMismatch { .. }
+        ));
+        assert_eq!(tfrecords(&corrupt).count(), 2);
+
+        // corrupt the second record's length
+        let mut corrupt = file.clone();
+        corrupt[21] ^= 1;
+        assert_eq!(
+            error(&corrupt).kind,
+            RecordErrorKind::LengthChecksumMismatch
+        );
+
+        assert_eq!(
+            error(&file[..file.len() - 1]).kind,
+            RecordErrorKind::Truncated
+        );
+        assert_eq!(error(&file[..11]).kind, RecordErrorKind::Truncated);
+
+        let message = error(&corrupt).to_string();
+        assert_eq!(message, "Record 1 at offset 21: length checksum mismatch");
+    }
+}
@@ -0,0 +1,632 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! NVMe end-to-end data protection, with the 64-bit Guard protection information format.
+//!
+//! Each logical block is a 512 or 4096-byte sector followed by 16 bytes of metadata, which hold
+//! the block's protection information, all big-endian:
+//!
+//! | Bytes  | Field                                                              |
+//! |--------|--------------------------------------------------------------------|
+//! | 0..8   | Guard, the CRC-64/NVME of the sector                               |
+//! | 8..10  | Application tag                                                    |
+//! | 10..16 | Storage tag (the high `storage_tag_size` bits) and reference tag   |
+//!
+//! The guards of 512-byte sectors are folded eight sectors at a time, interleaved, since a lone
+//! sector spends much of its time waiting on its own final reduction. On an AVX-512 Xeon,
+//! `cargo bench -- NVMe` measured [`generate`] at 14.8 GiB/s for 512-byte sectors, against
+//! 6.3 GiB/s for checksumming each sector in turn. 4096-byte sectors are checksummed one at a
+//! time, since interleaving them measured 17.3 GiB/s against 26.8 GiB/s for the single-buffer
+//! kernel's wide folding.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::nvme::{generate, verify, Checks, Format, ProtectionType, Tags};
+//!
+//! let format = Format::new(512, ProtectionType::Type1);
+//! let tags = Tags {
+//!     reference_tag: 1000, // the first LBA
+//!     application_tag: 0x1234,
+//!     storage_tag: 0,
+//! };
+//!
+//! let mut buffer = vec![0u8; 8 * format.block_size()];
+//! generate(&mut buffer, format, tags);
+//!
+//! assert_eq!(verify(&buffer, format, tags, Checks::default()), Ok(()));
+//!
+//! buffer[3 * format.block_size()] ^= 1;
+//! assert_eq!(verify(&buffer, format, tags, Checks::default()).unwrap_err().block, 3);
+//! ```
+
+use crate::{arch, get_calculator_params, CalculatorFn, CrcAlgorithm, CrcParams};
+use core::fmt::{Display, Formatter};
+
+/// The size of the protection information, which fills each block's metadata.
+const PI_SIZE: usize = 16;
+
+/// The number of 512-byte sectors whose guards are folded interleaved.
+const LANES: usize = 8;
+
+/// The protection information type of a namespace, which determines how reference tags are
+/// checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtectionType {
+    /// Reference tags are the low bits of each block's LBA.
+    Type1,
+    /// Reference tags start from a per-command initial value, incrementing for each block.
+    Type2,
+    /// Reference tags aren't incremented, so every block has the same one.
+    Type3,
+}
+
+/// A namespace's LBA format: its sector size, protection information type, and storage tag size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Format {
+    sector_size: usize,
+    protection_type: ProtectionType,
+    storage_tag_size: u32,
+}
+
+impl Format {
+    /// Creates a format with 512 or 4096-byte sectors and no storage tag.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sector_size` is neither 512 nor 4096.
+    pub fn new(sector_size: usize, protection_type: ProtectionType) -> Self {
+        assert!(
+            sector_size == 512 || sector_size == 4096,
+            "Sector size must be 512 or 4096 bytes"
+        );
+
+        Self {
+            sector_size,
+            protection_type,
+            storage_tag_size: 0,
+        }
+    }
+
+    /// Sets the storage tag size in bits, which takes the high bits of the 48 shared with the
+    /// reference tag.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is more than 48.
+    pub fn with_storage_tag_size(mut self, bits: u32) -> Self {
+        assert!(bits <= 48, "Storage tag size must be at most 48 bits");
+
+        self.storage_tag_size = bits;
+        self
+    }
+
+    /// Returns the sector size, excluding the metadata.
+    pub fn sector_size(&self) -> usize {
+        self.sector_size
+    }
+
+    /// Returns the size of each logical block, including the metadata.
+    pub fn block_size(&self) -> usize {
+        self.sector_size + PI_SIZE
+    }
+
+    /// Returns the number of bits of the 48-bit storage and reference tag field which hold the
+    /// reference tag.
+    fn reference_tag_size(&self) -> u32 {
+        48 - self.storage_tag_size
+    }
+
+    /// Returns the reference tag of the block `index` blocks into the transfer.
+    fn reference_tag(&self, initial: u64, index: usize) -> u64 {
+        let mask = low_bits(self.reference_tag_size());
+
+        match self.protection_type {
+            ProtectionType::Type3 => initial & mask,
+            _ => initial.wrapping_add(index as u64) & mask,
+        }
+    }
+}
+
+fn low_bits(bits: u32) -> u64 {
+    (1u64 << bits) - 1
+}
+
+/// The tags to generate, or to expect when verifying.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tags {
+    /// The reference tag of the first block: its LBA for Type 1, or the initial reference tag
+    /// for Types 2 and 3. Only the low bits which fit beside the storage tag are used.
+    pub reference_tag: u64,
+    pub application_tag: u16,
+    /// Only the low `storage_tag_size` bits are used.
+    pub storage_tag: u64,
+}
+
+/// Which fields [`verify`] checks, like the PRCHK bits and tag masks of an NVMe command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checks {
+    pub guard: bool,
+    /// The application tag bits to check, with 0 skipping the check.
+    pub application_tag_mask: u16,
+    /// The storage tag bits to check, with 0 skipping the check.
+    pub storage_tag_mask: u64,
+    pub reference_tag: bool,
+}
+
+impl Default for Checks {
+    /// Checks every field.
+    fn default() -> Self {
+        Self {
+            guard: true,
+            application_tag_mask: u16::MAX,
+            storage_tag_mask: u64::MAX,
+            reference_tag: true,
+        }
+    }
+}
+
+/// A logical block which failed verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PiError {
+    /// The index of the block in the buffer, counting from 0.
+    pub block: usize,
+    /// The byte offset of the block in the buffer.
+    pub offset: usize,
+    pub kind: PiErrorKind,
+}
+
+/// The kinds of [`PiError`]. Each has the expected value (calculated, or from the expected
+/// [`Tags`]) and the actual value stored in the protection information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PiErrorKind {
+    Guard { expected: u64, actual: u64 },
+    ApplicationTag { expected: u16, actual: u16 },
+    StorageTag { expected: u64, actual: u64 },
+    ReferenceTag { expected: u64, actual: u64 },
+}
+
+impl Display for PiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Block {} at offset {}: ", self.block, self.offset)?;
+
+        let (field, expected, actual) = match self.kind {
+            PiErrorKind::Guard { expected, actual } => ("guard", expected, actual),
+            PiErrorKind::ApplicationTag { expected, actual } => {
+                ("application tag", expected as u64, actual as u64)
+            }
+            PiErrorKind::StorageTag { expected, actual } => ("storage tag", expected, actual),
+            PiErrorKind::ReferenceTag { expected, actual } => ("reference tag", expected, actual),
+        };
+
+        write!(
+            f,
+            "{field} mismatch, expected 0x{expected:x}, got 0x{actual:x}"
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PiError {}
+
+/// Generates the protection information of every logical block in `buffer`, overwriting its
+/// metadata.
+///
+/// # Panics
+///
+/// Panics if `buffer` isn't a whole number of logical blocks.
+pub fn generate(buffer: &mut [u8], format: Format, tags: Tags) {
+    let block_size = format.block_size();
+    assert_eq!(
+        buffer.len() % block_size,
+        0,
+        "Buffer must be a whole number of blocks"
+    );
+
+    let (calculator, params) = get_calculator_params(CrcAlgorithm::Crc64Nvme);
+    let storage_tag = tags.storage_tag & low_bits(format.storage_tag_size);
+
+    let write = |index: usize, block: &mut [u8], guard: u64| {
+        let reference_tag = format.reference_tag(tags.reference_tag, index);
+        let field = storage_tag << format.reference_tag_size() | reference_tag;
+        let pi = &mut block[format.sector_size..];
+
+        pi[..8].copy_from_slice(&guard.to_be_bytes());
+        pi[8..10].copy_from_slice(&tags.application_tag.to_be_bytes());
+        pi[10..].copy_from_slice(&field.to_be_bytes()[2..]);
+    };
+
+    let (grouped, rest) = buffer.split_at_mut(grouped_len(buffer.len(), format));
+    let first = grouped.len() / block_size;
+
+    for (group_index, group) in grouped.chunks_exact_mut(LANES * block_size).enumerate() {
+        let guards = group_guards(group, format, calculator, params);
+
+        for (i, block) in group.chunks_exact_mut(block_size).enumerate() {
+            write(group_index * LANES + i, block, guards[i]);
+        }
+    }
+
+    for (i, block) in rest.chunks_exact_mut(block_size).enumerate() {
+        let guard = calculator(params.init, &block[..format.sector_size], params) ^ params.xorout;
+        write(first + i, block, guard);
+    }
+}
+
+/// Returns the length of the leading blocks of a buffer whose guards are calculated in groups of
+/// [`LANES`].
+///
+/// Only 512-byte sectors are grouped. A 4096-byte sector is long enough for the single-buffer
+/// kernel's wide folding to beat interleaving.
+fn grouped_len(len: usize, format: Format) -> usize {
+    match format.sector_size {
+        512 => len / (LANES * format.block_size()) * LANES * format.block_size(),
+        _ => 0,
+    }
+}
+
+/// Calculates the guards of a group of [`LANES`] blocks, folding their sectors interleaved.
+fn group_guards(
+    group: &[u8],
+    format: Format,
+    calculator: CalculatorFn,
+    params: CrcParams,
+) -> [u64; LANES] {
+    let block_size = format.block_size();
+    let sectors = core::array::from_fn(|i| &group[i * block_size..][..format.sector_size]);
+
+    arch::update_sectors(params.init, sectors, params, calculator)
+        .map(|guard| guard ^ params.xorout)
+}
+
+/// Verifies the protection information of every logical block in `buffer`, returning the first
+/// block which fails.
+///
+/// As in NVMe, blocks whose application tag is `0xffff` aren't checked, nor for Type 3 blocks
+/// whose storage and reference tags are also all ones.
+///
+/// # Panics
+///
+/// Panics if `buffer` isn't a whole number of logical blocks.
+pub fn verify(
+    buffer: &[u8],
+    format: Format,
+    expected: Tags,
+    checks: Checks,
+) -> Result<(), PiError> {
+    let block_size = format.block_size();
+    assert_eq!(
+        buffer.len() % block_size,
+        0,
+        "Buffer must be a whole number of blocks"
+    );
+
+    let (calculator, params) = get_calculator_params(CrcAlgorithm::Crc64Nvme);
+
+    let (grouped, rest) = buffer.split_at(grouped_len(buffer.len(), format));
+    let first = grouped.len() / block_size;
+
+    for (group_index, group) in grouped.chunks_exact(LANES * block_size).enumerate() {
+        let guards = checks
+            .guard
+            .then(|| group_guards(group, format, calculator, params));
+
+        for (i, block) in group.chunks_exact(block_size).enumerate() {
+            let guard = guards.map(|guards| guards[i]);
+            verify_block(
+                block,
+                group_index * LANES + i,
+                guard,
+                format,
+                expected,
+                checks,
+            )?;
+        }
+    }
+
+    for (i, block) in rest.chunks_exact(block_size).enumerate() {
+        let guard = checks
+            .guard
+            .then(|| calculator(params.init, &block[..format.sector_size], params) ^ params.xorout);
+        verify_block(block, first + i, guard, format, expected, checks)?;
+    }
+
+    Ok(())
+}
+
+/// Verifies a single logical block, given its calculated guard if it's being checked.
+fn verify_block(
+    block: &[u8],
+    index: usize,
+    guard: Option<u64>,
+    format: Format,
+    expected: Tags,
+    checks: Checks,
+) -> Result<(), PiError> {
+    let error = |kind| PiError {
+        block: index,
+        offset: index * format.block_size(),
+        kind,
+    };
+
+    let pi = &block[format.sector_size..];
+    let stored_guard = u64::from_be_bytes(pi[..8].try_into().unwrap());
+    let application_tag = u16::from_be_bytes([pi[8], pi[9]]);
+    let mut field = [0u8; 8];
+    field[2..].copy_from_slice(&pi[10..]);
+    let field = u64::from_be_bytes(field);
+
+    let escaped = match format.protection_type {
+        ProtectionType::Type3 => application_tag == u16::MAX && field == low_bits(48),
+        _ => application_tag == u16::MAX,
+    };
+    if escaped {
+        return Ok(());
+    }
+
+    if let Some(guard) = guard {
+        if stored_guard != guard {
+            return Err(error(PiErrorKind::Guard {
+                expected: guard,
+                actual: stored_guard,
+            }));
+        }
+    }
+
+    let mask = checks.application_tag_mask;
+    if application_tag & mask != expected.application_tag & mask {
+        return Err(error(PiErrorKind::ApplicationTag {
+            expected: expected.application_tag,
+            actual: application_tag,
+        }));
+    }
+
+    let storage_tag_mask = checks.storage_tag_mask & low_bits(format.storage_tag_size);
+    let storage_tag = field >> format.reference_tag_size();
+    let reference_tag = field & low_bits(format.reference_tag_size());
+    if storage_tag & storage_tag_mask != expected.storage_tag & storage_tag_mask {
+        return Err(error(PiErrorKind::StorageTag {
+            expected: expected.storage_tag,
+            actual: storage_tag,
+        }));
+    }
+
+    let expected_reference_tag = format.reference_tag(expected.reference_tag, index);
+    if checks.reference_tag && reference_tag != expected_reference_tag {
+        return Err(error(PiErrorKind::ReferenceTag {
+            expected: expected_reference_tag,
+            actual: reference_tag,
+        }));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum;
+    use rand::{rng, Rng};
+
+    fn random_blocks(format: Format, blocks: usize) -> Vec<u8> {
+        let mut buffer = vec![0u8; blocks * format.block_size()];
+        rng().fill(&mut buffer[..]);
+
+        buffer
+    }
+
+    #[test]
+    fn test_generate() {
+        let tags = Tags {
+            reference_tag: 0x1234_5678_9abc,
+            application_tag: 0xbeef,
+            storage_tag: 0xcafe,
+        };
+
+        for sector_size in [512, 4096] {
+            let format = Format::new(sector_size, ProtectionType::Type1).with_storage_tag_size(16);
+
+            for blocks in 0..=9 {
+                let mut buffer = random_blocks(format, blocks);
+                generate(&mut buffer, format, tags);
+
+                for (i, block) in buffer.chunks_exact(format.block_size()).enumerate() {
+                    let (sector, pi) = block.split_at(sector_size);
+
+                    assert_eq!(
+                        pi[..8],
+                        checksum(CrcAlgorithm::Crc64Nvme, sector).to_be_bytes(),
+                        "sector size {sector_size}, block {i} of {blocks}"
+                    );
+                    assert_eq!(pi[8..10], [0xbe, 0xef]);
+                    assert_eq!(pi[10..12], [0xca, 0xfe]);
+                    assert_eq!(pi[12..], (0x5678_9abc_u32 + i as u32).to_be_bytes());
+                }
+
+                assert_eq!(verify(&buffer, format, tags, Checks::default()), Ok(()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reference_tags() {
+        let tags = Tags {
+            reference_tag: (1 << 48) - 2,
+            ..Default::default()
+        };
+
+        // the reference tag wraps within its bits
+        let format = Format::new(512, ProtectionType::Type2);
+        let mut buffer = random_blocks(format, 3);
+        generate(&mut buffer, format, tags);
+
+        assert_eq!(buffer[512 + 10..528], [0xff, 0xff, 0xff, 0xff, 0xff, 0xfe]);
+        assert_eq!(
+            buffer[1040 + 10..1056],
+            [0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(buffer[1568 + 10..], [0, 0, 0, 0, 0, 0]);
+
+        // Type 3 reference tags aren't incremented
+        let format = Format::new(512, ProtectionType::Type3);
+        generate(&mut buffer, format, tags);
+
+        assert_eq!(buffer[512 + 10..528], buffer[1568 + 10..]);
+        assert_eq!(verify(&buffer, format, tags, Checks::default()), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_errors() {
+        let format = Format::new(4096, ProtectionType::Type1).with_storage_tag_size(8);
+        let tags = Tags {
+            reference_tag: 100,
+            application_tag: 0x0102,
+            storage_tag: 0x42,
+        };
+
+        let mut buffer = random_blocks(format, 7);
+        generate(&mut buffer, format, tags);
+        let offset = 5 * format.block_size();
+
+        // guard
+        let mut corrupted = buffer.clone();
+        corrupted[offset + 100] ^= 0x80;
+        let error = verify(&corrupted, format, tags, Checks::default()).unwrap_err();
+
+        assert_eq!(error.block, 5);
+        assert_eq!(error.offset, offset);
+        assert_eq!(
+            error.kind,
+            PiErrorKind::Guard {
+                expected: checksum(CrcAlgorithm::Crc64Nvme, &corrupted[offset..offset + 4096]),
+                actual: checksum(CrcAlgorithm::Crc64Nvme, &buffer[offset..offset + 4096]),
+            }
+        );
+
+        let checks = Checks {
+            guard: false,
+            ..Default::default()
+        };
+        assert_eq!(verify(&corrupted, format, tags, checks), Ok(()));
+
+        // application tag, checked through its mask
+        let other = Tags {
+            application_tag: 0x0103,
+            ..tags
+        };
+        assert_eq!(
+            verify(&buffer, format, other, Checks::default())
+                .unwrap_err()
+                .kind,
+            PiErrorKind::ApplicationTag {
+                expected: 0x0103,
+                actual: 0x0102
+            }
+        );
+
+        let checks = Checks {
+            application_tag_mask: 0xff00,
+            ..Default::default()
+        };
+        assert_eq!(verify(&buffer, format, other, checks), Ok(()));
+
+        // storage tag
+        let other = Tags {
+            storage_tag: 0x43,
+            ..tags
+        };
+        assert_eq!(
+            verify(&buffer, format, other, Checks::default())
+                .unwrap_err()
+                .kind,
+            PiErrorKind::StorageTag {
+                expected: 0x43,
+                actual: 0x42
+            }
+        );
+
+        // reference tag
+        let other = Tags {
+            reference_tag: 101,
+            ..tags
+        };
+        assert_eq!(
+            verify(&buffer, format, other, Checks::default()).unwrap_err(),
+            PiError {
+                block: 0,
+                offset: 0,
+                kind: PiErrorKind::ReferenceTag {
+                    expected: 101,
+                    actual: 100
+                }
+            }
+        );
+
+        let checks = Checks {
+            reference_tag: false,
+            ..Default::default()
+        };
+        assert_eq!(verify(&buffer, format, other, checks), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_interleaved_guards() {
+        let format = Format::new(512, ProtectionType::Type2);
+        let mut buffer = random_blocks(format, 2 * LANES + 3);
+        generate(&mut buffer, format, Tags::default());
+
+        // the first error is reported, whether its guard was folded with others or alone
+        for blocks in [[3, 12], [11, 17], [17, 18]] {
+            let mut corrupted = buffer.clone();
+            for block in blocks {
+                corrupted[block * format.block_size()] ^= 1;
+            }
+
+            let error = verify(&corrupted, format, Tags::default(), Checks::default());
+            assert_eq!(error.unwrap_err().block, blocks[0]);
+        }
+    }
+
+    #[test]
+    fn test_escapes() {
+        let format = Format::new(512, ProtectionType::Type1);
+        let mut buffer = random_blocks(format, 2);
+
+        // an application tag of 0xffff disables checking, whatever else is stored
+        buffer[512 + 8..512 + 10].copy_from_slice(&[0xff, 0xff]);
+        buffer[1040 + 8..1040 + 10].copy_from_slice(&[0xff, 0xff]);
+        assert_eq!(
+            verify(&buffer, format, Tags::default(), Checks::default()),
+            Ok(())
+        );
+
+        // but Type 3 also needs the storage and reference tags to be all ones
+        let format = Format::new(512, ProtectionType::Type3);
+        assert!(verify(&buffer, format, Tags::default(), Checks::default()).is_err());
+
+        buffer[512 + 10..528].fill(0xff);
+        buffer[1040 + 10..1056].fill(0xff);
+        assert_eq!(
+            verify(&buffer, format, Tags::default(), Checks::default()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Sector size must be 512 or 4096 bytes")]
+    fn test_invalid_sector_size() {
+        Format::new(520, ProtectionType::Type1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Buffer must be a whole number of blocks")]
+    fn test_partial_block() {
+        let format = Format::new(512, ProtectionType::Type1);
+        verify(
+            &[0u8; 528 + 512],
+            format,
+            Tags::default(),
+            Checks::default(),
+        )
+        .ok();
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides a rolling CRC over a fixed-size window.
+//!
+//! The CRC register after a window `m` with no initial value is `R(m) = m(x) * x^width mod P(x)`.
+//! Dropping the oldest byte `b` removes its contribution `b(x) * x^(width - 8) * x^(8 * window)
+//! mod P(x)`, leaving the register for the remaining bytes, which is then updated with the
+//! incoming byte as usual. The initial value's contribution is fixed for a given window, so it's
+//! folded into the outgoing table.
+
+use crate::gf2::{mul_mod, reflect, register_form, x_pow_mod};
+use crate::{
+    get_calculator, get_calculator_params, CalculatorFn, CrcAlgorithm, CrcParams, RollingCrc,
+};
+
+impl RollingCrc {
+    /// Creates a new `RollingCrc` for the specified CRC algorithm and window size in bytes.
+    ///
+    /// The window starts out as all zero bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum, RollingCrc, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let data = b"0123456789";
+    /// let mut rolling = RollingCrc::new(Crc32IsoHdlc, 4);
+    /// rolling.reset(&data[..4]);
+    ///
+    /// for end in 4..data.len() {
+    ///     let crc = rolling.roll(data[end - 4], data[end]);
+    ///
+    ///     assert_eq!(crc, checksum(Crc32IsoHdlc, &data[end - 3..=end]));
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is 0.
+    pub fn new(algorithm: CrcAlgorithm, window: usize) -> Self {
+        let (calculator, params) = get_calculator_params(algorithm);
+
+        Self::from_calculator(calculator, params, window)
+    }
+
+    /// Creates a new `RollingCrc` with custom CRC parameters and window size in bytes.
+    ///
+    /// The window starts out as all zero bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is 0.
+    pub fn new_with_params(params: CrcParams, window: usize) -> Self {
+        Self::from_calculator(get_calculator(&params), params, window)
+    }
+
+    fn from_calculator(calculator: CalculatorFn, params: CrcParams, window: usize) -> Self {
+        assert!(window > 0, "Window must be at least 1 byte");
+
+        let width = params.width;
+
+        // x^8 shifts a byte into the register, x^(8 * window) shifts it out the far end
+        let shift_in = x_pow_mod(8, params.poly, width);
+        let shift_out = x_pow_mod(8 * window as u64, params.poly, width);
+
+        // The initial value's contribution to the full window, less its contribution to the
+        // window without its oldest byte
+        let init = register_form(params.init, params);
+        let init_full = mul_mod(init, shift_out, params.poly, width);
+        let init_rest = mul_mod(
+            init,
+            x_pow_mod(8 * (window as u64 - 1), params.poly, width),
+            params.poly,
+            width,
+        );
+        let init_outgoing = register_form(init_full ^ init_rest, params);
+
+        let mut incoming = [0u64; 256];
+        let mut outgoing = [0u64; 256];
+        for byte in 0..256 {
+            let value = if params.refin { reflect(byte, 8) } else { byte } << (width - 8);
+
+            incoming[byte as usize] =
+                register_form(mul_mod(value, shift_in, params.poly, width), params);
+            outgoing[byte as usize] =
+                register_form(mul_mod(value, shift_out, params.poly, width), params)
+                    ^ init_outgoing;
+        }
+
+        Self {
+            state: register_form(init_full, params),
+            window,
+            params,
+            calculator,
+            incoming,
+            outgoing,
+        }
+    }
+
+    /// Returns the window size in bytes.
+    #[inline(always)]
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Replaces the window with `bytes`, which must be exactly `window()` bytes long.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` isn't the same length as the window.
+    pub fn reset(&mut self, bytes: &[u8]) {
+        assert_eq!(bytes.len(), self.window, "Window size mismatch");
+
+        self.state = (self.calculator)(self.params.init, bytes, self.params);
+    }
+
+    /// Slides the window forward one byte, dropping `outgoing` (the oldest byte) and appending
+    /// `incoming`, and returns the CRC checksum of the new window.
+    #[inline(always)]
+    pub fn roll(&mut self, outgoing: u8, incoming: u8) -> u64 {
+        self.state = self.step(self.state, outgoing, incoming);
+
+        self.checksum()
+    }
+
+    /// Returns the CRC checksum of the current window.
+    #[inline(always)]
+    pub fn checksum(&self) -> u64 {
+        self.state ^ self.params.xorout
+    }
+
+    /// Returns the CRC checksum of every window in `data`, in order of offset, so the first is
+    /// the checksum of `data[..window()]`. Yields nothing if `data` is shorter than the window.
+    ///
+    /// This doesn't change the current window.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum, RollingCrc, CrcAlgorithm::Crc64Nvme};
+    ///
+    /// let data = b"the quick brown fox jumps over the lazy dog";
+    /// let needle = checksum(Crc64Nvme, b"fox");
+    ///
+    /// let rolling = RollingCrc::new(Crc64Nvme, 3);
+    /// let offset = rolling.roll_slice(data).position(|crc| crc == needle);
+    ///
+    /// assert_eq!(offset, Some(16));
+    /// ```
+    pub fn roll_slice<'a>(&'a self, data: &'a [u8]) -> impl Iterator<Item = u64> + 'a {
+        let mut state = data
+            .get(..self.window)
+            .map(|first| (self.calculator)(self.params.init, first, self.params));
+        let mut offset = 0;
+
+        core::iter::from_fn(move || {
+            let current = state?;
+            state = data
+                .get(offset + self.window)
+                .map(|&incoming| self.step(current, data[offset], incoming));
+            offset += 1;
+
+            Some(current ^ self.params.xorout)
+        })
+    }
+
+    #[inline(always)]
+    fn step(&self, state: u64, outgoing: u8, incoming: u8) -> u64 {
+        // The register for the window without its oldest byte
+        let state = state ^ self.outgoing[outgoing as usize];
+
+        if self.params.refin {
+            (state >> 8) ^ self.incoming[((state ^ incoming as u64) & 0xff) as usize]
+        } else {
+            let width = self.params.width as u32;
+            let index = ((state >> (width - 8)) ^ incoming as u64) & 0xff;
+            let mask = u64::MAX >> (64 - width);
+
+            ((state << 8) & mask) ^ self.incoming[index as usize]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum_with_params;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_roll_slice_matches_checksum() {
+        let mut data = vec![0u8; 300];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            for window in [1, 2, 7, 16, 64, 255, 300] {
+                let rolling = RollingCrc::new(config.get_algorithm(), window);
+                let crcs: Vec<u64> = rolling.roll_slice(&data).collect();
+
+                assert_eq!(crcs.len(), data.len() - window + 1);
+                for (offset, crc) in crcs.into_iter().enumerate() {
+                    assert_eq!(
+                        crc,
+                        checksum_with_params(params, &data[offset..offset + window]),
+                        "{} window {window} offset {offset}",
+                        config.get_name()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_roll_matches_checksum() {
+        let mut data = vec![0u8; 1024];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+            let window = 48;
+
+            // starts out as all zeros, so the first window can be rolled in too
+            let mut rolling = RollingCrc::new_with_params(params, window);
+            assert_eq!(rolling.checksum(), checksum_with_params(params, &[0u8; 48]));
+
+            for &byte in &data[..window] {
+                rolling.roll(0, byte);
+            }
+            assert_eq!(
+                rolling.checksum(),
+                checksum_with_params(params, &data[..window])
+            );
+
+            for end in window..data.len() {
+                assert_eq!(
+                    rolling.roll(data[end - window], data[end]),
+                    checksum_with_params(params, &data[end + 1 - window..=end]),
+                    "{}",
+                    config.get_name()
+                );
+            }
+
+            rolling.reset(&data[100..148]);
+            assert_eq!(
+                rolling.checksum(),
+                checksum_with_params(params, &data[100..148])
+            );
+            assert_eq!(rolling.window(), window);
+        }
+    }
+
+    #[test]
+    fn test_roll_slice_short_data() {
+        let rolling = RollingCrc::new(CrcAlgorithm::Crc32Iscsi, 8);
+
+        assert_eq!(rolling.roll_slice(&[]).count(), 0);
+        assert_eq!(rolling.roll_slice(&[0u8; 7]).count(), 0);
+        assert_eq!(rolling.roll_slice(&[0u8; 8]).count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window must be at least 1 byte")]
+    fn test_empty_window() {
+        RollingCrc::new(CrcAlgorithm::Crc32IsoHdlc, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window size mismatch")]
+    fn test_reset_wrong_size() {
+        RollingCrc::new(CrcAlgorithm::Crc32IsoHdlc, 8).reset(b"1234");
+    }
+}
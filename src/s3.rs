@@ -0,0 +1,446 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Amazon S3 multipart upload checksums.
+//!
+//! S3 accepts two kinds of checksum for an object uploaded in parts:
+//!
+//! - A *full object* checksum is the CRC of the whole object, derived here by combining the part
+//!   CRCs with [`checksum_combine`](crate::checksum_combine), so the object never has to be read
+//!   twice. It's supported for CRC-32, CRC-32C and CRC-64/NVME.
+//! - A *composite* checksum is the CRC of the concatenated (big-endian) part CRCs, suffixed with
+//!   `-N` for the number of parts. It's supported for CRC-32 and CRC-32C only.
+//!
+//! Both are base64 encoded, as in the `x-amz-checksum-*` headers.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::s3::{composite_checksum, full_object_checksum, verify, Part, S3ChecksumAlgorithm};
+//! use crc_fast::{checksum, CrcAlgorithm::Crc32Iscsi};
+//!
+//! let parts = [b"1234".as_slice(), b"56789".as_slice()].map(|part| Part {
+//!     checksum: checksum(Crc32Iscsi, part),
+//!     length: part.len() as u64,
+//! });
+//!
+//! // the same as the CRC-32C of b"123456789"
+//! let full_object = full_object_checksum(S3ChecksumAlgorithm::Crc32c, &parts);
+//! assert_eq!(full_object, "4waSgw==");
+//!
+//! let composite = composite_checksum(S3ChecksumAlgorithm::Crc32c, &parts).unwrap();
+//! assert!(composite.ends_with("-2"));
+//!
+//! assert!(verify(S3ChecksumAlgorithm::Crc32c, &parts, &full_object).is_ok());
+//! assert!(verify(S3ChecksumAlgorithm::Crc32c, &parts, &composite).is_ok());
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use crate::{checksum, checksum_combine, ByteOrder, CrcAlgorithm, CrcBytes};
+use core::fmt::{Display, Formatter};
+
+/// The CRC algorithms S3 supports for multipart uploads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum S3ChecksumAlgorithm {
+    /// CRC-32/ISO-HDLC, as `CRC32`.
+    Crc32,
+    /// CRC-32/ISCSI, as `CRC32C`.
+    Crc32c,
+    /// CRC-64/NVME, as `CRC64NVME`. Full object checksums only.
+    Crc64Nvme,
+}
+
+impl S3ChecksumAlgorithm {
+    /// The CRC algorithm used to calculate the checksums.
+    pub fn algorithm(self) -> CrcAlgorithm {
+        match self {
+            S3ChecksumAlgorithm::Crc32 => CrcAlgorithm::Crc32IsoHdlc,
+            S3ChecksumAlgorithm::Crc32c => CrcAlgorithm::Crc32Iscsi,
+            S3ChecksumAlgorithm::Crc64Nvme => CrcAlgorithm::Crc64Nvme,
+        }
+    }
+
+    /// The HTTP header carrying the checksum, such as `x-amz-checksum-crc32c`.
+    pub fn header_name(self) -> &'static str {
+        match self {
+            S3ChecksumAlgorithm::Crc32 => "x-amz-checksum-crc32",
+            S3ChecksumAlgorithm::Crc32c => "x-amz-checksum-crc32c",
+            S3ChecksumAlgorithm::Crc64Nvme => "x-amz-checksum-crc64nvme",
+        }
+    }
+
+    fn width(self) -> u8 {
+        match self {
+            S3ChecksumAlgorithm::Crc32 | S3ChecksumAlgorithm::Crc32c => 32,
+            S3ChecksumAlgorithm::Crc64Nvme => 64,
+        }
+    }
+
+    fn encode(self, checksum: u64) -> String {
+        CrcBytes::new(checksum, self.width(), ByteOrder::BigEndian).to_base64()
+    }
+}
+
+/// The CRC and length of one part of a multipart upload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Part {
+    pub checksum: u64,
+    pub length: u64,
+}
+
+/// A parsed S3 checksum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum S3Checksum {
+    /// The CRC of the whole object.
+    FullObject(u64),
+    /// The CRC of the part CRCs, and the number of parts.
+    Composite { checksum: u64, parts: usize },
+}
+
+impl S3Checksum {
+    /// Parses a base64 checksum, with a `-N` suffix for composite checksums.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::s3::{S3Checksum, S3ChecksumAlgorithm};
+    ///
+    /// assert_eq!(
+    ///     S3Checksum::parse(S3ChecksumAlgorithm::Crc32c, "4waSgw=="),
+    ///     Ok(S3Checksum::FullObject(0xe3069283))
+    /// );
+    /// ```
+    pub fn parse(algorithm: S3ChecksumAlgorithm, encoded: &str) -> Result<Self, S3ChecksumError> {
+        let (encoded, parts) = match encoded.rsplit_once('-') {
+            Some((encoded, parts)) => {
+                // S3 writes the part count as a plain decimal, which is always at least 1
+                if parts.is_empty() || !parts.bytes().all(|c| c.is_ascii_digit()) {
+                    return Err(S3ChecksumError::InvalidEncoding);
+                }
+                match parts.parse::<usize>() {
+                    Ok(parts) if parts > 0 => (encoded, Some(parts)),
+                    _ => return Err(S3ChecksumError::InvalidEncoding),
+                }
+            }
+            None => (encoded, None),
+        };
+
+        let bytes = CrcBytes::from_base64(encoded).ok_or(S3ChecksumError::InvalidEncoding)?;
+        if bytes.len() * 8 != algorithm.width() as usize {
+            return Err(S3ChecksumError::InvalidEncoding);
+        }
+        let checksum = bytes.to_checksum(ByteOrder::BigEndian);
+
+        match parts {
+            None => Ok(S3Checksum::FullObject(checksum)),
+            Some(_) if algorithm == S3ChecksumAlgorithm::Crc64Nvme => {
+                Err(S3ChecksumError::UnsupportedAlgorithm)
+            }
+            Some(parts) => Ok(S3Checksum::Composite { checksum, parts }),
+        }
+    }
+}
+
+/// Errors from creating, parsing or verifying S3 checksums.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum S3ChecksumError {
+    /// The checksum isn't valid base64 of the algorithm's width, with an optional `-N` suffix.
+    InvalidEncoding,
+    /// S3 doesn't support composite checksums for CRC-64/NVME.
+    UnsupportedAlgorithm,
+    /// A composite checksum needs at least one part.
+    NoParts,
+    /// The composite checksum's part count doesn't match the number of parts.
+    PartCountMismatch { expected: usize, actual: usize },
+    /// The checksum doesn't match the parts.
+    ChecksumMismatch,
+}
+
+impl Display for S3ChecksumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            S3ChecksumError::InvalidEncoding => write!(f, "Invalid S3 checksum encoding"),
+            S3ChecksumError::UnsupportedAlgorithm => {
+                write!(f, "Composite checksums aren't supported for CRC64NVME")
+            }
+            S3ChecksumError::NoParts => write!(f, "Composite checksums need at least one part"),
+            S3ChecksumError::PartCountMismatch { expected, actual } => {
+                write!(f, "Expected {expected} parts, got {actual}")
+            }
+            S3ChecksumError::ChecksumMismatch => write!(f, "S3 checksum mismatch"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for S3ChecksumError {}
+
+/// Calculates the full object checksum of the parts, in order, as base64.
+///
+/// With no parts, it's the checksum of an empty object.
+pub fn full_object_checksum(algorithm: S3ChecksumAlgorithm, parts: &[Part]) -> String {
+    algorithm.encode(full_object_crc(algorithm, parts))
+}
+
+/// Calculates the composite checksum of the parts, in order, as base64 with a `-N` suffix.
+///
+/// Only the part CRCs are used, but taking [`Part`]s keeps the input the same for both forms.
+pub fn composite_checksum(
+    algorithm: S3ChecksumAlgorithm,
+    parts: &[Part],
+) -> Result<String, S3ChecksumError> {
+    let checksum = composite_crc(algorithm, parts)?;
+
+    Ok(format!("{}-{}", algorithm.encode(checksum), parts.len()))
+}
+
+/// Verifies a full object or composite checksum, as returned by S3, against the parts.
+pub fn verify(
+    algorithm: S3ChecksumAlgorithm,
+    parts: &[Part],
+    expected: &str,
+) -> Result<(), S3ChecksumError> {
+    let (expected, actual) = match S3Checksum::parse(algorithm, expected)? {
+        S3Checksum::FullObject(expected) => (expected, full_object_crc(algorithm, parts)),
+        S3Checksum::Composite {
+            checksum,
+            parts: count,
+        } => {
+            if count != parts.len() {
+                return Err(S3ChecksumError::PartCountMismatch {
+                    expected: count,
+                    actual: parts.len(),
+                });
+            }
+            (checksum, composite_crc(algorithm, parts)?)
+        }
+    };
+
+    if expected != actual {
+        return Err(S3ChecksumError::ChecksumMismatch);
+    }
+
+    Ok(())
+}
+
+fn full_object_crc(algorithm: S3ChecksumAlgorithm, parts: &[Part]) -> u64 {
+    let algorithm = algorithm.algorithm();
+
+    parts.iter().fold(checksum(algorithm, &[]), |crc, part| {
+        checksum_combine(algorithm, crc, part.checksum, part.length)
+    })
+}
+
+fn composite_crc(algorithm: S3ChecksumAlgorithm, parts: &[Part]) -> Result<u64, S3ChecksumError> {
+    if algorithm == S3ChecksumAlgorithm::Crc64Nvme {
+        return Err(S3ChecksumError::UnsupportedAlgorithm);
+    }
+    if parts.is_empty() {
+        return Err(S3ChecksumError::NoParts);
+    }
+
+    let width = algorithm.width();
+    let mut concatenated = Vec::with_capacity(parts.len() * width as usize / 8);
+    for part in parts {
+        concatenated.extend_from_slice(&CrcBytes::new(part.checksum, width, ByteOrder::BigEndian));
+    }
+
+    Ok(checksum(algorithm.algorithm(), &concatenated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rng, Rng};
+
+    const ALGORITHMS: [S3ChecksumAlgorithm; 3] = [
+        S3ChecksumAlgorithm::Crc32,
+        S3ChecksumAlgorithm::Crc32c,
+        S3ChecksumAlgorithm::Crc64Nvme,
+    ];
+
+    fn split(algorithm: S3ChecksumAlgorithm, data: &[u8], sizes: &[usize]) -> Vec<Part> {
+        let mut parts = Vec::new();
+        let mut offset = 0;
+
+        for &size in sizes {
+            let part = &data[offset..offset + size];
+            parts.push(Part {
+                checksum: checksum(algorithm.algorithm(), part),
+                length: size as u64,
+            });
+            offset += size;
+        }
+
+        parts
+    }
+
+    #[test]
+    fn test_full_object_checksum() {
+        let mut data = vec![0u8; 10_000];
+        rng().fill(&mut data[..]);
+
+        for algorithm in ALGORITHMS {
+            let expected = checksum(algorithm.algorithm(), &data);
+            let expected = CrcBytes::new(expected, algorithm.width(), ByteOrder::BigEndian);
+
+            for sizes in [&[10_000][..], &[5_000, 5_000], &[1, 4_095, 4_096, 1_808]] {
+                let parts = split(algorithm, &data, sizes);
+                let full_object = full_object_checksum(algorithm, &parts);
+
+                assert_eq!(full_object, expected.to_base64());
+                assert_eq!(verify(algorithm, &parts, &full_object), Ok(()));
+            }
+
+            // no parts is an empty object
+            assert_eq!(
+                full_object_checksum(algorithm, &[]),
+                algorithm.encode(checksum(algorithm.algorithm(), &[]))
+            );
+        }
+    }
+
+    #[test]
+    fn test_known_values() {
+        // full object checksums of "123456789" are the catalogue check values, base64 encoded
+        for (algorithm, expected) in [
+            (S3ChecksumAlgorithm::Crc32, "y/Q5Jg=="),
+            (S3ChecksumAlgorithm::Crc32c, "4waSgw=="),
+            (S3ChecksumAlgorithm::Crc64Nvme, "rosUhgp5mIg="),
+        ] {
+            let parts = split(algorithm, b"123456789", &[4, 5]);
+
+            assert_eq!(full_object_checksum(algorithm, &parts), expected);
+        }
+
+        // composite checksums of "1234" and "56789", computed outside this crate (with Python's
+        // zlib.crc32 and a bitwise CRC-32C) from the big-endian part CRCs:
+        //   CRC-32:  0x9be3e0a3, 0x131da070 -> 0xac133007
+        //   CRC-32C: 0xf63af4ee, 0x83b565d8 -> 0xc0295959
+        for (algorithm, part_checksums, expected) in [
+            (
+                S3ChecksumAlgorithm::Crc32,
+                [0x9be3e0a3, 0x131da070],
+                "rBMwBw==-2",
+            ),
+            (
+                S3ChecksumAlgorithm::Crc32c,
+                [0xf63af4ee, 0x83b565d8],
+                "wClZWQ==-2",
+            ),
+        ] {
+            let parts = split(algorithm, b"123456789", &[4, 5]);
+
+            assert_eq!(
+                parts.iter().map(|part| part.checksum).collect::<Vec<_>>(),
+                part_checksums
+            );
+            assert_eq!(
+                composite_checksum(algorithm, &parts).as_deref(),
+                Ok(expected)
+            );
+            assert_eq!(verify(algorithm, &parts, expected), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_composite_checksum() {
+        let mut data = vec![0u8; 10_000];
+        rng().fill(&mut data[..]);
+
+        for algorithm in [S3ChecksumAlgorithm::Crc32, S3ChecksumAlgorithm::Crc32c] {
+            let parts = split(algorithm, &data, &[3_000, 3_000, 4_000]);
+            let composite = composite_checksum(algorithm, &parts).unwrap();
+
+            assert!(composite.ends_with("-3"));
+            assert_eq!(verify(algorithm, &parts, &composite), Ok(()));
+
+            // the composite and full object forms differ
+            assert_ne!(
+                composite.split('-').next(),
+                Some(full_object_checksum(algorithm, &parts).as_str())
+            );
+
+            // a single part is still a checksum of its checksum
+            let single = split(algorithm, &data, &[10_000]);
+            assert_ne!(
+                composite_checksum(algorithm, &single).unwrap(),
+                format!("{}-1", full_object_checksum(algorithm, &single))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let algorithm = S3ChecksumAlgorithm::Crc32c;
+
+        assert_eq!(
+            S3Checksum::parse(algorithm, "4waSgw==-12"),
+            Ok(S3Checksum::Composite {
+                checksum: 0xe3069283,
+                parts: 12
+            })
+        );
+        assert_eq!(
+            S3Checksum::parse(S3ChecksumAlgorithm::Crc64Nvme, "rosUhgp5mIg="),
+            Ok(S3Checksum::FullObject(0xae8b14860a799888))
+        );
+
+        for invalid in [
+            "",
+            "4waSgw",
+            "4waSgw==-",
+            "4waSgw==-0",
+            "4waSgw==-+1",
+            "4waSgw==-x",
+            "rosUhgp5mIg=",
+        ] {
+            assert_eq!(
+                S3Checksum::parse(algorithm, invalid),
+                Err(S3ChecksumError::InvalidEncoding),
+                "{invalid}"
+            );
+        }
+
+        assert_eq!(
+            S3Checksum::parse(S3ChecksumAlgorithm::Crc64Nvme, "rosUhgp5mIg=-2"),
+            Err(S3ChecksumError::UnsupportedAlgorithm)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let algorithm = S3ChecksumAlgorithm::Crc32c;
+        let parts = split(algorithm, b"123456789", &[4, 5]);
+        let composite = composite_checksum(algorithm, &parts).unwrap();
+
+        assert_eq!(
+            verify(algorithm, &parts[..1], &composite),
+            Err(S3ChecksumError::PartCountMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            verify(algorithm, &parts[..1], "4waSgw=="),
+            Err(S3ChecksumError::ChecksumMismatch)
+        );
+
+        let mut swapped = parts.clone();
+        swapped.swap(0, 1);
+        assert_eq!(
+            verify(algorithm, &swapped, &composite),
+            Err(S3ChecksumError::ChecksumMismatch)
+        );
+
+        assert_eq!(
+            composite_checksum(S3ChecksumAlgorithm::Crc64Nvme, &parts),
+            Err(S3ChecksumError::UnsupportedAlgorithm)
+        );
+        assert_eq!(
+            composite_checksum(algorithm, &[]),
+            Err(S3ChecksumError::NoParts)
+        );
+    }
+}
@@ -2,8 +2,8 @@
 
 #![allow(dead_code)]
 
-use crate::traits::{CrcCalculator, CrcWidth};
-use crate::{arch, cache, CrcAlgorithm, CrcParams};
+use crate::traits::CrcWidth;
+use crate::{cache, ByteOrder, CrcAlgorithm, CrcParams};
 
 /// CRC-32 width implementation
 #[derive(Clone, Copy)]
@@ -30,13 +30,12 @@ pub struct CrcState<T> {
     pub reflected: bool,
 }
 
-pub(crate) struct Calculator {}
-
-impl CrcCalculator for Calculator {
-    #[inline(always)]
-    fn calculate(state: u64, data: &[u8], params: CrcParams) -> u64 {
-        unsafe { arch::update(state, data, params) }
-    }
+/// Per-CRC state when folding several CRCs over the same blocks in a single pass
+#[derive(Debug, Clone, Copy)]
+pub struct MultiLane<T> {
+    pub state: CrcState<T>,
+    pub width: u8,
+    pub keys: [u64; 27],
 }
 
 impl CrcParams {
@@ -48,6 +47,10 @@ impl CrcParams {
     ///
     /// Does not support mis-matched refin/refout parameters, so both must be true or both false.
     ///
+    /// Without the `std` or `cache` features, the keys for AVX-512 VPCLMULQDQ's widest folding
+    /// distances aren't available for custom parameters, so large buffers fold at 256-byte
+    /// distances instead, which is slower but gives the same CRC.
+    ///
     /// Rocksoft parameters for lots of variants: https://reveng.sourceforge.io/crc-catalogue/all.htm
     pub fn new(
         name: &'static str,
@@ -99,6 +102,41 @@ impl CrcParams {
         }
     }
 
+    /// Returns the 512 and 1024-byte distance folding keys used by the AVX-512 VPCLMULQDQ tier.
+    ///
+    /// Predefined polynomials use precomputed constants, and anything else goes through the
+    /// cache, so these stay out of the public `CrcKeysStorage`. Without a cache, custom
+    /// polynomials get all zeros, which falls back to the narrower folding loop.
+    #[inline]
+    pub(crate) fn wide_keys(self) -> [u64; 4] {
+        let predefined = match self.width {
+            32 => crate::crc32::consts::wide_keys(self.poly, self.refin),
+            64 => crate::crc64::consts::wide_keys(self.poly, self.refin),
+            _ => None,
+        };
+
+        match predefined {
+            Some(keys) => keys,
+            None => cache::get_or_generate_wide_keys(self.width, self.poly, self.refin),
+        }
+    }
+
+    /// Returns the conventional byte order for appending the CRC to a message as a trailer.
+    ///
+    /// Reflected CRCs shift out their least significant byte first, so they're appended
+    /// little-endian (as in the Ethernet FCS and gzip), while forward CRCs are appended
+    /// big-endian (as in MPEG-2). This is the order for which the CRC of a message and its
+    /// trailer is the catalogue's residue. Some formats differ, such as PNG, which stores its
+    /// reflected CRC-32 big-endian.
+    #[inline(always)]
+    pub fn byte_order(self) -> ByteOrder {
+        if self.refout {
+            ByteOrder::LittleEndian
+        } else {
+            ByteOrder::BigEndian
+        }
+    }
+
     /// Returns the number of keys available in this CrcParams instance.
     #[inline(always)]
     pub fn key_count(self) -> usize {
@@ -3,7 +3,6 @@
 #![cfg(test)]
 #![allow(dead_code)]
 
-use crate::consts::CRC_64_NVME;
 use crate::crc32::consts::{
     CRC32_AIXM, CRC32_AUTOSAR, CRC32_BASE91_D, CRC32_BZIP2, CRC32_CD_ROM_EDC, CRC32_CKSUM,
     CRC32_ISCSI, CRC32_ISO_HDLC, CRC32_JAMCRC, CRC32_MEF, CRC32_MPEG_2, CRC32_XFER,
@@ -13,7 +12,21 @@ use crate::crc64::consts::{
 };
 use crate::test::enums::*;
 use crate::test::structs::*;
-use crc::Table;
+use crc::{Algorithm, Table};
+
+// the crc crate doesn't include CRC-64/NVME, so define it for the reference implementation
+// https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-nvme
+// width=64 poly=0xad93d23594c93659 init=0xffffffffffffffff refin=true refout=true xorout=0xffffffffffffffff check=0xae8b14860a799888 residue=0xf310303b2b6f6e42 name="CRC-64/NVME"
+pub const CRC_64_NVME: Algorithm<u64> = Algorithm {
+    width: 64,
+    poly: 0xad93d23594c93659,
+    init: 0xFFFFFFFFFFFFFFFF,
+    refin: true,
+    refout: true,
+    xorout: 0xFFFFFFFFFFFFFFFF,
+    check: 0xae8b14860a799888,
+    residue: 0xf310303b2b6f6e42,
+};
 
 pub const TEST_CHECK_STRING: &[u8] = b"123456789";
 
@@ -5,10 +5,8 @@
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
 use crate::enums::Reflector;
 
-use crate::CrcParams;
-
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
-use crate::structs::CrcState;
+use crate::structs::{CrcState, MultiLane};
 
 use core::ops::BitXor;
 
@@ -20,18 +18,6 @@ pub trait CrcWidth {
     type Value: Copy + BitXor<Output = Self::Value>;
 }
 
-pub(crate) trait CrcCalculator {
-    fn update(data: &[u8], state: u64, params: CrcParams) -> u64 {
-        Self::calculate(state, data, params)
-    }
-
-    fn checksum(data: &[u8], params: CrcParams) -> u64 {
-        Self::calculate(params.init, data, params) ^ params.xorout
-    }
-
-    fn calculate(state: u64, data: &[u8], params: CrcParams) -> u64;
-}
-
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
 /// Trait defining architecture-specific SIMD operations for CRC calculation
 pub trait ArchOps: Sized + Copy + Clone {
@@ -48,7 +34,25 @@ pub trait ArchOps: Sized + Copy + Clone {
         _first: &[Self::Vector; 8],
         _rest: &[[Self::Vector; 8]],
         _reflector: &Reflector<Self::Vector>,
-        _keys: [u64; 23],
+        _keys: [u64; 27],
+    ) -> bool
+    where
+        Self::Vector: Copy,
+    {
+        // Default implementation just returns false
+        // indicating the non-enhanced algorithm should be used
+        false
+    }
+
+    /// Process aligned blocks for several CRCs at once, loading each block only once
+    ///
+    /// Returns true if the operation was handled by the accelerated path, in which case each
+    /// lane's state holds its reduced CRC value (as with `process_enhanced_simd_blocks`)
+    unsafe fn process_enhanced_multi_blocks<const N: usize>(
+        &self,
+        _lanes: &mut [MultiLane<Self::Vector>; N],
+        _first: &[Self::Vector; 8],
+        _rest: &[[Self::Vector; 8]],
     ) -> bool
     where
         Self::Vector: Copy,
@@ -114,6 +118,31 @@ pub trait ArchOps: Sized + Copy + Clone {
     /// May use native CPU features
     unsafe fn load_aligned(&self, ptr: *const [u64; 2]) -> Self::Vector;
 
+    /// Store bytes to memory (unaligned)
+    ///
+    /// # Safety
+    /// May use native CPU features
+    unsafe fn store_bytes(&self, ptr: *mut u8, vector: Self::Vector);
+
+    /// Store bytes to 16-byte aligned memory, bypassing the cache where supported
+    ///
+    /// Callers must call `store_fence()` once done with non-temporal stores.
+    ///
+    /// # Safety
+    /// May use native CPU features
+    unsafe fn store_bytes_non_temporal(&self, ptr: *mut u8, vector: Self::Vector) {
+        // Default implementation uses regular stores
+        self.store_bytes(ptr, vector)
+    }
+
+    /// Order any preceding non-temporal stores before subsequent stores
+    ///
+    /// # Safety
+    /// May use native CPU features
+    unsafe fn store_fence(&self) {
+        // Default implementation has nothing to order, since stores are regular
+    }
+
     //unsafe fn load_aligned(&self, ptr: &[u64]) -> Self::Vector;
 
     //unsafe fn load_aligned_const(&self, ptr: *const [u64; 2]) -> Self::Vector;
@@ -294,7 +323,7 @@ pub trait EnhancedCrcWidth: CrcWidth {
     unsafe fn perform_final_reduction<T: ArchOps>(
         state: T::Vector,
         reflected: bool,
-        keys: [u64; 23],
+        keys: [u64; 27],
         ops: &T,
     ) -> Self::Value
     where
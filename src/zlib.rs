@@ -0,0 +1,168 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! zlib-compatible C symbols
+//!
+//! Exports zlib's CRC-32 functions with its ABI, so C/C++ programs can link (or `LD_PRELOAD`) this
+//! library in place of zlib's CRC routines without source changes. They're declared by zlib's own
+//! `zlib.h`, so they're not part of `libcrc_fast.h`.
+//!
+//! Requires the `zlib` feature. Don't enable it when zlib itself is linked into the same binary,
+//! since the symbols would collide.
+
+#![cfg(feature = "zlib")]
+// c_long and c_ulong are 32 bits on some platforms and 64 on others
+#![allow(clippy::unnecessary_cast)]
+
+use crate::combine;
+use crate::crc32::consts::CRC32_ISO_HDLC;
+use crate::gf2::{mul_mod, pow_mod, reflect, x_pow_mod};
+use core::ffi::{c_long, c_uint, c_ulong};
+use core::slice;
+
+/// Updates a running CRC-32 with `len` bytes from `buf`, returning the required initial value
+/// (0) if `buf` is NULL.
+///
+/// # Safety
+/// `buf` must be NULL, or valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn crc32(crc: c_ulong, buf: *const u8, len: c_uint) -> c_ulong {
+    crc32_z(crc, buf, len as usize)
+}
+
+/// Same as `crc32()`, but with a `size_t` length.
+///
+/// # Safety
+/// `buf` must be NULL, or valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn crc32_z(crc: c_ulong, buf: *const u8, len: usize) -> c_ulong {
+    if buf.is_null() {
+        return 0;
+    }
+
+    let state = !(crc as u32) as u64;
+    let bytes = slice::from_raw_parts(buf, len);
+
+    let calculator = crate::get_calculator(&CRC32_ISO_HDLC);
+
+    !(calculator(state, bytes, CRC32_ISO_HDLC) as u32) as c_ulong
+}
+
+/// Combines the CRC-32s of two sequences into the CRC-32 of their concatenation, given the
+/// length of the second.
+#[no_mangle]
+pub extern "C" fn crc32_combine(crc1: c_ulong, crc2: c_ulong, len2: c_long) -> c_ulong {
+    crc32_combine64(crc1, crc2, len2 as i64)
+}
+
+/// Same as `crc32_combine()`, but with a 64-bit length.
+#[no_mangle]
+pub extern "C" fn crc32_combine64(crc1: c_ulong, crc2: c_ulong, len2: i64) -> c_ulong {
+    combine::checksums(
+        crc1 as u32 as u64,
+        crc2 as u32 as u64,
+        len2.max(0) as u64,
+        CRC32_ISO_HDLC,
+    ) as c_ulong
+}
+
+/// Generates the operator for `crc32_combine_op()` to combine with a second sequence of `len2`
+/// bytes.
+#[no_mangle]
+pub extern "C" fn crc32_combine_gen(len2: c_long) -> c_ulong {
+    crc32_combine_gen64(len2 as i64)
+}
+
+/// Same as `crc32_combine_gen()`, but with a 64-bit length.
+#[no_mangle]
+pub extern "C" fn crc32_combine_gen64(len2: i64) -> c_ulong {
+    // zlib represents the operator x^(8 * len2) mod P(x) reflected, like the CRC itself. Raising
+    // x^8 to len2 avoids multiplying len2 by 8, which could overflow.
+    let zero_byte = x_pow_mod(8, CRC32_ISO_HDLC.poly, 32);
+    let operator = pow_mod(zero_byte, len2.max(0) as u64, CRC32_ISO_HDLC.poly, 32);
+
+    reflect(operator, 32) as c_ulong
+}
+
+/// Combines the CRC-32s of two sequences using an operator from `crc32_combine_gen()`, which is
+/// faster than `crc32_combine()` when combining many sequences of the same length.
+#[no_mangle]
+pub extern "C" fn crc32_combine_op(crc1: c_ulong, crc2: c_ulong, op: c_ulong) -> c_ulong {
+    // Since init and xorout are equal, they cancel out, leaving crc1 * x^(8 * len2) + crc2
+    let shifted = mul_mod(
+        reflect(op as u64, 32),
+        reflect(crc1 as u64, 32),
+        CRC32_ISO_HDLC.poly,
+        32,
+    );
+
+    (reflect(shifted, 32) as u32 ^ crc2 as u32) as c_ulong
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::RUST_CRC32_ISO_HDLC;
+    use rand::{rng, Rng};
+
+    fn crc32_slice(crc: c_ulong, bytes: &[u8]) -> c_ulong {
+        unsafe { crc32(crc, bytes.as_ptr(), bytes.len() as c_uint) }
+    }
+
+    #[test]
+    fn test_crc32() {
+        let mut data = vec![0u8; 4096];
+        rng().fill(&mut data[..]);
+        let expected = RUST_CRC32_ISO_HDLC.checksum(&data) as c_ulong;
+
+        // zlib's idiom for the initial value
+        let initial = unsafe { crc32(0, core::ptr::null(), 0) };
+        assert_eq!(initial, 0);
+
+        assert_eq!(crc32_slice(initial, b"123456789"), 0xcbf43926);
+        assert_eq!(crc32_slice(initial, &data), expected);
+        assert_eq!(
+            crc32_slice(crc32_slice(0, &data[..1000]), &data[1000..]),
+            expected
+        );
+        assert_eq!(unsafe { crc32_z(0, data.as_ptr(), data.len()) }, expected);
+
+        // no bytes leaves the CRC unchanged
+        assert_eq!(crc32_slice(0xcbf43926, &[]), 0xcbf43926);
+    }
+
+    #[test]
+    fn test_crc32_combine() {
+        let mut data = vec![0u8; 4096];
+        rng().fill(&mut data[..]);
+        let expected = RUST_CRC32_ISO_HDLC.checksum(&data) as c_ulong;
+
+        for split in [0, 1, 15, 16, 1000, 4095, 4096] {
+            let (first, second) = data.split_at(split);
+            let (crc1, crc2) = (crc32_slice(0, first), crc32_slice(0, second));
+            let len2 = second.len();
+
+            assert_eq!(crc32_combine(crc1, crc2, len2 as c_long), expected);
+            assert_eq!(crc32_combine64(crc1, crc2, len2 as i64), expected);
+
+            let op = crc32_combine_gen(len2 as c_long);
+            assert_eq!(op, crc32_combine_gen64(len2 as i64));
+            assert_eq!(crc32_combine_op(crc1, crc2, op), expected);
+        }
+
+        // zlib's operators for x^0, x^8 and x^16
+        assert_eq!(crc32_combine_gen(0), 0x80000000);
+        assert_eq!(crc32_combine_gen(1), 0x00800000);
+        assert_eq!(crc32_combine_gen(2), 0x00008000);
+
+        // CRC-32's polynomial is primitive, so x^(8 * (2^32 - 1)) wraps around to x^0
+        assert_eq!(crc32_combine_gen64((1 << 32) - 1), 0x80000000);
+
+        // lengths too long to count in bits
+        let (crc1, crc2) = (crc32_slice(0, &data[..100]), crc32_slice(0, &data[100..]));
+        let op = crc32_combine_gen64(i64::MAX);
+        assert_eq!(
+            crc32_combine_op(crc1, crc2, op),
+            crc32_combine64(crc1, crc2, i64::MAX)
+        );
+    }
+}